@@ -1,4 +1,4 @@
-use rand_core::{Rng, TryRng};
+use rand::rand_core::{Rng, TryRng};
 
 pub(crate) fn get_rng() -> impl Rng {
     XorShiftStar {
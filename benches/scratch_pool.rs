@@ -0,0 +1,42 @@
+#![feature(test)]
+#![cfg(feature = "rand")]
+
+//! Tight loops of medium-size operations, whose temporaries the `scratch-pool` feature reuses.
+//! Compare `cargo bench --bench scratch_pool` with and without `--features scratch-pool`.
+
+extern crate test;
+
+use num_bigint::{BigUint, RandBigInt};
+use test::Bencher;
+
+mod rng;
+use rng::get_rng;
+
+fn operands(xbits: u64, ybits: u64) -> (BigUint, BigUint) {
+    let mut rng = get_rng();
+    (rng.random_biguint(xbits), rng.random_biguint(ybits))
+}
+
+#[bench]
+fn multiply_loop_4k(b: &mut Bencher) {
+    let (x, y) = operands(1 << 12, 1 << 12);
+    b.iter(|| (0..16).map(|_| &x * &y).count());
+}
+
+#[bench]
+fn multiply_loop_16k(b: &mut Bencher) {
+    let (x, y) = operands(1 << 14, 1 << 14);
+    b.iter(|| (0..16).map(|_| &x * &y).count());
+}
+
+#[bench]
+fn remainder_loop_4k(b: &mut Bencher) {
+    let (x, y) = operands(1 << 13, 1 << 12);
+    b.iter(|| (0..16).map(|_| &x % &y).count());
+}
+
+#[bench]
+fn div_rem_loop_16k(b: &mut Bencher) {
+    let (x, y) = operands(1 << 15, 1 << 14);
+    b.iter(|| (0..16).map(|_| (&x / &y, &x % &y)).count());
+}
@@ -27,8 +27,8 @@ if ! check_version $MSRV ; then
   exit 1
 fi
 
-STD_FEATURES=(arbitrary quickcheck rand serde)
-NO_STD_FEATURES=(serde rand)
+STD_FEATURES=(alloy-rlp arbitrary arrow-buffer bitvec crypto-bigint defmt ethnum ffi getrandom gmp locale openssl primitive-types quickcheck rand rayon reference scale scratch-pool serde stats tracing try-reserve ufmt)
+NO_STD_FEATURES=(alloy-rlp bitvec crypto-bigint defmt ethnum getrandom locale primitive-types serde rand reference scale stats tracing try-reserve ufmt)
 echo "Testing supported features: ${STD_FEATURES[*]}"
 if [ -n "${NO_STD_FEATURES[*]}" ]; then
   echo " no_std supported features: ${NO_STD_FEATURES[*]}"
@@ -37,6 +37,12 @@ fi
 # arbitrary 1.1.4 started using array::from_fn
 check_version 1.63.0 || cargo update -p arbitrary --precise 1.1.3
 
+# rayon 1.8 and rayon-core 1.12 require rustc 1.63
+if ! check_version 1.63.0 ; then
+  cargo update -p rayon --precise 1.7.0
+  cargo update -p rayon-core --precise 1.11.0
+fi
+
 set -x
 
 # test the default with std
@@ -11,13 +11,16 @@ use core::ops::{Neg, Not};
 use core::str;
 
 use num_integer::{Integer, Roots};
-use num_traits::{ConstZero, Num, One, Pow, Signed, Zero};
+use num_traits::{ConstZero, Num, One, Pow, Signed, ToPrimitive, Zero};
 
 use self::Sign::{Minus, NoSign, Plus};
 
 use crate::big_digit::BigDigit;
 use crate::biguint::to_str_radix_reversed;
-use crate::biguint::{BigUint, IntDigits, U32Digits, U64Digits};
+use crate::biguint::{gcd_cofactor, shr_round, BigUint, IntDigits, U32Digits, U64Digits};
+use crate::continued_fraction::{self, ContinuedFraction};
+use crate::range::{BigRange, BigRangeInclusive};
+use crate::{CapacityError, Exactness, RoundingMode};
 
 mod addition;
 mod division;
@@ -25,11 +28,18 @@ mod multiplication;
 mod subtraction;
 
 mod arbitrary;
+mod arrow_buffer;
 mod bits;
 mod convert;
+mod crypto_bigint;
+mod decimal128;
+mod defmt;
+mod gmp;
+mod openssl;
 mod power;
 mod serde;
 mod shift;
+mod ufmt;
 
 /// A `Sign` is a [`BigInt`]'s composing element.
 #[derive(PartialEq, PartialOrd, Eq, Ord, Copy, Clone, Debug, Hash)]
@@ -77,12 +87,17 @@ impl Clone for BigInt {
     }
 }
 
+/// Values that fit in an `i64` hash exactly like that `i64`, so that hash-based collections
+/// treat them consistently with primitive keys. Larger values hash their sign followed by
+/// their magnitude, as in [`BigUint`].
 impl hash::Hash for BigInt {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         debug_assert!((self.sign != NoSign) ^ self.data.is_zero());
-        self.sign.hash(state);
-        if self.sign != NoSign {
+        if let Some(n) = self.to_i64() {
+            n.hash(state);
+        } else {
+            self.sign.hash(state);
             self.data.hash(state);
         }
     }
@@ -445,6 +460,12 @@ impl Integer for BigInt {
         (Self::from(gcd), Self::from(lcm))
     }
 
+    /// Greatest common divisor and Bézout coefficients, with Lehmer's algorithm.
+    #[inline]
+    fn extended_gcd(&self, other: &Self) -> num_integer::ExtendedGcd<Self> {
+        Self::extended_gcd(self, other)
+    }
+
     /// Greatest common divisor, least common multiple, and Bézout coefficients.
     #[inline]
     fn extended_gcd_lcm(&self, other: &Self) -> (num_integer::ExtendedGcd<Self>, Self) {
@@ -615,6 +636,20 @@ impl BigInt {
         }
     }
 
+    /// Returns `2^bits`, building the digits directly like [`BigUint::pow2`].
+    ///
+    /// Panics if the result would exceed the size limit of [`limits`](crate::limits).
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::power_of_two(70), BigInt::from(1i128 << 70));
+    /// ```
+    #[must_use]
+    pub fn power_of_two(bits: u64) -> Self {
+        Self::from(BigUint::pow2(bits))
+    }
+
     /// Creates and initializes a [`BigInt`].
     ///
     /// The bytes are in big-endian byte order.
@@ -843,6 +878,55 @@ impl BigInt {
         self.data.iter_u64_digits()
     }
 
+    /// Returns the low 64 bits of the [`BigInt`]'s two's-complement representation, as an
+    /// `i64`. This is the value modulo 2⁶⁴, like a wrapping `as` cast between primitives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-1125).to_i64_wrapping(), -1125);
+    /// assert_eq!(BigInt::from(u64::MAX).to_i64_wrapping(), -1);
+    /// assert_eq!((BigInt::from(-1) << 64u32).to_i64_wrapping(), 0);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn to_i64_wrapping(&self) -> i64 {
+        let low = self.data.to_u64_wrapping();
+        let low = if self.sign == Minus {
+            low.wrapping_neg()
+        } else {
+            low
+        };
+        low as i64
+    }
+
+    /// Returns the low 128 bits of the [`BigInt`]'s two's-complement representation, as an
+    /// `i128`. This is the value modulo 2¹²⁸, like a wrapping `as` cast between primitives.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(-1125).to_i128_wrapping(), -1125);
+    /// assert_eq!(BigInt::from(u128::MAX).to_i128_wrapping(), -1);
+    /// ```
+    #[must_use]
+    #[inline]
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn to_i128_wrapping(&self) -> i128 {
+        let low = self.data.to_u128_wrapping();
+        let low = if self.sign == Minus {
+            low.wrapping_neg()
+        } else {
+            low
+        };
+        low as i128
+    }
+
     /// Returns the two's-complement byte representation of the [`BigInt`] in big-endian byte order.
     ///
     /// # Examples
@@ -875,6 +959,47 @@ impl BigInt {
         convert::to_signed_bytes_le(self)
     }
 
+    /// Returns the `mpint` encoding of [RFC 4251, section 5][rfc], as used throughout the SSH
+    /// protocols: a 32-bit big-endian byte count, followed by the minimal two's-complement
+    /// big-endian bytes, with no bytes at all for zero.
+    ///
+    /// [rfc]: https://www.rfc-editor.org/rfc/rfc4251#section-5
+    ///
+    /// Panics if the value needs more than `u32::MAX` bytes.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from(0).to_ssh_mpint(), [0, 0, 0, 0]);
+    /// assert_eq!(BigInt::from(0x80).to_ssh_mpint(), [0, 0, 0, 2, 0x00, 0x80]);
+    /// assert_eq!(BigInt::from(-0x1234).to_ssh_mpint(), [0, 0, 0, 2, 0xed, 0xcc]);
+    /// ```
+    #[must_use]
+    pub fn to_ssh_mpint(&self) -> Vec<u8> {
+        convert::to_ssh_mpint(self)
+    }
+
+    /// Reads an SSH `mpint` from the start of `bytes`, returning the value and the bytes that
+    /// follow it -- see [`BigInt::to_ssh_mpint`].
+    ///
+    /// Returns `None` if `bytes` is shorter than the encoded length, or if the encoding is not
+    /// minimal, since RFC 4251 forbids leading bytes that only repeat the sign.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let wire = [0, 0, 0, 5, 0xff, 0x21, 0x52, 0x41, 0x11, 0xaa];
+    /// let (n, rest) = BigInt::from_ssh_mpint(&wire).unwrap();
+    /// assert_eq!(n, BigInt::from(-0xdeadbeef_i64));
+    /// assert_eq!(rest, [0xaa]);
+    ///
+    /// assert_eq!(BigInt::from_ssh_mpint(&[0, 0, 0, 2, 0x00, 0x7f]), None);
+    /// ```
+    #[must_use]
+    pub fn from_ssh_mpint(bytes: &[u8]) -> Option<(Self, &[u8])> {
+        convert::from_ssh_mpint(bytes)
+    }
+
     /// Returns the integer formatted as a string in the given radix.
     /// `radix` must be in the range `2...36`.
     ///
@@ -992,6 +1117,31 @@ impl BigInt {
         (self.sign, self.data)
     }
 
+    /// Returns an iterator over the values in `range`, stepping by one.
+    ///
+    /// This can be used in place of `for i in start..end`, which requires the unstable `Step`
+    /// trait. The iterator is double-ended and updates its bounds in place.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let v: Vec<BigInt> = BigInt::range(BigInt::from(-1)..BigInt::from(2)).rev().collect();
+    /// assert_eq!(v, [1, 0, -1].map(BigInt::from));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn range(range: core::ops::Range<Self>) -> BigRange<Self> {
+        BigRange::new(range.start, range.end)
+    }
+
+    /// Returns an iterator over the values in the closed `range`, stepping by one.
+    #[must_use]
+    #[inline]
+    pub fn range_inclusive(range: core::ops::RangeInclusive<Self>) -> BigRangeInclusive<Self> {
+        let (start, end) = range.into_inner();
+        BigRangeInclusive::new(start, end)
+    }
+
     /// Determines the fewest bits necessary to express the [`BigInt`],
     /// not including the sign.
     #[must_use]
@@ -1000,6 +1150,16 @@ impl BigInt {
         self.data.bits()
     }
 
+    /// Converts the [`BigInt`] to the nearest `f64`, with ties to even, and reports whether
+    /// that conversion was exact, rounded, or overflowed to infinity.
+    ///
+    /// The value is the same as [`ToPrimitive::to_f64`](num_traits::ToPrimitive::to_f64).
+    #[must_use]
+    pub fn to_f64_checked(&self) -> (f64, Exactness) {
+        let (value, exactness) = self.data.to_f64_checked();
+        (if self.sign == Minus { -value } else { value }, exactness)
+    }
+
     /// Converts this [`BigInt`] into a [`BigUint`], if it's not negative.
     #[must_use]
     #[inline]
@@ -1038,12 +1198,136 @@ impl BigInt {
         Some(self / v)
     }
 
+    /// Returns `self / rhs`, rounded in the direction given by `mode`.
+    ///
+    /// The `/` operator always truncates toward zero, and [`Integer::div_floor`] rounds toward
+    /// negative infinity, so this covers the other modes, such as the round-half-even of
+    /// financial code.
+    ///
+    /// Panics if `rhs` is zero.
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, RoundingMode};
+    ///
+    /// let (a, b) = (BigInt::from(-5), BigInt::from(2));
+    /// assert_eq!(a.div_round(&b, RoundingMode::Floor), BigInt::from(-3));
+    /// assert_eq!(a.div_round(&b, RoundingMode::Ceil), BigInt::from(-2));
+    /// assert_eq!(a.div_round(&b, RoundingMode::Trunc), BigInt::from(-2));
+    /// assert_eq!(a.div_round(&b, RoundingMode::HalfUp), BigInt::from(-3));
+    /// assert_eq!(a.div_round(&b, RoundingMode::HalfEven), BigInt::from(-2));
+    /// ```
+    #[must_use]
+    pub fn div_round(&self, rhs: &Self, mode: RoundingMode) -> Self {
+        division::div_round(self, rhs, mode)
+    }
+
+    /// Adds `a * b` to `self` in place, without a temporary for the product -- see
+    /// [`BigUint::addmul_assign`].
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let mut acc = BigInt::from(5);
+    /// acc.addmul_assign(&BigInt::from(-6), &BigInt::from(7));
+    /// assert_eq!(acc, BigInt::from(-37));
+    /// ```
+    pub fn addmul_assign(&mut self, a: &Self, b: &Self) {
+        multiplication::addmul_signed(self, a, b, Plus);
+    }
+
+    /// Subtracts `a * b` from `self` in place, without a temporary for the product.
+    ///
+    /// The sign of `self` may change, as in the row operations of fraction-free Gaussian
+    /// elimination.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let mut acc = BigInt::from(5);
+    /// acc.submul_assign(&BigInt::from(6), &BigInt::from(7));
+    /// assert_eq!(acc, BigInt::from(-37));
+    /// acc.submul_assign(&BigInt::from(-6), &BigInt::from(7));
+    /// assert_eq!(acc, BigInt::from(5));
+    /// ```
+    pub fn submul_assign(&mut self, a: &Self, b: &Self) {
+        multiplication::addmul_signed(self, a, b, Minus);
+    }
+
+    /// Evaluates the polynomial with coefficients `coeffs` at `x`.
+    ///
+    /// `coeffs[i]` is the coefficient of `x^i`, and an empty slice is the zero polynomial.
+    /// Short polynomials use Horner's rule; long ones are split in balanced halves around
+    /// precomputed powers `x^(2^k)`, which turns the work into a few large multiplications.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// // 2 - 3x + x^2 at x = 5
+    /// let coeffs = [BigInt::from(2), BigInt::from(-3), BigInt::from(1)];
+    /// assert_eq!(BigInt::eval_poly(&coeffs, &BigInt::from(5)), BigInt::from(12));
+    /// ```
+    #[must_use]
+    pub fn eval_poly(coeffs: &[Self], x: &Self) -> Self {
+        multiplication::eval_poly(coeffs, x)
+    }
+
     /// Returns `self ^ exponent`.
     #[must_use]
     pub fn pow(&self, exponent: u32) -> Self {
         Pow::pow(self, exponent)
     }
 
+    /// Returns `self ^ exponent` for an arbitrary-precision exponent.
+    ///
+    /// Panics if the result could not possibly fit in memory, which is the case whenever
+    /// `|self| > 1` and the exponent exceeds a `u128`.
+    #[must_use]
+    pub fn pow_big(&self, exponent: &BigUint) -> Self {
+        Pow::pow(self, exponent)
+    }
+
+    /// Returns `self ^ exponent`, or `None` if the magnitude of the result would need more than
+    /// `max_bits` bits.
+    #[must_use]
+    pub fn checked_pow(&self, exponent: u32, max_bits: u64) -> Option<Self> {
+        power::checked_pow(self, &BigUint::from(exponent), max_bits)
+    }
+
+    /// Returns `self ^ exponent` for an arbitrary-precision exponent, or `None` if the
+    /// magnitude of the result would need more than `max_bits` bits.
+    #[must_use]
+    pub fn checked_pow_big(&self, exponent: &BigUint, max_bits: u64) -> Option<Self> {
+        power::checked_pow(self, exponent, max_bits)
+    }
+
+    /// Returns `self + other`, or an error if the magnitude of the sum would exceed the size
+    /// limit of [`limits`](crate::limits).
+    pub fn try_add(&self, other: &Self) -> Result<Self, CapacityError> {
+        let sum = self + other;
+        crate::limits::try_check(sum.bits())?;
+        Ok(sum)
+    }
+
+    /// Returns `self * other`, or an error if the magnitude of the product would exceed the
+    /// size limit -- see [`BigUint::try_mul`].
+    pub fn try_mul(&self, other: &Self) -> Result<Self, CapacityError> {
+        let magnitude = self.data.try_mul(&other.data)?;
+        Ok(Self::from_biguint(self.sign * other.sign, magnitude))
+    }
+
+    /// Returns `self << shift`, or an error if the magnitude of the result would exceed the
+    /// size limit or the capacity of the target -- see [`BigUint::try_shl`].
+    pub fn try_shl(&self, shift: u64) -> Result<Self, CapacityError> {
+        let magnitude = self.data.try_shl(shift)?;
+        Ok(Self::from_biguint(self.sign, magnitude))
+    }
+
+    /// Returns `self ^ exponent`, or an error if the magnitude of the result would exceed the
+    /// size limit or the capacity of the target -- see [`BigUint::try_pow`].
+    pub fn try_pow(&self, exponent: u32) -> Result<Self, CapacityError> {
+        power::try_pow(self, exponent)
+    }
+
     /// Returns `(self ^ exponent) mod modulus`
     ///
     /// Note that this rounds like `mod_floor`, not like the `%` operator,
@@ -1116,6 +1400,89 @@ impl BigInt {
         Some(Self::from_biguint(sign, mag))
     }
 
+    /// Returns the greatest common divisor of `self` and `other`, with Bézout coefficients `x`
+    /// and `y` such that `gcd = x * self + y * other`.
+    ///
+    /// The gcd is non-negative, and the coefficients are the minimal ones found by the
+    /// extended Euclidean algorithm. This uses Lehmer's algorithm, which works on the leading
+    /// word of large operands to replace most full-size division steps, so it is much faster
+    /// than the generic [`Integer::extended_gcd`] for large values.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let (a, b) = (BigInt::from(240), BigInt::from(-46));
+    /// let e = a.extended_gcd(&b);
+    /// assert_eq!(e.gcd, BigInt::from(2));
+    /// assert_eq!(e.x * a + e.y * b, e.gcd);
+    /// ```
+    #[must_use]
+    pub fn extended_gcd(&self, other: &Self) -> num_integer::ExtendedGcd<Self> {
+        let (gcd, mut x) = gcd_cofactor(&self.data, &other.data);
+        let mut y = if other.is_zero() {
+            Self::ZERO
+        } else {
+            (Self::from(gcd.clone()) - &x * self.abs()) / other.abs()
+        };
+        if self.is_negative() {
+            x = -x;
+        }
+        if other.is_negative() {
+            y = -y;
+        }
+        num_integer::ExtendedGcd {
+            gcd: Self::from(gcd),
+            x,
+            y,
+        }
+    }
+
+    /// Returns an iterator over the partial quotients of the continued fraction of
+    /// `numer / denom`.
+    ///
+    /// Panics if `denom` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// // 415/93 = 4 + 1/(2 + 1/(6 + 1/7))
+    /// let quotients: Vec<BigInt> =
+    ///     BigInt::continued_fraction(&BigInt::from(415), &BigInt::from(93)).collect();
+    /// assert_eq!(quotients, [4, 2, 6, 7].map(BigInt::from));
+    ///
+    /// let (p, q) = BigInt::continued_fraction(&BigInt::from(415), &BigInt::from(93))
+    ///     .convergents()
+    ///     .nth(2)
+    ///     .unwrap();
+    /// assert_eq!((p, q), (BigInt::from(58), BigInt::from(13)));
+    /// ```
+    #[must_use]
+    pub fn continued_fraction(numer: &Self, denom: &Self) -> ContinuedFraction {
+        ContinuedFraction::new(numer, denom)
+    }
+
+    /// Returns the fraction `(p, q)` closest to `numer / denom` with `0 < q <= max_denom`, in
+    /// lowest terms.
+    ///
+    /// This is the last [convergent](Self::continued_fraction) within the bound or the best
+    /// semiconvergent after it. On a tie the convergent is returned.
+    ///
+    /// Panics if `denom` is zero or `max_denom` is less than one.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// // Approximations of π from its first few digits.
+    /// let (n, d) = (BigInt::from(314_159_265_358_979u64), BigInt::from(100_000_000_000_000u64));
+    /// let approx = |max| BigInt::best_rational_approx(&n, &d, &BigInt::from(max));
+    /// assert_eq!(approx(10), (BigInt::from(22), BigInt::from(7)));
+    /// assert_eq!(approx(1000), (BigInt::from(355), BigInt::from(113)));
+    /// ```
+    #[must_use]
+    pub fn best_rational_approx(numer: &Self, denom: &Self, max_denom: &Self) -> (Self, Self) {
+        continued_fraction::best_rational_approx(numer, denom, max_denom)
+    }
+
     /// Returns the truncated principal square root of `self` --
     /// see [`num_integer::Roots::sqrt()`].
     #[must_use]
@@ -1194,6 +1561,25 @@ impl BigInt {
         // The top bit may have been cleared, so normalize
         self.normalize();
     }
+
+    /// Returns `self >> n`, with the discarded bits rounded in the direction given by `mode` --
+    /// see [`BigUint::shr_round`].
+    ///
+    /// The `>>` operator rounds toward negative infinity, like [`RoundingMode::Floor`].
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, RoundingMode};
+    ///
+    /// let n = BigInt::from(-0b1011_1000);
+    /// assert_eq!(n.shr_round(4, RoundingMode::Floor), &n >> 4);
+    /// assert_eq!(n.shr_round(4, RoundingMode::Trunc), BigInt::from(-0b1011));
+    /// assert_eq!(n.shr_round(4, RoundingMode::HalfEven), BigInt::from(-0b1100));
+    /// ```
+    #[must_use]
+    pub fn shr_round(&self, n: u64, mode: RoundingMode) -> Self {
+        let magnitude = shr_round(&self.data, n, self.sign == Minus, mode);
+        Self::from_biguint(self.sign, magnitude)
+    }
 }
 
 impl num_traits::FromBytes for BigInt {
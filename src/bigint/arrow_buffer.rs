@@ -0,0 +1,47 @@
+#![cfg(feature = "arrow-buffer")]
+#![cfg_attr(docsrs, doc(cfg(feature = "arrow-buffer")))]
+
+use super::{BigInt, Sign};
+
+use crate::TryFromBigIntError;
+
+use arrow_buffer::i256;
+use core::convert::TryFrom;
+
+impl TryFrom<&BigInt> for i256 {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(value: &BigInt) -> Result<Self, TryFromBigIntError<()>> {
+        let bytes = value.to_signed_bytes_le();
+        if bytes.len() > 32 {
+            return Err(TryFromBigIntError::new(()));
+        }
+        let fill = if value.sign == Sign::Minus { 0xff } else { 0 };
+        let mut le = [fill; 32];
+        le[..bytes.len()].copy_from_slice(&bytes);
+        Ok(Self::from_le_bytes(le))
+    }
+}
+
+impl TryFrom<BigInt> for i256 {
+    type Error = TryFromBigIntError<BigInt>;
+
+    #[inline]
+    fn try_from(value: BigInt) -> Result<Self, TryFromBigIntError<BigInt>> {
+        Self::try_from(&value).map_err(|e| e.with_original(value))
+    }
+}
+
+impl From<i256> for BigInt {
+    #[inline]
+    fn from(value: i256) -> Self {
+        Self::from_signed_bytes_le(&value.to_le_bytes())
+    }
+}
+
+impl From<&i256> for BigInt {
+    #[inline]
+    fn from(value: &i256) -> Self {
+        Self::from(*value)
+    }
+}
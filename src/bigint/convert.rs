@@ -1,13 +1,18 @@
 use super::Sign::{self, Minus, NoSign, Plus};
 use super::{BigInt, ToBigInt};
 
-use crate::TryFromBigIntError;
 use crate::{BigUint, ParseBigIntError, ToBigUint};
+use crate::{RoundingMode, TryFromBigIntError, TryFromFloatError};
 
 use alloc::vec::Vec;
-use core::cmp::Ordering::{Equal, Greater, Less};
+use core::cmp::Ordering::{self, Equal, Greater, Less};
 use core::convert::TryFrom;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 use core::str::{self, FromStr};
+use num_traits::float::FloatCore;
 use num_traits::{FromPrimitive, Num, One, ToPrimitive, Zero};
 
 impl FromStr for BigInt {
@@ -102,6 +107,15 @@ impl ToPrimitive for BigInt {
     }
 }
 
+/// Returns the error for a value that does not fit a primitive type.
+fn out_of_range(value: &BigInt, unsigned: bool) -> TryFromBigIntError<()> {
+    if unsigned && value.sign() == Sign::Minus {
+        TryFromBigIntError::negative(())
+    } else {
+        TryFromBigIntError::new(())
+    }
+}
+
 macro_rules! impl_try_from_bigint {
     ($T:ty, $to_ty:path) => {
         impl TryFrom<&BigInt> for $T {
@@ -109,7 +123,7 @@ macro_rules! impl_try_from_bigint {
 
             #[inline]
             fn try_from(value: &BigInt) -> Result<$T, TryFromBigIntError<()>> {
-                $to_ty(value).ok_or(TryFromBigIntError::new(()))
+                $to_ty(value).ok_or_else(|| out_of_range(value, <$T>::MIN == 0))
             }
         }
 
@@ -118,7 +132,7 @@ macro_rules! impl_try_from_bigint {
 
             #[inline]
             fn try_from(value: BigInt) -> Result<$T, TryFromBigIntError<BigInt>> {
-                <$T>::try_from(&value).map_err(|_| TryFromBigIntError::new(value))
+                <$T>::try_from(&value).map_err(|e| e.with_original(value))
             }
         }
     };
@@ -138,6 +152,84 @@ impl_try_from_bigint!(i64, ToPrimitive::to_i64);
 impl_try_from_bigint!(isize, ToPrimitive::to_isize);
 impl_try_from_bigint!(i128, ToPrimitive::to_i128);
 
+macro_rules! impl_try_from_bigint_nonzero {
+    ($($NonZero:ty => $T:ty),*) => {$(
+        impl TryFrom<&BigInt> for $NonZero {
+            type Error = TryFromBigIntError<()>;
+
+            #[inline]
+            fn try_from(value: &BigInt) -> Result<$NonZero, TryFromBigIntError<()>> {
+                let n = <$T>::try_from(value)?;
+                <$NonZero>::new(n).ok_or(TryFromBigIntError::zero(()))
+            }
+        }
+
+        impl TryFrom<BigInt> for $NonZero {
+            type Error = TryFromBigIntError<BigInt>;
+
+            #[inline]
+            fn try_from(value: BigInt) -> Result<$NonZero, TryFromBigIntError<BigInt>> {
+                <$NonZero>::try_from(&value).map_err(|e| e.with_original(value))
+            }
+        }
+    )*};
+}
+
+impl_try_from_bigint_nonzero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64,
+    NonZeroUsize => usize, NonZeroU128 => u128,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+    NonZeroIsize => isize, NonZeroI128 => i128
+);
+
+/// Compares exactly against the float's value, rather than rounding `self` to `f64`.
+/// NaN is unordered, and infinities compare beyond every finite value.
+fn cmp_f64(x: &BigInt, f: f64) -> Option<Ordering> {
+    if f.is_nan() {
+        return None;
+    }
+    if f == f64::INFINITY {
+        return Some(Less);
+    }
+    if f == f64::NEG_INFINITY {
+        return Some(Greater);
+    }
+    // `floor` is exact, and every finite integer-valued float converts exactly.
+    let floor = FloatCore::floor(f);
+    match x.cmp(&BigInt::from_f64(floor).unwrap()) {
+        Equal if f > floor => Some(Less),
+        ord => Some(ord),
+    }
+}
+
+impl PartialEq<f64> for BigInt {
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        cmp_f64(self, *other) == Some(Equal)
+    }
+}
+
+impl PartialOrd<f64> for BigInt {
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        cmp_f64(self, *other)
+    }
+}
+
+impl PartialEq<BigInt> for f64 {
+    #[inline]
+    fn eq(&self, other: &BigInt) -> bool {
+        cmp_f64(other, *self) == Some(Equal)
+    }
+}
+
+impl PartialOrd<BigInt> for f64 {
+    #[inline]
+    fn partial_cmp(&self, other: &BigInt) -> Option<Ordering> {
+        cmp_f64(other, *self).map(Ordering::reverse)
+    }
+}
+
 impl FromPrimitive for BigInt {
     #[inline]
     fn from_i64(n: i64) -> Option<Self> {
@@ -170,6 +262,107 @@ impl FromPrimitive for BigInt {
     }
 }
 
+impl BigInt {
+    /// Converts an `f64` to a [`BigInt`], rounding any fractional part in the given direction,
+    /// or returns `None` if the value is NaN or infinite.
+    ///
+    /// [`FromPrimitive::from_f64`] always truncates toward zero, like [`RoundingMode::Trunc`].
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, RoundingMode};
+    ///
+    /// assert_eq!(BigInt::from_f64_round(-2.5, RoundingMode::Floor), Some(BigInt::from(-3)));
+    /// assert_eq!(BigInt::from_f64_round(-2.5, RoundingMode::Ceil), Some(BigInt::from(-2)));
+    /// assert_eq!(BigInt::from_f64_round(2.5, RoundingMode::HalfEven), Some(BigInt::from(2)));
+    /// assert_eq!(BigInt::from_f64_round(f64::NAN, RoundingMode::Trunc), None);
+    /// ```
+    #[must_use]
+    #[allow(clippy::float_cmp)]
+    pub fn from_f64_round(x: f64, mode: RoundingMode) -> Option<Self> {
+        if !x.is_finite() {
+            return None;
+        }
+        let rounded = match mode {
+            RoundingMode::Floor => FloatCore::floor(x),
+            RoundingMode::Ceil => FloatCore::ceil(x),
+            RoundingMode::Trunc => FloatCore::trunc(x),
+            RoundingMode::HalfUp => FloatCore::round(x),
+            RoundingMode::HalfEven => {
+                // `round` breaks ties away from zero, so step back toward zero when that
+                // landed on an odd value. Only values below 2^53 can have a fraction of
+                // exactly one half, so the parity check is exact.
+                let r = FloatCore::round(x);
+                if FloatCore::abs(x - FloatCore::trunc(x)) == 0.5 && r % 2.0 != 0.0 {
+                    r - FloatCore::signum(x)
+                } else {
+                    r
+                }
+            }
+        };
+        Self::from_f64(rounded)
+    }
+
+    /// Converts an `f64` to the largest [`BigInt`] not above it, or returns `None` if the value
+    /// is NaN or infinite.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_f64_floor(-2.5), Some(BigInt::from(-3)));
+    /// assert_eq!(BigInt::from_f64_floor(2.5), Some(BigInt::from(2)));
+    /// ```
+    #[must_use]
+    pub fn from_f64_floor(x: f64) -> Option<Self> {
+        Self::from_f64_round(x, RoundingMode::Floor)
+    }
+
+    /// Converts an `f64` to the smallest [`BigInt`] not below it, or returns `None` if the
+    /// value is NaN or infinite.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_f64_ceil(-2.5), Some(BigInt::from(-2)));
+    /// assert_eq!(BigInt::from_f64_ceil(2.5), Some(BigInt::from(3)));
+    /// ```
+    #[must_use]
+    pub fn from_f64_ceil(x: f64) -> Option<Self> {
+        Self::from_f64_round(x, RoundingMode::Ceil)
+    }
+
+    /// Converts an `f64` to a [`BigInt`] by dropping any fractional part, or returns `None`
+    /// if the value is NaN or infinite.
+    ///
+    /// This is what [`FromPrimitive::from_f64`] does, under a name that says so.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// assert_eq!(BigInt::from_f64_trunc(-2.5), Some(BigInt::from(-2)));
+    /// assert_eq!(BigInt::from_f64_trunc(2.5), Some(BigInt::from(2)));
+    /// ```
+    #[must_use]
+    pub fn from_f64_trunc(x: f64) -> Option<Self> {
+        Self::from_f64_round(x, RoundingMode::Trunc)
+    }
+}
+
+/// Converts an `f64` that is exactly an integer value, unlike [`FromPrimitive::from_f64`]
+/// which silently truncates any fractional part.
+impl TryFrom<f64> for BigInt {
+    type Error = TryFromFloatError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            Err(TryFromFloatError::not_finite())
+        } else if FloatCore::fract(value) != 0.0 {
+            Err(TryFromFloatError::fractional())
+        } else {
+            Self::from_f64(value).ok_or(TryFromFloatError::not_finite())
+        }
+    }
+}
+
 impl From<i64> for BigInt {
     #[inline]
     fn from(n: i64) -> Self {
@@ -313,7 +506,7 @@ impl TryFrom<&BigInt> for BigUint {
     fn try_from(value: &BigInt) -> Result<Self, TryFromBigIntError<()>> {
         value
             .to_biguint()
-            .ok_or_else(|| TryFromBigIntError::new(()))
+            .ok_or_else(|| TryFromBigIntError::negative(()))
     }
 }
 
@@ -323,7 +516,7 @@ impl TryFrom<BigInt> for BigUint {
     #[inline]
     fn try_from(value: BigInt) -> Result<Self, TryFromBigIntError<BigInt>> {
         if value.sign() == Sign::Minus {
-            Err(TryFromBigIntError::new(value))
+            Err(TryFromBigIntError::negative(value))
         } else {
             Ok(value.data)
         }
@@ -438,6 +631,42 @@ pub(super) fn to_signed_bytes_le(x: &BigInt) -> Vec<u8> {
     bytes
 }
 
+pub(super) fn to_ssh_mpint(x: &BigInt) -> Vec<u8> {
+    // Zero has no bytes at all, rather than the single zero of `to_signed_bytes_be`.
+    let body = if x.is_zero() {
+        Vec::new()
+    } else {
+        to_signed_bytes_be(x)
+    };
+    let len = u32::try_from(body.len()).expect("mpint does not fit a 32-bit length");
+    let mut bytes = Vec::with_capacity(4 + body.len());
+    bytes.extend_from_slice(&len.to_be_bytes());
+    bytes.extend_from_slice(&body);
+    bytes
+}
+
+pub(super) fn from_ssh_mpint(bytes: &[u8]) -> Option<(BigInt, &[u8])> {
+    if bytes.len() < 4 {
+        return None;
+    }
+    let (len, rest) = bytes.split_at(4);
+    let len = u32::from_be_bytes([len[0], len[1], len[2], len[3]]);
+    let len = usize::try_from(len).ok().filter(|&len| len <= rest.len())?;
+    let (body, rest) = rest.split_at(len);
+
+    // Leading bytes that only repeat the sign of the next byte must not be included.
+    let redundant = match *body {
+        [0] => true,
+        [0, next, ..] => next < 0x80,
+        [0xff, next, ..] => next >= 0x80,
+        _ => false,
+    };
+    if redundant {
+        return None;
+    }
+    Some((from_signed_bytes_be(body), rest))
+}
+
 /// Perform in-place two's complement of the given binary representation,
 /// in little-endian byte order.
 #[inline]
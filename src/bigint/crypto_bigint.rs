@@ -0,0 +1,44 @@
+#![cfg(feature = "crypto-bigint")]
+#![cfg_attr(docsrs, doc(cfg(feature = "crypto-bigint")))]
+
+use super::{BigInt, Sign};
+
+use crate::{BigUint, TryFromBigIntError};
+
+use core::convert::TryFrom;
+use crypto_bigint::Uint;
+
+impl<const LIMBS: usize> TryFrom<&BigInt> for Uint<LIMBS> {
+    type Error = TryFromBigIntError<()>;
+
+    #[inline]
+    fn try_from(value: &BigInt) -> Result<Self, TryFromBigIntError<()>> {
+        if value.sign == Sign::Minus {
+            return Err(TryFromBigIntError::negative(()));
+        }
+        Uint::try_from(&value.data)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BigInt> for Uint<LIMBS> {
+    type Error = TryFromBigIntError<BigInt>;
+
+    #[inline]
+    fn try_from(value: BigInt) -> Result<Self, TryFromBigIntError<BigInt>> {
+        Uint::try_from(&value).map_err(|e| e.with_original(value))
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for BigInt {
+    #[inline]
+    fn from(value: &Uint<LIMBS>) -> Self {
+        Self::from(BigUint::from(value))
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BigInt {
+    #[inline]
+    fn from(value: Uint<LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
@@ -0,0 +1,131 @@
+//! Conversions to and from the IEEE 754-2008 decimal128 format, in the binary integer decimal
+//! encoding used by BSON.
+//!
+//! A decimal128 is `(-1)^sign × coefficient × 10^exponent`, with a coefficient of at most 34
+//! decimal digits and an exponent from -6176 to 6111. The 128 bits hold the sign, then 14 bits
+//! of biased exponent, then 113 bits of coefficient, unless the two bits after the sign are both
+//! set. That second form encodes infinities, NaNs, and coefficients too large to be canonical,
+//! which count as zero.
+
+use super::{BigInt, Sign};
+
+use crate::{BigUint, TryFromBigIntError};
+
+use num_integer::Integer;
+use num_traits::{Pow, ToPrimitive, Zero};
+
+const DIGITS: u32 = 34;
+const BIAS: u32 = 6176;
+const MAX_EXPONENT: u32 = 6111;
+const COEFFICIENT_BITS: u32 = 113;
+
+/// `10^34`, the first coefficient that does not fit.
+fn coefficient_limit() -> BigUint {
+    BigUint::from(10u8).pow(DIGITS)
+}
+
+impl BigInt {
+    /// Encodes `self` as an IEEE 754-2008 decimal128, in the little-endian byte order of BSON,
+    /// as taken by `bson::Decimal128::from_bytes`.
+    ///
+    /// Values of up to 34 decimal digits are exact with exponent zero. Larger values are kept
+    /// exact by moving trailing zeros into the exponent.
+    ///
+    /// # Errors
+    ///
+    /// Fails if moving the trailing zeros still leaves more than 34 significant digits, or if
+    /// the value exceeds the largest decimal128.
+    ///
+    /// # Panics
+    ///
+    /// Never panics: the coefficient that is left is below `10^34`, which always fits in its
+    /// 113 bits.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// let n = BigInt::from(-42);
+    /// let bytes = n.try_to_decimal128().unwrap();
+    /// assert_eq!(u128::from_le_bytes(bytes), 0xb040_0000_0000_0000_0000_0000_0000_002a);
+    /// assert_eq!(BigInt::from_decimal128(bytes), Some(n));
+    ///
+    /// let big = BigInt::from(10).pow(40u32);
+    /// assert_eq!(BigInt::from_decimal128(big.try_to_decimal128().unwrap()), Some(big.clone()));
+    /// assert!((big + 1u8).try_to_decimal128().is_err());
+    /// ```
+    pub fn try_to_decimal128(&self) -> Result<[u8; 16], TryFromBigIntError<()>> {
+        // Anything of more bits is beyond 10^6145, too large for even the largest exponent, so
+        // skip stripping its digits one at a time.
+        if self.bits() > 20_414 {
+            return Err(TryFromBigIntError::new(()));
+        }
+
+        let limit = coefficient_limit();
+        let ten = BigUint::from(10u8);
+        let mut coefficient = self.magnitude().clone();
+        let mut exponent = 0;
+        while coefficient >= limit {
+            let (q, r) = coefficient.div_rem(&ten);
+            if !r.is_zero() || exponent == MAX_EXPONENT {
+                return Err(TryFromBigIntError::new(()));
+            }
+            coefficient = q;
+            exponent += 1;
+        }
+
+        let sign = u128::from(self.sign == Sign::Minus) << 127;
+        let exponent = u128::from(exponent + BIAS) << COEFFICIENT_BITS;
+        let coefficient = coefficient.to_u128().unwrap();
+        Ok((sign | exponent | coefficient).to_le_bytes())
+    }
+
+    /// Decodes an IEEE 754-2008 decimal128 from the little-endian byte order of BSON, as
+    /// returned by `bson::Decimal128::bytes`.
+    ///
+    /// Returns `None` for infinities, NaNs, and values with a fractional part. Non-canonical
+    /// coefficients are zero, as the standard requires.
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    ///
+    /// // 1.50E+3
+    /// let bytes = (0x3042_0000_0000_0000_0000_0000_0000_0096u128).to_le_bytes();
+    /// assert_eq!(BigInt::from_decimal128(bytes), Some(BigInt::from(1500)));
+    /// // 1.5
+    /// let bytes = (0x303e_0000_0000_0000_0000_0000_0000_000fu128).to_le_bytes();
+    /// assert_eq!(BigInt::from_decimal128(bytes), None);
+    /// ```
+    #[must_use]
+    pub fn from_decimal128(bytes: [u8; 16]) -> Option<Self> {
+        let bits = u128::from_le_bytes(bytes);
+        let negative = bits >> 127 != 0;
+        let (exponent, coefficient) = if (bits >> 125) & 0b11 == 0b11 {
+            if (bits >> 123) & 0b11 == 0b11 {
+                return None;
+            }
+            // The implied coefficient is at least 2^113, which is never canonical.
+            ((bits >> 111) & 0x3fff, 0)
+        } else {
+            let coefficient = bits & ((1 << COEFFICIENT_BITS) - 1);
+            ((bits >> COEFFICIENT_BITS) & 0x3fff, coefficient)
+        };
+
+        let mut coefficient = BigUint::from(coefficient);
+        if coefficient >= coefficient_limit() {
+            coefficient = BigUint::ZERO;
+        }
+        // Both forms mask the exponent to 14 bits, so it always fits.
+        let exponent = u32::try_from(exponent).ok()?;
+        let magnitude = if exponent >= BIAS {
+            coefficient * BigUint::from(10u8).pow(exponent - BIAS)
+        } else {
+            let (q, r) = coefficient.div_rem(&BigUint::from(10u8).pow(BIAS - exponent));
+            if !r.is_zero() {
+                return None;
+            }
+            q
+        };
+        let sign = if negative { Sign::Minus } else { Sign::Plus };
+        Some(Self::from_biguint(sign, magnitude))
+    }
+}
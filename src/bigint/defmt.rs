@@ -0,0 +1,15 @@
+#![cfg(feature = "defmt")]
+#![cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+
+use super::{BigInt, Sign};
+
+/// Logs the sign and the magnitude in hexadecimal, followed by the bit length of the
+/// magnitude, as in `-0xff (8 bits)`.
+impl defmt::Format for BigInt {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        if self.sign == Sign::Minus {
+            defmt::write!(f, "-");
+        }
+        defmt::write!(f, "{}", self.data);
+    }
+}
@@ -2,7 +2,7 @@ use super::CheckedUnsignedAbs::{Negative, Positive};
 use super::Sign::NoSign;
 use super::{BigInt, UnsignedAbs};
 
-use crate::{IsizePromotion, UsizePromotion};
+use crate::{IsizePromotion, RoundingMode, UsizePromotion};
 
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use num_integer::Integer;
@@ -469,6 +469,9 @@ impl CheckedEuclid for BigInt {
     }
 
     fn checked_div_rem_euclid(&self, v: &Self) -> Option<(Self, Self)> {
+        if v.is_zero() {
+            return None;
+        }
         Some(self.div_rem_euclid(v))
     }
 }
@@ -515,3 +518,22 @@ impl Euclid for BigInt {
         }
     }
 }
+
+/// Divides `u` by `d`, rounding the quotient by `mode`.
+pub(super) fn div_round(u: &BigInt, d: &BigInt, mode: RoundingMode) -> BigInt {
+    let (q, r) = u.div_rem(d);
+    if r.is_zero() {
+        return q;
+    }
+    let negative = u.sign != d.sign;
+    let fraction = r.magnitude().cmp(&(d.magnitude() - r.magnitude()));
+    if mode.rounds_away(negative, q.is_odd(), fraction) {
+        if negative {
+            q - 1
+        } else {
+            q + 1
+        }
+    } else {
+        q
+    }
+}
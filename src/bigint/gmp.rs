@@ -0,0 +1,40 @@
+#![cfg(feature = "gmp")]
+#![cfg_attr(docsrs, doc(cfg(feature = "gmp")))]
+
+use super::{BigInt, Sign};
+
+use crate::biguint::gmp::{from_limbs, mpz_limbs, write_mpz};
+use gmp_mpfr_sys::gmp;
+
+impl BigInt {
+    /// Creates a [`BigInt`] by copying the sign and limbs of a GMP integer.
+    ///
+    /// This works with any `mpz_t`, including the raw value behind a `rug::Integer`.
+    ///
+    /// # Safety
+    ///
+    /// `z` must have been initialized by GMP, e.g. with `mpz_init`.
+    #[must_use]
+    pub unsafe fn from_mpz(z: &gmp::mpz_t) -> Self {
+        // SAFETY: the caller guarantees that `z` is initialized.
+        unsafe {
+            let sign = match gmp::mpz_sgn(z) {
+                s if s < 0 => Sign::Minus,
+                0 => Sign::NoSign,
+                _ => Sign::Plus,
+            };
+            Self::from_biguint(sign, from_limbs(mpz_limbs(z)))
+        }
+    }
+
+    /// Assigns the value of this [`BigInt`] to a GMP integer, reusing its allocation when
+    /// it is large enough.
+    ///
+    /// # Safety
+    ///
+    /// `z` must have been initialized by GMP, e.g. with `mpz_init`.
+    pub unsafe fn assign_to_mpz(&self, z: &mut gmp::mpz_t) {
+        // SAFETY: the caller guarantees that `z` is initialized.
+        unsafe { write_mpz(&self.data, self.sign == Sign::Minus, z) }
+    }
+}
@@ -2,6 +2,8 @@ use super::CheckedUnsignedAbs::{Negative, Positive};
 use super::Sign::{self, Minus, NoSign, Plus};
 use super::{BigInt, UnsignedAbs};
 
+use crate::biguint::{addmul3, submul3, IntDigits};
+
 use crate::{IsizePromotion, UsizePromotion};
 
 use core::iter::Product;
@@ -21,6 +23,67 @@ impl Mul<Self> for Sign {
     }
 }
 
+/// Adds `a * b` with the given sign to `acc` in place.
+pub(super) fn addmul_signed(acc: &mut BigInt, a: &BigInt, b: &BigInt, sign: Sign) {
+    let sign = sign * a.sign * b.sign;
+    if sign == NoSign {
+        return;
+    }
+    if acc.sign == NoSign || acc.sign == sign {
+        addmul3(&mut acc.data, a.data.digits(), b.data.digits());
+        acc.sign = sign;
+    } else if submul3(&mut acc.data, a.data.digits(), b.data.digits()) {
+        acc.sign = sign;
+    }
+    acc.normalize();
+}
+
+/// Polynomials with more coefficients than this are split in halves rather than evaluated by
+/// Horner's rule, so the work lands in a few large, balanced multiplications.
+const EVAL_POLY_SPLIT_THRESHOLD: usize = 64;
+
+/// Evaluates the polynomial with `coeffs` in ascending order of degree at `x`.
+pub(super) fn eval_poly(coeffs: &[BigInt], x: &BigInt) -> BigInt {
+    if coeffs.len() <= EVAL_POLY_SPLIT_THRESHOLD {
+        return horner(coeffs, x);
+    }
+
+    // `powers[k]` is `x^(2^k)`, up to the first split.
+    let mut powers = vec![x.clone()];
+    while powers.len() <= split_log2(coeffs.len()) {
+        let last = powers.last().unwrap();
+        powers.push(last * last);
+    }
+    eval_poly_split(coeffs, &powers)
+}
+
+fn horner(coeffs: &[BigInt], x: &BigInt) -> BigInt {
+    let mut coeffs = coeffs.iter().rev();
+    let mut acc = coeffs.next().cloned().unwrap_or_default();
+    for c in coeffs {
+        acc *= x;
+        acc += c;
+    }
+    acc
+}
+
+/// Returns `k` with `2^k` the largest power of two below `len`.
+const fn split_log2(len: usize) -> usize {
+    (usize::BITS - (len - 1).leading_zeros() - 1) as usize
+}
+
+/// Evaluates as `low(x) + x^m * high(x)`, with `m` the largest power of two below the length.
+fn eval_poly_split(coeffs: &[BigInt], powers: &[BigInt]) -> BigInt {
+    if coeffs.len() <= EVAL_POLY_SPLIT_THRESHOLD {
+        return horner(coeffs, &powers[0]);
+    }
+    let k = split_log2(coeffs.len());
+    let (low, high) = coeffs.split_at(1 << k);
+    let mut acc = eval_poly_split(low, powers);
+    acc.addmul_assign(&eval_poly_split(high, powers), &powers[k]);
+    acc
+}
+
 macro_rules! impl_mul {
     ($(impl Mul<$Other:ty> for $Self:ty;)*) => {$(
         impl Mul<$Other> for $Self {
@@ -0,0 +1,41 @@
+#![cfg(feature = "openssl")]
+#![cfg_attr(docsrs, doc(cfg(feature = "openssl")))]
+
+use super::{BigInt, Sign};
+
+use crate::biguint::openssl::to_bn;
+use crate::BigUint;
+
+use openssl::bn::{BigNum, BigNumRef};
+use openssl::error::ErrorStack;
+
+impl BigInt {
+    /// Creates a [`BigInt`] from an OpenSSL [`BigNumRef`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigInt;
+    /// use openssl::bn::BigNum;
+    ///
+    /// let bn = BigNum::from_dec_str("-1234567890123456789012345678901234567890").unwrap();
+    /// let x = BigInt::from_bn(&bn);
+    /// assert_eq!(x.to_string(), "-1234567890123456789012345678901234567890");
+    /// ```
+    #[must_use]
+    pub fn from_bn(bn: &BigNumRef) -> Self {
+        let sign = if bn.is_negative() {
+            Sign::Minus
+        } else {
+            Sign::Plus
+        };
+        Self::from_biguint(sign, BigUint::from_bytes_be(&bn.to_vec()))
+    }
+
+    /// Converts this [`BigInt`] to an OpenSSL [`BigNum`].
+    ///
+    /// This only fails if OpenSSL fails to allocate.
+    pub fn to_bn(&self) -> Result<BigNum, ErrorStack> {
+        to_bn(&self.data, self.sign == Sign::Minus)
+    }
+}
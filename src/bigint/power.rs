@@ -1,7 +1,7 @@
 use super::BigInt;
 use super::Sign::{self, Minus, Plus};
 
-use crate::BigUint;
+use crate::{BigUint, CapacityError};
 
 use num_integer::Integer;
 use num_traits::{Pow, Signed, Zero};
@@ -68,6 +68,16 @@ pow_impl!(usize);
 pow_impl!(u128);
 pow_impl!(BigUint);
 
+pub(super) fn checked_pow(x: &BigInt, exp: &BigUint, max_bits: u64) -> Option<BigInt> {
+    let magnitude = x.data.checked_pow_big(exp, max_bits)?;
+    Some(BigInt::from_biguint(powsign(x.sign, exp), magnitude))
+}
+
+pub(super) fn try_pow(x: &BigInt, exp: u32) -> Result<BigInt, CapacityError> {
+    let magnitude = x.data.try_pow(exp)?;
+    Ok(BigInt::from_biguint(powsign(x.sign, &exp), magnitude))
+}
+
 pub(super) fn modpow(x: &BigInt, exponent: &BigInt, modulus: &BigInt) -> BigInt {
     assert!(
         !exponent.is_negative(),
@@ -3,7 +3,10 @@
 
 use super::{BigInt, Sign};
 
-use serde::de::{Error, Unexpected};
+use crate::biguint::U32Visitor;
+
+use core::fmt;
+use serde::de::{Error, SeqAccess, Unexpected, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 impl Serialize for Sign {
@@ -55,7 +58,46 @@ impl<'de> Deserialize<'de> for BigInt {
     where
         D: Deserializer<'de>,
     {
-        let (sign, data) = Deserialize::deserialize(deserializer)?;
+        deserializer.deserialize_tuple(2, SignMagnitudeVisitor(U32Visitor::default()))
+    }
+}
+
+impl BigInt {
+    /// Deserializes a value like [`Deserialize`], but with a limit of `max_bits` bits on its
+    /// magnitude in place of the one set by [`set_serde_max_bits`](crate::set_serde_max_bits).
+    /// The limit is rounded up to a multiple of 32 bits.
+    pub fn deserialize_with_max_bits<'de, D>(
+        deserializer: D,
+        max_bits: u64,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let magnitude = U32Visitor::with_max_bits(max_bits);
+        deserializer.deserialize_tuple(2, SignMagnitudeVisitor(magnitude))
+    }
+}
+
+/// Deserializes the `(Sign, BigUint)` tuple of a `BigInt`, limiting the magnitude.
+struct SignMagnitudeVisitor(U32Visitor);
+
+impl<'de> Visitor<'de> for SignMagnitudeVisitor {
+    type Value = BigInt;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a tuple of a sign and a sequence of unsigned 32-bit numbers")
+    }
+
+    fn visit_seq<S>(self, mut seq: S) -> Result<BigInt, S::Error>
+    where
+        S: SeqAccess<'de>,
+    {
+        let sign = seq
+            .next_element::<Sign>()?
+            .ok_or_else(|| S::Error::invalid_length(0, &self))?;
+        let data = seq
+            .next_element_seed(self.0)?
+            .ok_or_else(|| S::Error::invalid_length(1, &self))?;
         Ok(BigInt::from_biguint(sign, data))
     }
 }
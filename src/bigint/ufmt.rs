@@ -0,0 +1,27 @@
+#![cfg(feature = "ufmt")]
+#![cfg_attr(docsrs, doc(cfg(feature = "ufmt")))]
+
+use super::{BigInt, Sign};
+
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+impl uDisplay for BigInt {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        if self.sign == Sign::Minus {
+            f.write_str("-")?;
+        }
+        uDisplay::fmt(&self.data, f)
+    }
+}
+
+impl uDebug for BigInt {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        uDisplay::fmt(self, f)
+    }
+}
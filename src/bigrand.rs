@@ -4,15 +4,16 @@
 
 use rand::distr::uniform::{Error as RandError, SampleBorrow, SampleUniform, UniformSampler};
 use rand::prelude::*;
+use rand::TryRng;
 
 use crate::BigInt;
 use crate::BigUint;
 use crate::Sign::{Minus, NoSign, Plus};
 
-use crate::biguint::biguint_from_vec;
+use crate::biguint::IntDigits;
 
 use num_integer::Integer;
-use num_traits::{ToPrimitive, Zero};
+use num_traits::{One, ToPrimitive, Zero};
 
 /// A trait for sampling random big integers.
 ///
@@ -37,6 +38,175 @@ pub trait RandBigInt {
     /// bound is inclusive; the upper bound is exclusive. Fails when
     /// the upper bound is not greater than the lower bound.
     fn random_bigint_range(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt;
+
+    /// Generate a random [`BigUint`] of the given bit size into `out`, reusing its
+    /// allocation. This yields the same value as [`random_biguint`](Self::random_biguint).
+    fn random_biguint_into(&mut self, out: &mut BigUint, bit_size: u64) {
+        *out = self.random_biguint(bit_size);
+    }
+
+    /// Generate a random [`BigUint`] less than the given bound into `out`, reusing its
+    /// allocation. Fails when the bound is zero.
+    fn random_biguint_below_into(&mut self, out: &mut BigUint, bound: &BigUint) {
+        *out = self.random_biguint_below(bound);
+    }
+
+    /// Generate a random [`BigUint`] within the given range into `out`, reusing its
+    /// allocation. The lower bound is inclusive; the upper bound is exclusive. Fails when
+    /// the upper bound is not greater than the lower bound.
+    fn random_biguint_range_into(&mut self, out: &mut BigUint, lbound: &BigUint, ubound: &BigUint) {
+        *out = self.random_biguint_range(lbound, ubound);
+    }
+
+    /// Generate a random [`BigUint`] of exactly the given bit size, with the most significant
+    /// bit always set, such as for an `n`-bit modulus. A bit size of zero yields zero.
+    fn random_biguint_exact_bits(&mut self, bit_size: u64) -> BigUint {
+        let mut n = self.random_biguint(bit_size);
+        if bit_size > 0 {
+            n.set_bit(bit_size - 1, true);
+        }
+        n
+    }
+
+    /// Generate a random odd [`BigUint`] of at most the given bit size, such as a prime
+    /// candidate or a Montgomery modulus.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_size` is zero, since there are no odd values in that range.
+    fn random_odd_biguint(&mut self, bit_size: u64) -> BigUint {
+        assert!(bit_size > 0, "no odd values of zero bits");
+        let mut n = self.random_biguint(bit_size);
+        n.set_bit(0, true);
+        n
+    }
+
+    /// Generate a random prime of exactly the given bit size, proven prime by construction
+    /// rather than by a probabilistic test.
+    ///
+    /// This is Maurer's recursive method: a provable prime `q` of about half the size is
+    /// generated first, and candidates `n = 2Rq + 1` with random `R` are accepted by
+    /// Pocklington's criterion, which holds for a base `a` with `a^(n-1) ≡ 1 (mod n)` and
+    /// `gcd(a^(2R) - 1, n) = 1` since `q > √n`. Primes of up to 64 bits are found directly
+    /// with the exact [`BigUint::is_prime_deterministic`]. The primes are not uniformly
+    /// distributed, but every one returned is certainly prime.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bit_size` is less than two, since there are no smaller primes.
+    fn random_provable_prime(&mut self, bit_size: u64) -> BigUint {
+        assert!(bit_size >= 2, "no primes of fewer than two bits");
+        provable_prime(self, bit_size)
+    }
+
+    /// Generate a random [`BigUint`] below `modulus` that is coprime to it, i.e. a uniformly
+    /// random invertible element modulo `modulus`, such as an RSA blinding factor.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    fn random_biguint_coprime(&mut self, modulus: &BigUint) -> BigUint {
+        assert!(!modulus.is_zero(), "modulus must be non-zero");
+        if modulus.is_one() {
+            // Everything is congruent to zero, which is its own inverse.
+            return BigUint::ZERO;
+        }
+        let even = modulus.is_even();
+        loop {
+            let n = self.random_biguint_below(modulus);
+            // Rejecting shared factors of two is much cheaper than the full gcd.
+            if even && n.is_even() {
+                continue;
+            }
+            if n.gcd(modulus).is_one() {
+                return n;
+            }
+        }
+    }
+
+    /// Generate a random non-zero [`BigInt`] whose magnitude has exactly the given bit size,
+    /// with a random sign. A bit size of zero yields zero.
+    fn random_bigint_exact_bits(&mut self, bit_size: u64) -> BigInt {
+        let n = self.random_biguint_exact_bits(bit_size);
+        let sign = if n.is_zero() {
+            NoSign
+        } else if self.random_biguint(1).is_zero() {
+            Plus
+        } else {
+            Minus
+        };
+        BigInt::from_biguint(sign, n)
+    }
+
+    /// Generate a random [`BigUint`] within the given range. Both bounds
+    /// are inclusive. Fails when the upper bound is less than the lower bound.
+    fn random_biguint_range_inclusive(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint {
+        assert!(*lbound <= *ubound);
+        lbound + self.random_biguint_below(&(ubound - lbound + 1u32))
+    }
+
+    /// Generate a random [`BigInt`] within the given range. Both bounds
+    /// are inclusive. Fails when the upper bound is less than the lower bound.
+    fn random_bigint_range_inclusive(&mut self, lbound: &BigInt, ubound: &BigInt) -> BigInt {
+        assert!(*lbound <= *ubound);
+        let len = (ubound - lbound).into_parts().1 + 1u32;
+        lbound + BigInt::from(self.random_biguint_below(&len))
+    }
+}
+
+/// The odd primes below 256, for rejecting most candidates in [`provable_prime`] cheaply.
+const SIEVE_PRIMES: [u8; 53] = [
+    3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83, 89, 97,
+    101, 103, 107, 109, 113, 127, 131, 137, 139, 149, 151, 157, 163, 167, 173, 179, 181, 191, 193,
+    197, 199, 211, 223, 227, 229, 233, 239, 241, 251,
+];
+
+/// Maurer's construction for [`RandBigInt::random_provable_prime`].
+fn provable_prime<R: RandBigInt + ?Sized>(rng: &mut R, bits: u64) -> BigUint {
+    if bits <= 64 {
+        loop {
+            let n = rng.random_biguint_exact_bits(bits);
+            if n.is_prime_deterministic() {
+                return n;
+            }
+        }
+    }
+
+    // With `q` at least `2^(bits/2 + 1)`, it exceeds the square root of any `bits`-bit `n`.
+    let q = provable_prime(rng, bits / 2 + 2);
+    let two_q = &q << 1u8;
+    let low = BigUint::one() << (bits - 1);
+    let r_low = Integer::div_ceil(&(&low - 1u8), &two_q);
+    let r_high = ((low << 1u8) - 2u8) / &two_q;
+    let two = BigUint::from(2u8);
+    loop {
+        let r = rng.random_biguint_range_inclusive(&r_low, &r_high);
+        let n = &r * &two_q + 1u8;
+        if SIEVE_PRIMES.iter().any(|&p| (&n % p).is_zero()) {
+            continue;
+        }
+        let n1 = &n - 1u8;
+        // The single base 2 decides almost every candidate; the rare primes for which
+        // `2^(2R) ≡ 1` are simply skipped.
+        if two.modpow(&n1, &n).is_one()
+            && ((two.modpow(&(r << 1u8), &n) + &n1) % &n).gcd(&n).is_one()
+        {
+            return n;
+        }
+    }
+}
+
+/// Returns a value in `0..=max` with every value equally likely, by rejecting draws with more
+/// than the bits of `max` masked off that exceed it. Each draw is kept with probability over
+/// one half, and a draw of zero is always kept.
+fn random_u64_to<R: Rng + ?Sized>(rng: &mut R, max: u64) -> u64 {
+    let mask = u64::MAX.checked_shr(max.leading_zeros()).unwrap_or(0);
+    loop {
+        let n = rng.random::<u64>() & mask;
+        if n <= max {
+            return n;
+        }
+    }
 }
 
 fn random_bits<R: Rng + ?Sized>(rng: &mut R, data: &mut [u32], rem: u64) {
@@ -49,18 +219,26 @@ fn random_bits<R: Rng + ?Sized>(rng: &mut R, data: &mut [u32], rem: u64) {
 }
 
 impl<R: Rng + ?Sized> RandBigInt for R {
+    fn random_biguint(&mut self, bit_size: u64) -> BigUint {
+        let mut n = BigUint::ZERO;
+        self.random_biguint_into(&mut n, bit_size);
+        n
+    }
+
     cfg_digit!(
-        fn random_biguint(&mut self, bit_size: u64) -> BigUint {
+        fn random_biguint_into(&mut self, out: &mut BigUint, bit_size: u64) {
             let (digits, rem) = bit_size.div_rem(&32);
             let len = (digits + (rem > 0) as u64)
                 .to_usize()
                 .expect("capacity overflow");
-            let mut data = vec![0u32; len];
-            random_bits(self, &mut data, rem);
-            biguint_from_vec(data)
+            let data = out.digits_mut();
+            data.clear();
+            data.resize(len, 0);
+            random_bits(self, data, rem);
+            out.normalize();
         }
 
-        fn random_biguint(&mut self, bit_size: u64) -> BigUint {
+        fn random_biguint_into(&mut self, out: &mut BigUint, bit_size: u64) {
             use core::slice;
 
             let (digits, rem) = bit_size.div_rem(&32);
@@ -69,7 +247,9 @@ impl<R: Rng + ?Sized> RandBigInt for R {
                 .expect("capacity overflow");
             let native_digits = Integer::div_ceil(&bit_size, &64);
             let native_len = native_digits.to_usize().expect("capacity overflow");
-            let mut data = vec![0u64; native_len];
+            let data = out.digits_mut();
+            data.clear();
+            data.resize(native_len, 0);
             unsafe {
                 // Generate bits in a `&mut [u32]` slice for value stability
                 let ptr = data.as_mut_ptr().cast::<u32>();
@@ -78,11 +258,11 @@ impl<R: Rng + ?Sized> RandBigInt for R {
                 random_bits(self, data, rem);
             }
             #[cfg(target_endian = "big")]
-            for digit in &mut data {
+            for digit in data.iter_mut() {
                 // swap u32 digits into u64 endianness
                 *digit = (*digit << 32) | (*digit >> 32);
             }
-            biguint_from_vec(data)
+            out.normalize();
         }
     );
 
@@ -110,22 +290,57 @@ impl<R: Rng + ?Sized> RandBigInt for R {
     }
 
     fn random_biguint_below(&mut self, bound: &BigUint) -> BigUint {
+        let mut n = BigUint::ZERO;
+        self.random_biguint_below_into(&mut n, bound);
+        n
+    }
+
+    fn random_biguint_below_into(&mut self, out: &mut BigUint, bound: &BigUint) {
         assert!(!bound.is_zero());
         let bits = bound.bits();
+        if bits <= 64 {
+            let n = random_u64_to(self, bound.to_u64().unwrap() - 1);
+            out.digits_mut().clear();
+            *out += n;
+            return;
+        }
+
+        // Plain rejection sampling of `bits`-bit values could discard up to half of them.
+        // Instead, draw the top 64 bits of the bound's range uniformly, which wastes at most
+        // a few `u64`s, and fill in the low bits freely. Only when the top
+        // bits tie with the bound (probability below 2^-63) might the low bits overshoot,
+        // and then we start over, which keeps the result exactly uniform.
+        let shift = bits - 64;
+        let top_bound = (bound >> shift).to_u64().unwrap();
         loop {
-            let n = self.random_biguint(bits);
-            if n < *bound {
-                return n;
+            let top = random_u64_to(self, top_bound);
+            self.random_biguint_into(out, shift);
+            if top == top_bound && *out >= bound - (BigUint::from(top_bound) << shift) {
+                continue;
+            }
+            // Set the high bits from the top down, so the digits only grow once.
+            for i in (0..64).rev() {
+                if (top >> i) & 1 == 1 {
+                    out.set_bit(shift + i, true);
+                }
             }
+            return;
         }
     }
 
     fn random_biguint_range(&mut self, lbound: &BigUint, ubound: &BigUint) -> BigUint {
+        let mut n = BigUint::ZERO;
+        self.random_biguint_range_into(&mut n, lbound, ubound);
+        n
+    }
+
+    fn random_biguint_range_into(&mut self, out: &mut BigUint, lbound: &BigUint, ubound: &BigUint) {
         assert!(*lbound < *ubound);
         if lbound.is_zero() {
-            self.random_biguint_below(ubound)
+            self.random_biguint_below_into(out, ubound);
         } else {
-            lbound + self.random_biguint_below(&(ubound - lbound))
+            self.random_biguint_below_into(out, &(ubound - lbound));
+            *out += lbound;
         }
     }
 
@@ -142,6 +357,128 @@ impl<R: Rng + ?Sized> RandBigInt for R {
     }
 }
 
+/// A trait for sampling random big integers from a fallible [`TryRng`], such as a
+/// hardware or OS source, returning its error instead of panicking.
+///
+/// For generators that never fail, each method yields the same value as its counterpart in
+/// [`RandBigInt`]. The `rand` feature must be enabled to use this.
+pub trait TryRandBigInt {
+    /// The error reported by the underlying generator.
+    type Error;
+
+    /// Generate a random [`BigUint`] of the given bit size.
+    fn try_random_biguint(&mut self, bit_size: u64) -> Result<BigUint, Self::Error>;
+
+    /// Generate a random [`BigInt`] of the given bit size.
+    fn try_random_bigint(&mut self, bit_size: u64) -> Result<BigInt, Self::Error>;
+
+    /// Generate a random [`BigUint`] less than the given bound. Fails
+    /// when the bound is zero.
+    fn try_random_biguint_below(&mut self, bound: &BigUint) -> Result<BigUint, Self::Error>;
+
+    /// Generate a random [`BigUint`] within the given range. The lower
+    /// bound is inclusive; the upper bound is exclusive. Fails when
+    /// the upper bound is not greater than the lower bound.
+    fn try_random_biguint_range(
+        &mut self,
+        lbound: &BigUint,
+        ubound: &BigUint,
+    ) -> Result<BigUint, Self::Error>;
+
+    /// Generate a random [`BigInt`] within the given range. The lower
+    /// bound is inclusive; the upper bound is exclusive. Fails when
+    /// the upper bound is not greater than the lower bound.
+    fn try_random_bigint_range(
+        &mut self,
+        lbound: &BigInt,
+        ubound: &BigInt,
+    ) -> Result<BigInt, Self::Error>;
+}
+
+/// Adapts a [`TryRng`] to an infallible [`Rng`] by keeping the first error and producing
+/// zeros from then on, so the sampling in [`RandBigInt`] can be reused as-is.
+///
+/// Zeros never trigger a retry in the samplers used here, so they always finish promptly
+/// once the generator has failed, and the partial result is discarded.
+struct ErrorTrap<'a, R: TryRng + ?Sized> {
+    rng: &'a mut R,
+    error: Option<R::Error>,
+}
+
+impl<'a, R: TryRng + ?Sized> ErrorTrap<'a, R> {
+    fn run<T>(rng: &'a mut R, f: impl FnOnce(&mut Self) -> T) -> Result<T, R::Error> {
+        let mut trap = Self { rng, error: None };
+        let value = f(&mut trap);
+        trap.error.map_or(Ok(value), Err)
+    }
+
+    fn trap<T: Default>(&mut self, f: impl FnOnce(&mut R) -> Result<T, R::Error>) -> T {
+        if self.error.is_none() {
+            match f(self.rng) {
+                Ok(value) => return value,
+                Err(error) => self.error = Some(error),
+            }
+        }
+        T::default()
+    }
+}
+
+impl<R: TryRng + ?Sized> TryRng for ErrorTrap<'_, R> {
+    type Error = core::convert::Infallible;
+
+    fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+        Ok(self.trap(R::try_next_u32))
+    }
+
+    fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+        Ok(self.trap(R::try_next_u64))
+    }
+
+    fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+        if self.error.is_none() {
+            if let Err(error) = self.rng.try_fill_bytes(dst) {
+                self.error = Some(error);
+            }
+        }
+        if self.error.is_some() {
+            dst.fill(0);
+        }
+        Ok(())
+    }
+}
+
+impl<R: TryRng + ?Sized> TryRandBigInt for R {
+    type Error = R::Error;
+
+    fn try_random_biguint(&mut self, bit_size: u64) -> Result<BigUint, Self::Error> {
+        ErrorTrap::run(self, |rng| rng.random_biguint(bit_size))
+    }
+
+    fn try_random_bigint(&mut self, bit_size: u64) -> Result<BigInt, Self::Error> {
+        ErrorTrap::run(self, |rng| rng.random_bigint(bit_size))
+    }
+
+    fn try_random_biguint_below(&mut self, bound: &BigUint) -> Result<BigUint, Self::Error> {
+        ErrorTrap::run(self, |rng| rng.random_biguint_below(bound))
+    }
+
+    fn try_random_biguint_range(
+        &mut self,
+        lbound: &BigUint,
+        ubound: &BigUint,
+    ) -> Result<BigUint, Self::Error> {
+        ErrorTrap::run(self, |rng| rng.random_biguint_range(lbound, ubound))
+    }
+
+    fn try_random_bigint_range(
+        &mut self,
+        lbound: &BigInt,
+        ubound: &BigInt,
+    ) -> Result<BigInt, Self::Error> {
+        ErrorTrap::run(self, |rng| rng.random_bigint_range(lbound, ubound))
+    }
+}
+
 /// The back-end implementing rand's [`UniformSampler`] for [`BigUint`].
 #[derive(Clone, Debug)]
 pub struct UniformBigUint {
@@ -304,6 +641,137 @@ impl Distribution<BigInt> for RandomBits {
     }
 }
 
+/// A random distribution for [`BigUint`] and [`BigInt`] values whose bit size is itself drawn
+/// from another distribution, and then sampled uniformly at that size.
+///
+/// This produces a mix of small and huge values, as wanted by fuzzers and stress tests. Any
+/// `Distribution<u64>` can choose the bit size, or see [`RandomBitsWeighted::geometric`].
+///
+/// The `rand` feature must be enabled to use this. See crate-level documentation for details.
+#[derive(Clone, Copy, Debug)]
+pub struct RandomBitsWeighted<D = GeometricBits> {
+    bits: D,
+}
+
+impl<D: Distribution<u64>> RandomBitsWeighted<D> {
+    /// Creates a distribution that draws each bit size from `bits`.
+    #[must_use]
+    #[inline]
+    pub const fn new(bits: D) -> Self {
+        Self { bits }
+    }
+}
+
+impl RandomBitsWeighted<GeometricBits> {
+    /// Creates a distribution whose bit sizes are geometrically weighted toward small values,
+    /// up to `max_bits`. See [`GeometricBits`].
+    #[must_use]
+    #[inline]
+    pub const fn geometric(max_bits: u64) -> Self {
+        Self::new(GeometricBits::new(max_bits))
+    }
+}
+
+impl<D: Distribution<u64>> Distribution<BigUint> for RandomBitsWeighted<D> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigUint {
+        let bits = self.bits.sample(rng);
+        rng.random_biguint(bits)
+    }
+}
+
+impl<D: Distribution<u64>> Distribution<BigInt> for RandomBitsWeighted<D> {
+    #[inline]
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> BigInt {
+        let bits = self.bits.sample(rng);
+        rng.random_bigint(bits)
+    }
+}
+
+/// A distribution of bit sizes up to a maximum, where each doubling of the size is half as
+/// likely as the last.
+///
+/// A scale `k` is chosen with probability `2^-(k+1)`, and then the bit size is uniform in
+/// `0..=2^k`, capped at the maximum. Any excess probability falls on the largest scale.
+#[derive(Clone, Copy, Debug)]
+pub struct GeometricBits {
+    max_bits: u64,
+}
+
+impl GeometricBits {
+    /// Creates a distribution of bit sizes in `0..=max_bits`.
+    #[must_use]
+    #[inline]
+    pub const fn new(max_bits: u64) -> Self {
+        Self { max_bits }
+    }
+}
+
+impl Distribution<u64> for GeometricBits {
+    fn sample<R: Rng + ?Sized>(&self, rng: &mut R) -> u64 {
+        // The smallest scale whose range covers `max_bits`.
+        let max_scale = 64 - self.max_bits.saturating_sub(1).leading_zeros();
+        let mut scale = 0;
+        while scale < max_scale && rng.random::<bool>() {
+            scale += 1;
+        }
+        let limit = Ord::min(1u64 << scale, self.max_bits);
+        rng.random_range(0..=limit)
+    }
+}
+
+impl BigUint {
+    /// Returns `self ^ exponent % modulus` computed on a randomly blinded base, as in RSA
+    /// private-key operations.
+    ///
+    /// A random unit `r` is drawn from `rng`, the base is multiplied by
+    /// `r ^ public_exponent`, the product is raised to `exponent`, and the result is
+    /// multiplied by `r⁻¹`. The exponentiation then never sees the actual base, so its timing
+    /// reveals nothing about it. The unblinding is only correct when the two exponents are
+    /// inverses, i.e. `(x ^ public_exponent) ^ exponent ≡ x (mod modulus)` for all `x`, as
+    /// for an RSA key pair `(e, d)`.
+    ///
+    /// The `rand` feature must be enabled to use this. See crate-level documentation for
+    /// details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(61u16 * 53);
+    /// let (e, d) = (BigUint::from(17u8), BigUint::from(2753u16));
+    /// let c = BigUint::from(2790u16);
+    /// let m = c.modpow_blinded(&d, &e, &n, &mut rand::rng());
+    /// assert_eq!(m, c.modpow(&d, &n));
+    /// ```
+    #[must_use]
+    pub fn modpow_blinded<R: Rng + ?Sized>(
+        &self,
+        exponent: &Self,
+        public_exponent: &Self,
+        modulus: &Self,
+        rng: &mut R,
+    ) -> Self {
+        assert!(
+            !modulus.is_zero(),
+            "attempt to calculate with zero modulus!"
+        );
+        if modulus.is_one() {
+            return Self::ZERO;
+        }
+
+        // The blinding factor must be invertible for the unblinding step, which
+        // `random_biguint_coprime` ensures by drawing again until it is.
+        let r = rng.random_biguint_coprime(modulus);
+        let blinded = self * r.modpow(public_exponent, modulus) % modulus;
+        let unblind = r.modinv(modulus).unwrap();
+        blinded.modpow(exponent, modulus) * unblind % modulus
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -318,6 +786,232 @@ mod test {
         assert!(a_random_biguint < maxval);
     }
 
+    #[test]
+    fn test_rand_biguint_into_matches() {
+        use rand::rngs::StdRng;
+
+        let mut rng1 = StdRng::seed_from_u64(42);
+        let mut rng2 = StdRng::seed_from_u64(42);
+        let mut out = BigUint::from(u64::MAX) << 1000u32;
+        for bits in [0, 1, 31, 32, 33, 63, 64, 65, 100, 1000, 3] {
+            rng1.random_biguint_into(&mut out, bits);
+            assert_eq!(out, rng2.random_biguint(bits));
+            assert!(out.bits() <= bits);
+        }
+
+        let bound = BigUint::from(1000u32);
+        let lbound = BigUint::from(500u32);
+        for _ in 0..10 {
+            rng1.random_biguint_below_into(&mut out, &bound);
+            assert_eq!(out, rng2.random_biguint_below(&bound));
+            rng1.random_biguint_range_into(&mut out, &lbound, &bound);
+            assert_eq!(out, rng2.random_biguint_range(&lbound, &bound));
+            assert!(lbound <= out && out < bound);
+        }
+    }
+
+    #[test]
+    fn test_rand_exact_bits() {
+        let mut rng = rand::rng();
+        assert!(rng.random_biguint_exact_bits(0).is_zero());
+        assert!(rng.random_bigint_exact_bits(0).is_zero());
+        assert_eq!(rng.random_biguint_exact_bits(1), BigUint::from(1u8));
+        for bits in [2, 31, 32, 33, 64, 65, 1000] {
+            for _ in 0..10 {
+                assert_eq!(rng.random_biguint_exact_bits(bits).bits(), bits);
+                assert_eq!(rng.random_bigint_exact_bits(bits).bits(), bits);
+            }
+        }
+    }
+
+    #[test]
+    fn test_rand_odd() {
+        let mut rng = rand::rng();
+        assert_eq!(rng.random_odd_biguint(1), BigUint::from(1u8));
+        for bits in [2, 32, 33, 64, 65, 1000] {
+            for _ in 0..10 {
+                let n = rng.random_odd_biguint(bits);
+                assert!(n.bit(0));
+                assert!(n.bits() <= bits);
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "no odd values of zero bits")]
+    fn test_rand_odd_zero_bits() {
+        rand::rng().random_odd_biguint(0);
+    }
+
+    #[test]
+    fn test_rand_coprime() {
+        let mut rng = rand::rng();
+        assert!(rng.random_biguint_coprime(&BigUint::one()).is_zero());
+        for m in [2u32, 3, 12, 30, 1 << 20, 1_000_000_007] {
+            let m = BigUint::from(m);
+            for _ in 0..20 {
+                let n = rng.random_biguint_coprime(&m);
+                assert!(n < m);
+                assert!(n.gcd(&m).is_one());
+            }
+        }
+        let m = BigUint::from(3u8).pow(200u32) * 2u8;
+        let n = rng.random_biguint_coprime(&m);
+        assert!(n.gcd(&m).is_one());
+    }
+
+    #[test]
+    fn test_modpow_blinded() {
+        let mut rng = rand::rng();
+        let (p, q) = (
+            BigUint::from(1_000_000_007u32),
+            BigUint::from(998_244_353u32),
+        );
+        let n = &p * &q;
+        let lambda = (&p - 1u32).lcm(&(&q - 1u32));
+        let e = BigUint::from(65_537u32);
+        let d = e.modinv(&lambda).unwrap();
+        for x in [0u64, 1, 2, 12_345, 1_000_000_007, u64::MAX] {
+            let x = BigUint::from(x);
+            assert_eq!(x.modpow_blinded(&d, &e, &n, &mut rng), x.modpow(&d, &n));
+        }
+        let one = BigUint::one();
+        assert!(d.modpow_blinded(&d, &e, &one, &mut rng).is_zero());
+
+        // With n = 15, the first blinding factor drawn is 3, which shares a factor with n and
+        // has no inverse, so another is drawn.
+        let n = BigUint::from(15u8);
+        let (d, e) = (BigUint::from(3u8), BigUint::from(3u8));
+        for x in 0u8..15 {
+            let x = BigUint::from(x);
+            let mut rng = CountingRng(3);
+            assert_eq!(x.modpow_blinded(&d, &e, &n, &mut rng), x.modpow(&d, &n));
+            assert_eq!(rng.0, 5);
+        }
+    }
+
+    #[test]
+    fn test_rand_provable_prime() {
+        let mut rng = rand::rng();
+        for bits in [2, 3, 8, 64, 65, 66, 100, 257, 512] {
+            let p = rng.random_provable_prime(bits);
+            assert_eq!(p.bits(), bits);
+            assert!(p.is_prime_deterministic());
+        }
+    }
+
+    #[test]
+    fn test_random_bits_weighted() {
+        let mut rng = rand::rng();
+        let dist = RandomBitsWeighted::geometric(1000);
+        let mut small = 0;
+        let mut large = 0;
+        for _ in 0..2000 {
+            let n: BigUint = rng.sample(dist);
+            assert!(n.bits() <= 1000);
+            if n.bits() <= 8 {
+                small += 1;
+            } else if n.bits() > 64 {
+                large += 1;
+            }
+            let i: BigInt = rng.sample(dist);
+            assert!(i.bits() <= 1000);
+        }
+        // Both ends of the range show up.
+        assert!(small > 0 && large > 0);
+
+        assert!((0..100).all(|_| rng.sample(GeometricBits::new(0)) == 0));
+        assert!((0..100).all(|_| rng.sample(GeometricBits::new(1)) <= 1));
+
+        let fixed = RandomBitsWeighted::new(rand::distr::Uniform::new(64, 65).unwrap());
+        let n: BigUint = rng.sample(fixed);
+        assert!(n.bits() <= 64);
+    }
+
+    #[test]
+    fn test_rand_below_distribution() {
+        let mut rng = rand::rng();
+
+        let bound = BigUint::from(6u8);
+        let mut counts = [0u32; 6];
+        for _ in 0..6000 {
+            counts[rng.random_biguint_below(&bound).to_usize().unwrap()] += 1;
+        }
+        assert!(counts.iter().all(|c| (800..1200).contains(c)), "{counts:?}");
+
+        // A bound spanning several digits, just past a multiple of a power of two.
+        let shift = 100u32;
+        let bound = (BigUint::from(5u8) << shift) + 1u8;
+        let mut counts = [0u32; 6];
+        for _ in 0..5000 {
+            let n = rng.random_biguint_below(&bound);
+            assert!(n < bound);
+            counts[(n >> shift).to_usize().unwrap()] += 1;
+        }
+        // The top part is 5 only when the low part is zero, which is vanishingly rare.
+        assert!(
+            counts[..5].iter().all(|c| (800..1200).contains(c)),
+            "{counts:?}"
+        );
+        assert_eq!(counts[5], 0);
+
+        // The tie between the top bits and the bound is handled exactly.
+        let bound = (BigUint::from(u64::MAX) << 64u32) + 1u8;
+        for _ in 0..100 {
+            assert!(rng.random_biguint_below(&bound) < bound);
+        }
+        let one = BigUint::one();
+        assert!(rng.random_biguint_below(&one).is_zero());
+    }
+
+    /// Yields `n`, `n + 1`, `n + 2`, ... in turn.
+    struct CountingRng(u64);
+
+    impl TryRng for CountingRng {
+        type Error = core::convert::Infallible;
+
+        fn try_next_u32(&mut self) -> Result<u32, Self::Error> {
+            Ok(self.try_next_u64()? as u32)
+        }
+
+        fn try_next_u64(&mut self) -> Result<u64, Self::Error> {
+            self.0 += 1;
+            Ok(self.0 - 1)
+        }
+
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), Self::Error> {
+            for chunk in dst.chunks_mut(8) {
+                let bytes = self.try_next_u64()?.to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_rand_below_unbiased() {
+        // Every run of eight draws gives each value below a bound of six exactly once.
+        let mut rng = CountingRng(0);
+        let bound = BigUint::from(6u8);
+        let mut counts = [0u32; 6];
+        for _ in 0..60 {
+            counts[rng.random_biguint_below(&bound).to_usize().unwrap()] += 1;
+        }
+        assert_eq!(counts, [10; 6]);
+
+        // And a random generator is spread evenly over a bound of three.
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let bound = BigUint::from(3u8);
+        let mut counts = [0u32; 3];
+        for _ in 0..30_000 {
+            counts[rng.random_biguint_below(&bound).to_usize().unwrap()] += 1;
+        }
+        assert!(
+            counts.iter().all(|c| (9_500..10_500).contains(c)),
+            "{counts:?}"
+        );
+    }
+
     #[test]
     fn test_rand_bigint_range_works() {
         let mut rng = rand::rng();
@@ -327,4 +1021,101 @@ mod test {
         assert!(a_random_bigint >= minval);
         assert!(a_random_bigint < maxval);
     }
+
+    #[test]
+    fn test_rand_range_inclusive() {
+        let mut rng = rand::rng();
+
+        let n = BigUint::from(7u8);
+        assert_eq!(rng.random_biguint_range_inclusive(&n, &n), n);
+        let i = BigInt::from(-7);
+        assert_eq!(rng.random_bigint_range_inclusive(&i, &i), i);
+
+        let (mut low, mut high) = (false, false);
+        for _ in 0..1000 {
+            let x = rng.random_bigint_range_inclusive(&BigInt::from(-2), &BigInt::from(2));
+            assert!(x >= BigInt::from(-2) && x <= BigInt::from(2));
+            low |= x == BigInt::from(-2);
+            high |= x == BigInt::from(2);
+
+            let y = rng.random_biguint_range_inclusive(&BigUint::zero(), &BigUint::one());
+            assert!(y <= BigUint::one());
+        }
+        assert!(low && high);
+    }
+
+    #[test]
+    #[should_panic(expected = "assertion failed")]
+    fn test_rand_range_inclusive_empty() {
+        rand::rng().random_biguint_range_inclusive(&BigUint::one(), &BigUint::zero());
+    }
+
+    /// Yields a few words and then fails for good.
+    struct FailingRng(u32);
+
+    #[derive(Debug)]
+    struct RngFailure;
+
+    impl core::fmt::Display for RngFailure {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            f.write_str("rng failure")
+        }
+    }
+
+    impl core::error::Error for RngFailure {}
+
+    impl TryRng for FailingRng {
+        type Error = RngFailure;
+
+        fn try_next_u32(&mut self) -> Result<u32, RngFailure> {
+            Ok(self.try_next_u64()? as u32)
+        }
+
+        fn try_next_u64(&mut self) -> Result<u64, RngFailure> {
+            if self.0 == 0 {
+                return Err(RngFailure);
+            }
+            self.0 -= 1;
+            Ok(u64::MAX)
+        }
+
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> Result<(), RngFailure> {
+            for chunk in dst.chunks_mut(8) {
+                let bytes = self.try_next_u64()?.to_le_bytes();
+                chunk.copy_from_slice(&bytes[..chunk.len()]);
+            }
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_try_rand() {
+        let mut rng = FailingRng(1);
+        assert_eq!(rng.try_random_biguint(64).unwrap(), BigUint::from(u64::MAX));
+        assert!(rng.try_random_biguint(64).is_err());
+
+        let bound = BigUint::one() << 1000u32;
+        let lbound = BigInt::from(-1) << 1000u32;
+        let ubound = BigInt::one() << 1000u32;
+        for words in 0..4 {
+            assert!(FailingRng(words).try_random_biguint(1000).is_err());
+            assert!(FailingRng(words).try_random_bigint(1000).is_err());
+            assert!(FailingRng(words).try_random_biguint_below(&bound).is_err());
+            assert!(FailingRng(words)
+                .try_random_biguint_range(&bound, &(&bound << 1u8))
+                .is_err());
+            assert!(FailingRng(words)
+                .try_random_bigint_range(&lbound, &ubound)
+                .is_err());
+        }
+
+        // Infallible generators give the same values as `RandBigInt`.
+        let mut a = rand::rngs::StdRng::seed_from_u64(1);
+        let mut b = rand::rngs::StdRng::seed_from_u64(1);
+        assert_eq!(
+            a.try_random_biguint_below(&bound).unwrap(),
+            b.random_biguint_below(&bound)
+        );
+        assert_eq!(a.try_random_bigint(100).unwrap(), b.random_bigint(100));
+    }
 }
@@ -1,4 +1,6 @@
 use crate::big_digit::{self, BigDigit};
+use crate::range::{BigRange, BigRangeInclusive};
+use crate::{Cancelled, CapacityError, DigitOrder, Exactness, RoundingMode};
 
 use alloc::string::String;
 use alloc::vec::Vec;
@@ -8,7 +10,9 @@ use core::default::Default;
 use core::fmt;
 use core::hash;
 use core::mem;
+use core::ops::RangeBounds;
 use core::str;
+use core::sync::atomic::AtomicBool;
 
 use num_integer::{Integer, Roots};
 use num_traits::{ConstZero, Num, One, Pow, ToPrimitive, Unsigned, Zero};
@@ -16,19 +20,70 @@ use num_traits::{ConstZero, Num, One, Pow, ToPrimitive, Unsigned, Zero};
 mod addition;
 mod division;
 mod multiplication;
+mod scratch;
 mod subtraction;
 
+mod accumulator;
+mod alloy_rlp;
 mod arbitrary;
 mod bits;
+mod bitvec;
+mod cancel;
 mod convert;
+mod crypto_bigint;
+mod defmt;
+mod divsteps;
+mod ethnum;
+mod expr;
+mod factor;
+mod gcd;
+mod getrandom;
+pub(crate) mod gmp;
 mod iter;
-mod monty;
+pub mod lowlevel;
+pub(crate) mod monty;
+mod nonzero;
+mod odd;
+pub(crate) mod openssl;
 mod power;
+mod pratt;
+mod prime;
+mod primitive_types;
+mod reserve;
+mod scale;
 mod serde;
+mod shared;
 mod shift;
+mod slice;
+mod sqrt;
+mod stack;
+mod ufmt;
 
+pub use self::accumulator::Accumulator;
 pub(crate) use self::convert::to_str_radix_reversed;
+pub use self::division::PreparedDivisor;
+pub use self::expr::{expr, Expr};
+pub use self::factor::{Divisors, Factorization, SortedDivisors};
+pub(crate) use self::gcd::gcd_cofactor;
 pub use self::iter::{U32Digits, U64Digits};
+pub(crate) use self::multiplication::{addmul3, submul3};
+pub use self::nonzero::NonZeroBigUint;
+pub use self::odd::OddModulus;
+pub use self::pratt::PrattCertificate;
+pub use self::prime::Primes;
+#[cfg(feature = "serde")]
+pub(crate) use self::serde::U32Visitor;
+#[cfg(feature = "serde")]
+pub use self::serde::{serde_max_bits, set_serde_max_bits, DEFAULT_SERDE_MAX_BITS};
+#[cfg(target_has_atomic = "ptr")]
+pub use self::shared::ArcBigUint;
+pub(crate) use self::shift::shr_round;
+pub use self::slice::BigUintSlice;
+pub use self::stack::StackBigUint;
+
+use self::addition::__add2;
+use self::multiplication::mul3_into;
+use self::subtraction::sub2;
 
 /// A big unsigned integer type.
 pub struct BigUint {
@@ -51,11 +106,22 @@ impl Clone for BigUint {
     }
 }
 
+/// Values that fit in a `u64` hash exactly like that `u64`, so that hash-based collections
+/// treat them consistently with primitive keys. Larger values hash their length followed by
+/// their `u64` digits, least significant first, independent of the internal digit size.
 impl hash::Hash for BigUint {
     #[inline]
     fn hash<H: hash::Hasher>(&self, state: &mut H) {
         debug_assert!(self.data.last() != Some(&0));
-        self.data.hash(state);
+        if let Some(n) = self.to_u64() {
+            n.hash(state);
+        } else {
+            let digits = self.iter_u64_digits();
+            state.write_usize(digits.len());
+            for digit in digits {
+                state.write_u64(digit);
+            }
+        }
     }
 }
 
@@ -249,7 +315,7 @@ impl Integer for BigUint {
             m -= &n;
         }
 
-        n << shift
+        n.into_shl_unchecked(shift)
     }
 
     /// Calculates the Lowest Common Multiple (LCM) of the number and `other`.
@@ -270,7 +336,13 @@ impl Integer for BigUint {
         let lcm = if gcd.is_zero() {
             Self::ZERO
         } else {
-            self / &gcd * other
+            // The gcd divides both exactly, so divide the shorter one.
+            let (short, long) = if self.data.len() <= other.data.len() {
+                (self, other)
+            } else {
+                (other, self)
+            };
+            division::div_exact(short, &gcd) * long
         };
         (gcd, lcm)
     }
@@ -344,7 +416,7 @@ where
         // powers, and then take a long time to walk back.  We know an upper
         // bound based on bit size, so saturate on that.
         x = if xn.bits() > max_bits {
-            BigUint::one() << max_bits
+            BigUint::one().into_shl_unchecked(max_bits)
         } else {
             xn
         };
@@ -409,15 +481,15 @@ impl Roots for BigUint {
                 let root_scale = Integer::div_ceil(&extra_bits, &n64);
                 let scale = root_scale * n64;
                 if scale < bits && bits - scale > n64 {
-                    (self >> scale).nth_root(n) << root_scale
+                    (self >> scale).nth_root(n).into_shl_unchecked(root_scale)
                 } else {
-                    Self::one() << max_bits
+                    Self::one().into_shl_unchecked(max_bits)
                 }
             }
         };
 
         #[cfg(not(feature = "std"))]
-        let guess = Self::one() << max_bits;
+        let guess = Self::one().into_shl_unchecked(max_bits);
 
         let n_min_1 = n - 1;
         fixpoint(guess, max_bits, move |s| {
@@ -440,6 +512,9 @@ impl Roots for BigUint {
         }
 
         let bits = self.bits();
+        if bits > sqrt::NEWTON_THRESHOLD_BITS {
+            return sqrt::sqrt_rem(self).0;
+        }
         let max_bits = bits / 2 + 1;
 
         #[cfg(feature = "std")]
@@ -456,12 +531,12 @@ impl Roots for BigUint {
                 let extra_bits = bits - (f64::MAX_EXP as u64 - 1);
                 let root_scale = (extra_bits + 1) / 2;
                 let scale = root_scale * 2;
-                (self >> scale).sqrt() << root_scale
+                (self >> scale).sqrt().into_shl_unchecked(root_scale)
             }
         };
 
         #[cfg(not(feature = "std"))]
-        let guess = Self::one() << max_bits;
+        let guess = Self::one().into_shl_unchecked(max_bits);
 
         fixpoint(guess, max_bits, move |s| {
             let q = self / s;
@@ -497,16 +572,16 @@ impl Roots for BigUint {
                 let extra_bits = bits - (f64::MAX_EXP as u64 - 1);
                 let root_scale = (extra_bits + 2) / 3;
                 let scale = root_scale * 3;
-                (self >> scale).cbrt() << root_scale
+                (self >> scale).cbrt().into_shl_unchecked(root_scale)
             }
         };
 
         #[cfg(not(feature = "std"))]
-        let guess = Self::one() << max_bits;
+        let guess = Self::one().into_shl_unchecked(max_bits);
 
         fixpoint(guess, max_bits, move |s| {
             let q = self / (s * s);
-            let t = (s << 1) + q;
+            let t = s.shl_unchecked(1) + q;
             t / 3u32
         })
     }
@@ -526,6 +601,27 @@ pub(crate) fn biguint_from_vec(digits: Vec<BigDigit>) -> BigUint {
     BigUint { data: digits }.normalized()
 }
 
+/// Creates and initializes a [`BigUint`] from `u64` digits.
+///
+/// The digits are in little-endian base 2<sup>64</sup>.
+pub(super) fn biguint_from_u64_digits<I>(digits: I) -> BigUint
+where
+    I: IntoIterator<Item = u64>,
+{
+    let digits = digits.into_iter();
+    let mut data = Vec::with_capacity(digits.size_hint().0 * (64 / big_digit::BITS as usize));
+    for digit in digits {
+        cfg_digit_expr!(
+            {
+                data.push(digit as u32);
+                data.push((digit >> 32) as u32);
+            },
+            data.push(digit)
+        );
+    }
+    biguint_from_vec(data)
+}
+
 impl BigUint {
     /// A constant `BigUint` with value 0, useful for static initialization.
     pub const ZERO: Self = Self { data: Vec::new() };
@@ -575,6 +671,61 @@ impl BigUint {
         self.normalize();
     }
 
+    /// Returns `2^bits - 1`, the number whose low `bits` bits are all ones.
+    ///
+    /// This builds the digits directly, without the shift and subtraction of
+    /// `(BigUint::one() << bits) - 1u8`. Panics if the result would exceed the size limit of
+    /// [`limits`](crate::limits).
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::mask(0), BigUint::from(0u8));
+    /// assert_eq!(BigUint::mask(12), BigUint::from(0xfffu16));
+    /// assert_eq!(BigUint::mask(300).count_ones(), 300);
+    /// ```
+    #[must_use]
+    pub fn mask(bits: u64) -> Self {
+        crate::limits::check(bits);
+        let (digits, rem) = Self::split_bits(bits);
+        let mut data = alloc::vec![big_digit::MAX; digits];
+        if rem > 0 {
+            data.push(big_digit::MAX >> (big_digit::BITS - rem));
+        }
+        biguint_from_vec(data)
+    }
+
+    /// Returns `2^bits`, the number with only bit `bits` set.
+    ///
+    /// This builds the digits directly, without the shift of `BigUint::one() << bits`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the result would exceed the size limit of [`limits`](crate::limits), or could
+    /// not be stored at all.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::pow2(0), BigUint::from(1u8));
+    /// assert_eq!(BigUint::pow2(100), BigUint::from(1u128 << 100));
+    /// ```
+    #[must_use]
+    pub fn pow2(bits: u64) -> Self {
+        crate::limits::check(bits.checked_add(1).expect("capacity overflow"));
+        let (digits, rem) = Self::split_bits(bits);
+        let mut data = alloc::vec![0; digits];
+        data.push(1 << rem);
+        biguint_from_vec(data)
+    }
+
+    /// Returns the number of whole digits in `bits` bits, and the bits left over.
+    fn split_bits(bits: u64) -> (usize, u8) {
+        let digit_bits = u64::from(big_digit::BITS);
+        let digits = usize::try_from(bits / digit_bits).expect("capacity overflow");
+        (digits, u8::try_from(bits % digit_bits).unwrap())
+    }
+
     /// Creates and initializes a [`BigUint`].
     ///
     /// The bytes are in big-endian byte order.
@@ -683,6 +834,32 @@ impl BigUint {
         convert::from_radix_le(buf, radix)
     }
 
+    /// Creates a [`BigUint`] from digits in base `radix`, taken one at a time from an iterator
+    /// in the given order, or returns `None` if a digit is not less than `radix`.
+    ///
+    /// Unlike [`from_radix_be`](Self::from_radix_be), the digits are not collected first: they
+    /// are packed into words as they arrive, and the words are joined by halves so the work
+    /// stays subquadratic. No digits at all give zero.
+    /// `radix` must be in the range `2...256`.
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, DigitOrder};
+    ///
+    /// let digits = "31415926535".bytes().map(|c| c - b'0');
+    /// let n = BigUint::from_radix_digits_iter(digits, 10, DigitOrder::MostSignificantFirst);
+    /// assert_eq!(n, Some(BigUint::from(31_415_926_535u64)));
+    ///
+    /// let n = BigUint::from_radix_digits_iter([5, 3, 1], 8, DigitOrder::LeastSignificantFirst);
+    /// assert_eq!(n, Some(BigUint::from(0o135u8)));
+    /// ```
+    #[must_use]
+    pub fn from_radix_digits_iter<I>(digits: I, radix: u32, order: DigitOrder) -> Option<Self>
+    where
+        I: IntoIterator<Item = u8>,
+    {
+        convert::from_radix_digits_iter(digits, radix, order)
+    }
+
     /// Returns the byte representation of the [`BigUint`] in big-endian byte order.
     ///
     /// # Examples
@@ -721,6 +898,46 @@ impl BigUint {
         }
     }
 
+    /// Returns the [RLP] encoding used by Ethereum for scalars: the big-endian bytes without
+    /// leading zeros, with zero as the empty string.
+    ///
+    /// [RLP]: https://ethereum.org/developers/docs/data-structures-and-encoding/rlp/
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(0u8).to_rlp(), [0x80]);
+    /// assert_eq!(BigUint::from(0x7fu8).to_rlp(), [0x7f]);
+    /// assert_eq!(BigUint::from(1024u32).to_rlp(), [0x82, 0x04, 0x00]);
+    /// ```
+    #[must_use]
+    pub fn to_rlp(&self) -> Vec<u8> {
+        convert::to_rlp(self)
+    }
+
+    /// Reads an RLP-encoded scalar from the start of `bytes`, returning the value and the bytes
+    /// that follow it -- see [`BigUint::to_rlp`].
+    ///
+    /// Returns `None` if `bytes` is too short, holds a list, or is not in the canonical form:
+    /// leading zeros (including zero as the byte `0x00` rather than `0x80`), a single byte below
+    /// `0x80` with a prefix, or a long length that fits the short form are all rejected, as
+    /// Ethereum clients do.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (n, rest) = BigUint::from_rlp(&[0x82, 0x04, 0x00, 0xc0]).unwrap();
+    /// assert_eq!(n, BigUint::from(1024u32));
+    /// assert_eq!(rest, [0xc0]);
+    ///
+    /// assert_eq!(BigUint::from_rlp(&[0x82, 0x00, 0x04]), None);
+    /// assert_eq!(BigUint::from_rlp(&[0x81, 0x7f]), None);
+    /// ```
+    #[must_use]
+    pub fn from_rlp(bytes: &[u8]) -> Option<(Self, &[u8])> {
+        convert::from_rlp(bytes)
+    }
+
     /// Returns the `u32` digits representation of the [`BigUint`] ordered least significant digit
     /// first.
     ///
@@ -799,6 +1016,60 @@ impl BigUint {
         U64Digits::new(self.data.as_slice())
     }
 
+    /// Returns a borrowed view of the 64-bit limbs of `self` in `range`, least significant
+    /// limb first.
+    ///
+    /// Limbs past the top of the value read as zero, so the range may extend beyond it.
+    ///
+    /// Panics if the range starts after it ends.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(u128::MAX) + 1u8;
+    /// assert!(n.limb_window(..2).is_zero());
+    /// assert_eq!(n.limb_window(2..), BigUint::from(1u8));
+    /// ```
+    #[must_use]
+    pub fn limb_window<R: RangeBounds<usize>>(&self, range: R) -> BigUintSlice<'_> {
+        BigUintSlice::new(self, range)
+    }
+
+    /// Returns the low 64 bits of the [`BigUint`], i.e. its value modulo 2⁶⁴.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert_eq!(BigUint::from(1125u32).to_u64_wrapping(), 1125);
+    /// assert_eq!(BigUint::from((1u128 << 64) + 7).to_u64_wrapping(), 7);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_u64_wrapping(&self) -> u64 {
+        self.iter_u64_digits().next().unwrap_or(0)
+    }
+
+    /// Returns the low 128 bits of the [`BigUint`], i.e. its value modulo 2¹²⁸.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = (BigUint::from(3u8) << 128u32) + (u128::MAX - 1);
+    /// assert_eq!(x.to_u128_wrapping(), u128::MAX - 1);
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn to_u128_wrapping(&self) -> u128 {
+        let mut digits = self.iter_u64_digits();
+        let lo = digits.next().unwrap_or(0);
+        let hi = digits.next().unwrap_or(0);
+        u128::from(lo) | (u128::from(hi) << 64)
+    }
+
     /// Returns the integer formatted as a string in the given radix.
     /// `radix` must be in the range `2...36`.
     ///
@@ -818,6 +1089,33 @@ impl BigUint {
         unsafe { String::from_utf8_unchecked(v) }
     }
 
+    /// Returns the first `sig_digits` decimal digits of the integer, truncated, and the
+    /// exponent `e` such that the integer is those digits times `10^e` plus a remainder below
+    /// `10^e`. An integer with no more than `sig_digits` digits is returned in full, with an
+    /// exponent of zero.
+    ///
+    /// Rather than converting the whole integer, this divides its leading bits by a bounded
+    /// approximation of the power of ten, increasing the precision only until the bounds agree.
+    /// Its cost depends on `sig_digits` and only logarithmically on the size of the integer,
+    /// apart from integers extremely close to a multiple of `10^e`, such as powers of ten.
+    ///
+    /// Panics if `sig_digits` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use num_traits::Pow;
+    ///
+    /// let x = BigUint::from(2u8).pow(10_000u32);
+    /// assert_eq!(x.to_string_approx(5), ("19950".to_string(), 3006));
+    /// assert_eq!(BigUint::from(123u8).to_string_approx(5), ("123".to_string(), 0));
+    /// ```
+    #[must_use]
+    pub fn to_string_approx(&self, sig_digits: usize) -> (String, u64) {
+        convert::to_string_approx(self, sig_digits)
+    }
+
     /// Returns the integer in the requested base in big-endian digit order.
     /// The output is not given in a human readable alphabet but as a zero
     /// based `u8` number.
@@ -860,6 +1158,98 @@ impl BigUint {
         convert::to_radix_le(self, radix)
     }
 
+    /// Returns the integer written with the symbols of `alphabet`, most significant first,
+    /// where the radix is the number of symbols and `alphabet[d]` stands for the digit `d`.
+    ///
+    /// This covers the bases beyond the fixed `0-9a-z` digits of
+    /// [`to_str_radix`](Self::to_str_radix), such as base62, base85 or z-base-32. Zero is
+    /// written as the single symbol `alphabet[0]`.
+    ///
+    /// Panics unless `alphabet` has 2 to 256 symbols, all different.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// const BASE62: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+    ///
+    /// let n = BigUint::from(3_844_000u32);
+    /// assert_eq!(n.to_radix_custom(BASE62), b"G800");
+    /// assert_eq!(BigUint::from_radix_custom(b"G800", BASE62), Some(n));
+    /// ```
+    #[must_use]
+    pub fn to_radix_custom(&self, alphabet: &[u8]) -> Vec<u8> {
+        convert::to_radix_custom(self, alphabet)
+    }
+
+    /// Reads an integer written with the symbols of `alphabet`, most significant first, as
+    /// produced by [`to_radix_custom`](Self::to_radix_custom), or returns `None` if `buf` is
+    /// empty or has a byte that is not in the alphabet.
+    ///
+    /// Panics unless `alphabet` has 2 to 256 symbols, all different.
+    #[must_use]
+    pub fn from_radix_custom(buf: &[u8], alphabet: &[u8]) -> Option<Self> {
+        convert::from_radix_custom(buf, alphabet)
+    }
+
+    /// Returns the digits of the integer in a mixed radix system, least significant first,
+    /// where digit `i` is below `radices[i]`, along with the part above the last digit.
+    ///
+    /// This generalizes [`to_radix_le`](Self::to_radix_le) to positional systems whose bases
+    /// vary, such as the factorial number system behind Lehmer codes, or durations split into
+    /// seconds, minutes, hours and days. The part above the last digit is zero when the
+    /// integer is below the product of the radices.
+    ///
+    /// Panics if any radix is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // 200000 seconds are 2 days, 7 hours, 33 minutes and 20 seconds.
+    /// let (digits, days) = BigUint::from(200_000u32).to_mixed_radix(&[60, 60, 24]);
+    /// assert_eq!(digits, [20, 33, 7]);
+    /// assert_eq!(days, BigUint::from(2u8));
+    /// assert_eq!(BigUint::from_mixed_radix(&digits, &[60, 60, 24], days), Some(200_000u32.into()));
+    /// ```
+    #[must_use]
+    pub fn to_mixed_radix(&self, radices: &[u64]) -> (Vec<u64>, Self) {
+        convert::to_mixed_radix(self, radices)
+    }
+
+    /// Returns the integer with the given mixed radix digits, least significant first, and the
+    /// part `high` above the last digit, as produced by [`to_mixed_radix`](Self::to_mixed_radix),
+    /// or `None` if a digit is not below its radix.
+    ///
+    /// Panics if `digits` and `radices` have different lengths.
+    #[must_use]
+    pub fn from_mixed_radix(digits: &[u64], radices: &[u64], high: Self) -> Option<Self> {
+        convert::from_mixed_radix(digits, radices, high)
+    }
+
+    /// Returns an iterator over the values in `range`, stepping by one.
+    ///
+    /// This can be used in place of `for i in start..end`, which requires the unstable `Step`
+    /// trait. The iterator is double-ended and updates its bounds in place.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let v: Vec<BigUint> = BigUint::range(BigUint::from(3u8)..BigUint::from(6u8)).collect();
+    /// assert_eq!(v, [3u8, 4, 5].map(BigUint::from));
+    /// ```
+    #[must_use]
+    #[inline]
+    pub fn range(range: core::ops::Range<Self>) -> BigRange<Self> {
+        BigRange::new(range.start, range.end)
+    }
+
+    /// Returns an iterator over the values in the closed `range`, stepping by one.
+    #[must_use]
+    #[inline]
+    pub fn range_inclusive(range: core::ops::RangeInclusive<Self>) -> BigRangeInclusive<Self> {
+        let (start, end) = range.into_inner();
+        BigRangeInclusive::new(start, end)
+    }
+
     /// Determines the fewest bits necessary to express the [`BigUint`].
     ///
     /// # Panics
@@ -874,6 +1264,52 @@ impl BigUint {
         self.data.len() as u64 * u64::from(big_digit::BITS) - zeros
     }
 
+    /// Splits the [`BigUint`] into a mantissa in the range `[0.5, 1)` and a binary exponent,
+    /// such that `self` is approximately `mantissa * 2^exponent`.
+    ///
+    /// Unlike [`ToPrimitive::to_f64`](num_traits::ToPrimitive::to_f64), this never overflows
+    /// to infinity, so it can be used for logarithms and ratios of very large values. The
+    /// mantissa is rounded to nearest, ties to even. Zero returns `(0.0, 0)`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(3u8) << 2000u32;
+    /// assert_eq!(x.to_f64_exp(), (0.75, 2002));
+    /// ```
+    #[must_use]
+    pub fn to_f64_exp(&self) -> (f64, u64) {
+        convert::to_f64_exp(self)
+    }
+
+    /// Converts the [`BigUint`] to the nearest `f64`, with ties to even, and reports whether
+    /// that conversion was exact, rounded, or overflowed to infinity.
+    ///
+    /// The value is the same as [`ToPrimitive::to_f64`].
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, Exactness};
+    ///
+    /// let x = BigUint::from(1u64 << 53);
+    /// assert_eq!(x.to_f64_checked(), (9007199254740992.0, Exactness::Exact));
+    /// assert_eq!((x + 1u8).to_f64_checked(), (9007199254740992.0, Exactness::Rounded));
+    /// ```
+    #[must_use]
+    pub fn to_f64_checked(&self) -> (f64, Exactness) {
+        // `to_f64` of a `BigUint` always succeeds, overflowing to infinity.
+        let value = self.to_f64().unwrap_or(f64::INFINITY);
+        let exactness = if value.is_infinite() {
+            Exactness::Overflow
+        } else if self.bits() - self.trailing_zeros().unwrap_or(0)
+            <= u64::from(f64::MANTISSA_DIGITS)
+        {
+            Exactness::Exact
+        } else {
+            Exactness::Rounded
+        };
+        (value, exactness)
+    }
+
     /// Strips off trailing zero bigdigits - comparisons require the last element in the vector to
     /// be nonzero.
     #[inline]
@@ -887,6 +1323,14 @@ impl BigUint {
         }
     }
 
+    /// Removes high zero digits without releasing any capacity, unlike
+    /// [`normalize`](Self::normalize), for callers that reuse the allocation.
+    #[inline]
+    fn truncate_zeros(&mut self) {
+        let len = self.data.iter().rposition(|&d| d != 0).map_or(0, |i| i + 1);
+        self.data.truncate(len);
+    }
+
     /// Returns a normalized [`BigUint`].
     #[inline]
     fn normalized(mut self) -> Self {
@@ -894,12 +1338,256 @@ impl BigUint {
         self
     }
 
+    /// Writes `a + b` into `out`, reusing its allocation.
+    ///
+    /// This avoids the allocation of a new result in loops that repeatedly compute values of
+    /// a similar size, in the style of GMP's three-operand functions.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut out = BigUint::default();
+    /// BigUint::add_into(&BigUint::from(2u8), &BigUint::from(3u8), &mut out);
+    /// assert_eq!(out, BigUint::from(5u8));
+    /// ```
+    pub fn add_into(a: &Self, b: &Self, out: &mut Self) {
+        let (long, short) = if a.data.len() >= b.data.len() {
+            (a, b)
+        } else {
+            (b, a)
+        };
+        out.data.clear();
+        out.data.extend_from_slice(&long.data);
+        let carry = __add2(&mut out.data, &short.data);
+        if carry != 0 {
+            out.data.push(carry);
+        }
+    }
+
+    /// Writes `a - b` into `out`, reusing its allocation.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `b` is greater than `a`, like [`Sub`](core::ops::Sub).
+    pub fn sub_into(a: &Self, b: &Self, out: &mut Self) {
+        out.data.clear();
+        out.data.extend_from_slice(&a.data);
+        sub2(&mut out.data, &b.data);
+        out.truncate_zeros();
+    }
+
+    /// Writes `a * b` into `out`, reusing its allocation for the result.
+    pub fn mul_into(a: &Self, b: &Self, out: &mut Self) {
+        mul3_into(&a.data, &b.data, out);
+    }
+
+    /// Returns `(self * other) mod 2^bits`, the low `bits` bits of the product.
+    ///
+    /// Digits of the product at or above the cut are never computed, which saves close to half
+    /// the work of a full multiplication for operands of moderate size.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (a, b) = (BigUint::from(u64::MAX), BigUint::from(12_345u32));
+    /// assert_eq!(a.mul_low_bits(&b, 64), (&a * &b) % (BigUint::from(1u8) << 64));
+    /// ```
+    #[must_use]
+    pub fn mul_low_bits(&self, other: &Self, bits: u64) -> Self {
+        multiplication::mul_low_bits(self, other, bits)
+    }
+
+    /// Returns `(self * other) >> bits`, the product without its low `bits` bits.
+    ///
+    /// Most of the digits below the cut are never computed. The result is still exact: when
+    /// the skipped part could carry into it, the full product is used instead.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (a, b) = (BigUint::from(u64::MAX), BigUint::from(12_345u32));
+    /// assert_eq!(a.mul_high_bits(&b, 64), BigUint::from(12_344u32));
+    /// ```
+    #[must_use]
+    pub fn mul_high_bits(&self, other: &Self, bits: u64) -> Self {
+        multiplication::mul_high_bits(self, other, bits)
+    }
+
+    /// Adds `other << shift` to `self` in place.
+    ///
+    /// The shifted value is never materialized: each digit of `other` is shifted as it is
+    /// added, which suits radix recombination and other shift-and-accumulate loops.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut acc = BigUint::from(5u8);
+    /// acc.add_shifted(&BigUint::from(3u8), 100);
+    /// assert_eq!(acc, (BigUint::from(3u8) << 100) + 5u8);
+    /// ```
+    pub fn add_shifted(&mut self, other: &Self, shift: u64) {
+        addition::add_shifted(self, other, shift);
+    }
+
+    /// Adds `a * b` to `self` in place.
+    ///
+    /// The product is accumulated straight into `self`, digit by digit for small operands,
+    /// rather than built in a temporary and then added. This is the inner step of dot
+    /// products, polynomial evaluation and similar sums of products.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut acc = BigUint::from(5u8);
+    /// acc.addmul_assign(&BigUint::from(6u8), &BigUint::from(7u8));
+    /// assert_eq!(acc, BigUint::from(47u8));
+    /// ```
+    pub fn addmul_assign(&mut self, a: &Self, b: &Self) {
+        addmul3(self, &a.data, &b.data);
+    }
+
+    /// Returns the dot product of `xs` and `ys`, the sum of `xs[i] * ys[i]`.
+    ///
+    /// All the products accumulate into a single buffer, instead of allocating each product
+    /// and adding it to a running sum.
+    ///
+    /// Panics if `xs` and `ys` have different lengths.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let xs = [BigUint::from(1u8), BigUint::from(2u8), BigUint::from(3u8)];
+    /// let ys = [BigUint::from(4u8), BigUint::from(5u8), BigUint::from(6u8)];
+    /// assert_eq!(BigUint::dot(&xs, &ys), BigUint::from(32u8));
+    /// ```
+    #[must_use]
+    pub fn dot(xs: &[Self], ys: &[Self]) -> Self {
+        multiplication::dot(xs, ys)
+    }
+
     /// Returns `self ^ exponent`.
     #[must_use]
     pub fn pow(&self, exponent: u32) -> Self {
         Pow::pow(self, exponent)
     }
 
+    /// Returns `self ^ exponent` for an arbitrary-precision exponent.
+    ///
+    /// Panics if the result could not possibly fit in memory, which is the case whenever
+    /// `self > 1` and the exponent exceeds a `u128`.
+    #[must_use]
+    pub fn pow_big(&self, exponent: &Self) -> Self {
+        Pow::pow(self, exponent)
+    }
+
+    /// Returns `self ^ exponent`, or `None` if the result would need more than `max_bits` bits.
+    ///
+    /// The size of the result is bounded from the size of `self` first, so an oversized power
+    /// is rejected without being computed.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(3u8);
+    /// assert_eq!(x.checked_pow(40, 64), Some(x.pow(40)));
+    /// assert_eq!(x.checked_pow(41, 64), None);
+    /// assert_eq!(x.checked_pow(1_000_000_000, 1 << 20), None);
+    /// ```
+    #[must_use]
+    pub fn checked_pow(&self, exponent: u32, max_bits: u64) -> Option<Self> {
+        power::checked_pow(self, &Self::from(exponent), max_bits)
+    }
+
+    /// Returns `self ^ exponent` for an arbitrary-precision exponent, or `None` if the result
+    /// would need more than `max_bits` bits -- see [`checked_pow`](Self::checked_pow).
+    #[must_use]
+    pub fn checked_pow_big(&self, exponent: &Self, max_bits: u64) -> Option<Self> {
+        power::checked_pow(self, exponent, max_bits)
+    }
+
+    /// Returns `self + other`, or an error if the sum would exceed the size limit of
+    /// [`limits`](crate::limits).
+    pub fn try_add(&self, other: &Self) -> Result<Self, CapacityError> {
+        let len = cmp::max(self.data.len(), other.data.len()) + 1;
+        let mut sum = Self {
+            data: reserve::digits(len)?,
+        };
+        sum.data.extend_from_slice(&self.data);
+        sum += other;
+        crate::limits::try_check(sum.bits())?;
+        Ok(sum)
+    }
+
+    /// Returns `self * other`, or an error if the product would exceed the size limit of
+    /// [`limits`](crate::limits).
+    ///
+    /// A product that is surely too large is rejected before it is computed.
+    ///
+    /// ```
+    /// use num_bigint::{limits, BigUint, CapacityErrorKind};
+    ///
+    /// let x = BigUint::from(3u8).pow(1000u32);
+    /// assert_eq!(x.try_mul(&x), Ok(&x * &x));
+    ///
+    /// limits::set_max_bits(1 << 10);
+    /// assert_eq!(x.try_mul(&x).unwrap_err().kind(), CapacityErrorKind::LimitExceeded);
+    /// # limits::set_max_bits(u64::MAX);
+    /// ```
+    pub fn try_mul(&self, other: &Self) -> Result<Self, CapacityError> {
+        if self.is_zero() || other.is_zero() {
+            return Ok(Self::ZERO);
+        }
+        crate::limits::try_check(self.bits() + other.bits() - 1)?;
+        let product = multiplication::try_mul3(&self.data, &other.data)?;
+        crate::limits::try_check(product.bits())?;
+        Ok(product)
+    }
+
+    /// Returns `self << shift`, or an error if the result would exceed the size limit of
+    /// [`limits`](crate::limits) or the capacity of the target.
+    pub fn try_shl(&self, shift: u64) -> Result<Self, CapacityError> {
+        shift::try_shl(self, shift)
+    }
+
+    /// Returns `self ^ exponent`, or an error if the result would exceed the size limit of
+    /// [`limits`](crate::limits) or the capacity of the target.
+    ///
+    /// Like [`checked_pow`](Self::checked_pow), an oversized power is rejected without being
+    /// computed.
+    pub fn try_pow(&self, exponent: u32) -> Result<Self, CapacityError> {
+        power::try_pow(self, u64::from(exponent))
+    }
+
+    /// Returns `self * base ^ exponent`.
+    ///
+    /// The power is never built in full for small exponents. Factors of two in `base` become a
+    /// shift and the rest is applied one machine word at a time, which suits scaling by powers
+    /// of a radix in decimal and fixed-point arithmetic.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(7u8);
+    /// assert_eq!(x.mul_pow(6, 30), &x * BigUint::from(6u8).pow(30));
+    /// ```
+    #[must_use]
+    pub fn mul_pow(&self, base: u32, exponent: u32) -> Self {
+        power::mul_pow(self, base, exponent)
+    }
+
+    /// Returns `self * 10 ^ exponent`, by way of [`mul_pow`](Self::mul_pow).
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(125u8).mul_pow10(40);
+    /// assert_eq!(x.to_string(), format!("125{}", "0".repeat(40)));
+    /// ```
+    #[must_use]
+    pub fn mul_pow10(&self, exponent: u32) -> Self {
+        power::mul_pow(self, 10, exponent)
+    }
+
     /// Returns `(self ^ exponent) % modulus`.
     ///
     /// Panics if the modulus is zero.
@@ -908,6 +1596,138 @@ impl BigUint {
         power::modpow(self, exponent, modulus)
     }
 
+    /// Returns `(self ^ exponent) % modulus` for a modulus already known to be odd, which
+    /// always uses Montgomery multiplication and cannot panic.
+    #[must_use]
+    pub fn modpow_odd(&self, exponent: &Self, modulus: &OddModulus) -> Self {
+        trace_span!(
+            "modpow",
+            exponent_bits = exponent.bits(),
+            modulus_bits = modulus.bits(),
+            algorithm = "montgomery",
+        );
+        count_stat!(montgomery_modpows);
+        cancel::unwrap(monty::monty_modpow(self, exponent, modulus.get(), &()))
+    }
+
+    /// Returns `self * other`, or `Cancelled` if `cancel` is set before the product is done.
+    ///
+    /// The token is checked before each step of the subquadratic multiplication algorithms, so
+    /// setting it from another thread stops even a huge product soon after.
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, Cancelled};
+    /// use std::sync::atomic::AtomicBool;
+    ///
+    /// let x = BigUint::from(3u8).pow(100_000);
+    /// assert_eq!(x.try_mul_cancellable(&x, &AtomicBool::new(false)), Ok(&x * &x));
+    /// assert_eq!(x.try_mul_cancellable(&x, &AtomicBool::new(true)), Err(Cancelled));
+    /// ```
+    pub fn try_mul_cancellable(
+        &self,
+        other: &Self,
+        cancel: &AtomicBool,
+    ) -> Result<Self, Cancelled> {
+        multiplication::mul3_cancellable(&self.data, &other.data, cancel)
+    }
+
+    /// Returns `(self / other, self % other)`, or `Cancelled` if `cancel` is set before the
+    /// division is done.
+    ///
+    /// The token is checked every 64 digits of the quotient.
+    ///
+    /// Panics if `other` is zero.
+    pub fn try_div_rem_cancellable(
+        &self,
+        other: &Self,
+        cancel: &AtomicBool,
+    ) -> Result<(Self, Self), Cancelled> {
+        division::div_rem_cancellable(self, other, cancel)
+    }
+
+    /// Returns the digits of `self` in base `radix`, like [`to_str_radix`](Self::to_str_radix),
+    /// or `Cancelled` if `cancel` is set before the conversion is done.
+    ///
+    /// The token is checked in the divisions that split off each group of digits, including
+    /// those on the worker threads of the `rayon` feature.
+    ///
+    /// Panics if the radix is not in the range `2...36`.
+    pub fn try_to_str_radix_cancellable(
+        &self,
+        radix: u32,
+        cancel: &AtomicBool,
+    ) -> Result<String, Cancelled> {
+        let mut v = convert::to_str_radix_reversed_cancellable(self, radix, cancel)?;
+        v.reverse();
+        Ok(unsafe { String::from_utf8_unchecked(v) })
+    }
+
+    /// Returns `(self ^ exponent) % modulus`, or `Cancelled` if `cancel` is set before the
+    /// exponentiation is done.
+    ///
+    /// The token is checked once per digit of the exponent.
+    ///
+    /// Panics if the modulus is zero.
+    pub fn try_modpow_cancellable(
+        &self,
+        exponent: &Self,
+        modulus: &Self,
+        cancel: &AtomicBool,
+    ) -> Result<Self, Cancelled> {
+        power::modpow_cancellable(self, exponent, modulus, cancel)
+    }
+
+    /// Returns `(self ^ d) % (p * q)` for an RSA private key in CRT form, where
+    /// `dp = d % (p - 1)`, `dq = d % (q - 1)` and `qinv = q^-1 % p`.
+    ///
+    /// This does two exponentiations of half the size and recombines them with Garner's
+    /// formula, which is about four times faster than a single [`modpow`](Self::modpow).
+    ///
+    /// Panics if `p` or `q` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (p, q) = (BigUint::from(61u8), BigUint::from(53u8));
+    /// let d = BigUint::from(2753u16);
+    /// let dp = &d % (&p - 1u8);
+    /// let dq = &d % (&q - 1u8);
+    /// let qinv = q.modinv(&p).unwrap();
+    ///
+    /// let c = BigUint::from(2790u16);
+    /// assert_eq!(c.modpow_crt(&dp, &dq, &p, &q, &qinv), c.modpow(&d, &(&p * &q)));
+    /// ```
+    #[must_use]
+    pub fn modpow_crt(&self, dp: &Self, dq: &Self, p: &Self, q: &Self, qinv: &Self) -> Self {
+        power::modpow_crt(self, dp, dq, p, q, qinv)
+    }
+
+    /// Like [`modpow_crt`](Self::modpow_crt), but checks the result against the public
+    /// exponent `e` before returning it, and returns `None` on a mismatch.
+    ///
+    /// A fault during either half of the computation would otherwise let an attacker factor
+    /// the modulus from a single bad signature, so this check should be used wherever faults
+    /// can be induced.
+    #[must_use]
+    #[allow(clippy::too_many_arguments)]
+    pub fn modpow_crt_checked(
+        &self,
+        dp: &Self,
+        dq: &Self,
+        p: &Self,
+        q: &Self,
+        qinv: &Self,
+        e: &Self,
+    ) -> Option<Self> {
+        let result = power::modpow_crt(self, dp, dq, p, q, qinv);
+        let n = p * q;
+        if result.modpow(e, &n) == self % &n {
+            Some(result)
+        } else {
+            None
+        }
+    }
+
     /// Returns the modular multiplicative inverse if it exists, otherwise `None`.
     ///
     /// This solves for `x` in the interval `[0, modulus)` such that `self * x ≡ 1 (mod modulus)`.
@@ -933,10 +1753,6 @@ impl BigUint {
     /// ```
     #[must_use]
     pub fn modinv(&self, modulus: &Self) -> Option<Self> {
-        // Based on the inverse pseudocode listed here:
-        // https://en.wikipedia.org/wiki/Extended_Euclidean_algorithm#Modular_integers
-        // TODO: consider Binary or Lehmer's GCD algorithms for optimization.
-
         assert!(
             !modulus.is_zero(),
             "attempt to calculate with zero modulus!"
@@ -945,48 +1761,209 @@ impl BigUint {
             return Some(Self::zero());
         }
 
-        let mut r0; // = modulus.clone();
-        let mut r1 = self % modulus;
-        let mut t0; // = Self::zero();
-        let mut t1; // = Self::one();
+        let (gcd, x) = gcd_cofactor(&(self % modulus), modulus);
+        if gcd.is_one() {
+            Some(
+                x.mod_floor(&crate::BigInt::from(modulus.clone()))
+                    .into_parts()
+                    .1,
+            )
+        } else {
+            None
+        }
+    }
 
-        // Lift and simplify the first iteration to avoid some initial allocations.
-        if r1.is_zero() {
-            return None;
-        } else if r1.is_one() {
-            return Some(r1);
+    /// Returns the modular multiplicative inverse modulo an odd `modulus` if it exists,
+    /// otherwise `None`, like [`modinv`](Self::modinv) but with a fixed sequence of operations.
+    ///
+    /// This uses the Bernstein–Yang divstep iteration with a fixed step count, and applies
+    /// every choice as a mask rather than a branch, so the work done depends only on the
+    /// lengths of the inputs and not on their values. `self` is first reduced with an ordinary
+    /// remainder only if it has more digits than `modulus`. Whether the inverse exists is of
+    /// course visible in the result.
+    ///
+    /// Panics if `modulus` is even or zero; see [`modinv_ct_odd`](Self::modinv_ct_odd) for a
+    /// version that takes an [`OddModulus`] instead.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let m = BigUint::from(383_u32);
+    /// assert_eq!(BigUint::from(271_u32).modinv_ct(&m), Some(BigUint::from(106_u32)));
+    /// assert_eq!(BigUint::from(766_u32).modinv_ct(&m), None);
+    /// ```
+    #[must_use]
+    pub fn modinv_ct(&self, modulus: &Self) -> Option<Self> {
+        assert!(modulus.is_odd(), "modulus must be odd");
+        divsteps::modinv(self, modulus)
+    }
+
+    /// Returns the modular multiplicative inverse modulo `modulus` if it exists, otherwise
+    /// `None`, like [`modinv_ct`](Self::modinv_ct) with the same fixed sequence of operations,
+    /// but for a modulus already known to be odd, so that it cannot panic.
+    #[must_use]
+    pub fn modinv_ct_odd(&self, modulus: &OddModulus) -> Option<Self> {
+        divsteps::modinv(self, modulus.get())
+    }
+
+    /// Returns the greatest common divisor of all `values`, or zero if there are none.
+    ///
+    /// This stops early once the running gcd reaches one, without consuming the rest.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let values = [12u8, 18, 30].map(BigUint::from);
+    /// assert_eq!(BigUint::gcd_all(&values), BigUint::from(6u8));
+    /// ```
+    pub fn gcd_all<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: core::borrow::Borrow<Self>,
+    {
+        let mut gcd = Self::ZERO;
+        for value in values {
+            gcd = gcd.gcd(value.borrow());
+            if gcd.is_one() {
+                break;
+            }
         }
+        gcd
+    }
 
-        let (q, r2) = modulus.div_rem(&r1);
-        if r2.is_zero() {
-            return None;
+    /// Returns the least common multiple of all `values`, or one if there are none.
+    ///
+    /// The values are combined pairwise in a balanced tree rather than folded from the left,
+    /// so intermediate results stay as small as possible until the final steps.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let values = [4u8, 6, 10].map(BigUint::from);
+    /// assert_eq!(BigUint::lcm_all(&values), BigUint::from(60u8));
+    /// ```
+    pub fn lcm_all<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: core::borrow::Borrow<Self>,
+    {
+        let mut level: Vec<Self> = values.into_iter().map(|v| v.borrow().clone()).collect();
+        if level.iter().any(Self::is_zero) {
+            return Self::ZERO;
         }
-        r0 = r1;
-        r1 = r2;
-        t0 = Self::one();
-        t1 = modulus - q;
-
-        while !r1.is_zero() {
-            let (q, r2) = r0.div_rem(&r1);
-            r0 = r1;
-            r1 = r2;
-
-            // let t2 = (t0 - q * t1) % modulus;
-            let qt1 = q * &t1 % modulus;
-            let t2 = if t0 < qt1 {
-                t0 + (modulus - qt1)
-            } else {
-                t0 - qt1
-            };
-            t0 = t1;
-            t1 = t2;
+        while level.len() > 1 {
+            level = level
+                .chunks(2)
+                .map(|pair| match pair {
+                    [a, b] => a.lcm(b),
+                    [a] => a.clone(),
+                    _ => unreachable!(),
+                })
+                .collect();
         }
+        level.pop().unwrap_or_else(Self::one)
+    }
 
-        if r0.is_one() {
-            Some(t0)
-        } else {
-            None
-        }
+    /// Returns the greatest common divisor and least common multiple of `self` and `other`.
+    ///
+    /// This computes the gcd only once, and derives the lcm from it with an exact division of
+    /// the shorter operand, which is about half the work of calling [`Integer::gcd`] and
+    /// [`Integer::lcm`] separately. Both are zero if `self` and `other` are zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (gcd, lcm) = BigUint::from(12u8).gcd_lcm(&BigUint::from(18u8));
+    /// assert_eq!(gcd, BigUint::from(6u8));
+    /// assert_eq!(lcm, BigUint::from(36u8));
+    /// ```
+    #[must_use]
+    pub fn gcd_lcm(&self, other: &Self) -> (Self, Self) {
+        Integer::gcd_lcm(self, other)
+    }
+
+    /// Returns `self / divisor` for a `divisor` known to divide `self` evenly.
+    ///
+    /// This computes the quotient from the least significant digit up, which avoids the
+    /// estimation and correction steps of general division. It suits formulas that are known to
+    /// divide out, such as binomial coefficients or interpolation steps.
+    ///
+    /// The result is unspecified if the division is not exact. In debug builds that case
+    /// panics.
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let a = BigUint::from(3u8).pow(100u32) * 1000u32;
+    /// assert_eq!(a.div_exact(&BigUint::from(3u8).pow(90u32)), BigUint::from(59_049_000u32));
+    /// ```
+    #[must_use]
+    pub fn div_exact(&self, divisor: &Self) -> Self {
+        division::div_exact(self, divisor)
+    }
+
+    /// Returns the quotient and remainder of `self` divided by a `u64`.
+    ///
+    /// Unlike `self.div_rem(&BigUint::from(divisor))`, this doesn't allocate for the divisor
+    /// or the remainder.
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(10u8).pow(30u32) + 7u32;
+    /// let (q, r) = n.div_rem_u64(10_000_000_000);
+    /// assert_eq!(q, BigUint::from(10u8).pow(20u32));
+    /// assert_eq!(r, 7);
+    /// ```
+    #[must_use]
+    pub fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut q = self.clone();
+        let r = q.div_assign_u64(divisor);
+        (q, r)
+    }
+
+    /// Divides `self` by a `u64` in place, returning the remainder.
+    ///
+    /// This is the step of radix conversions and similar loops that repeatedly peel off the low
+    /// part of a number, with no allocation at all.
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let mut n = BigUint::from(1_234_567u32);
+    /// let mut digits = Vec::new();
+    /// while n.bits() > 0 {
+    ///     digits.push(n.div_assign_u64(1000));
+    /// }
+    /// assert_eq!(digits, [567, 234, 1]);
+    /// ```
+    pub fn div_assign_u64(&mut self, divisor: u64) -> u64 {
+        division::div_rem_u64_in_place(self, divisor)
+    }
+
+    /// Returns `self % divisor`, computed without allocating or storing a quotient.
+    ///
+    /// This is the same path the `%` operators take, spelled out for callers that want to be
+    /// sure no quotient is produced, such as modular reductions in sieves and loops.
+    ///
+    /// Panics if `divisor` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(10u8).pow(40u32) + 7u32;
+    /// let m = BigUint::from(10u8).pow(20u32);
+    /// assert_eq!(n.rem_no_quotient(&m), BigUint::from(7u8));
+    /// ```
+    #[must_use]
+    pub fn rem_no_quotient(&self, divisor: &Self) -> Self {
+        division::rem_ref(self, divisor)
     }
 
     /// Returns the truncated principal square root of `self` --
@@ -996,6 +1973,21 @@ impl BigUint {
         Roots::sqrt(self)
     }
 
+    /// Returns the truncated principal square root of `self` and the remainder
+    /// `self - root * root`.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let (root, rem) = BigUint::from(1_000_000_007u32).sqrt_rem();
+    /// assert_eq!(root, BigUint::from(31622u32));
+    /// assert_eq!(rem, BigUint::from(1_000_000_007u32 - 31622 * 31622));
+    /// ```
+    #[must_use]
+    pub fn sqrt_rem(&self) -> (Self, Self) {
+        sqrt::sqrt_rem(self)
+    }
+
     /// Returns the truncated principal cube root of `self` --
     /// see [Roots::cbrt](https://docs.rs/num-integer/0.1/num_integer/trait.Roots.html#method.cbrt).
     #[must_use]
@@ -1019,6 +2011,23 @@ impl BigUint {
         Some(i as u64 * u64::from(big_digit::BITS) + zeros)
     }
 
+    /// Returns the odd part of `self` and the exponent of two, `(odd, k)` such that
+    /// `self = odd * 2^k`, or `(0, 0)` if `self` is zero.
+    ///
+    /// This is the first step of Miller–Rabin, the binary gcd, and Jacobi symbols.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let x = BigUint::from(3u8 * 5) << 100u32;
+    /// assert_eq!(x.remove_twos(), (BigUint::from(15u8), 100));
+    /// ```
+    #[must_use]
+    pub fn remove_twos(&self) -> (Self, u64) {
+        let zeros = self.trailing_zeros().unwrap_or(0);
+        (self >> zeros, zeros)
+    }
+
     /// Returns the number of least-significant bits that are ones.
     #[must_use]
     pub fn trailing_ones(&self) -> u64 {
@@ -1072,6 +2081,53 @@ impl BigUint {
             self.normalize();
         }
     }
+
+    /// Splits `self` at bit `k` into its low `k` bits and the remaining high part, returning
+    /// `(self mod 2^k, self >> k)`.
+    ///
+    /// Both parts are built in one pass over the digits. [`BigUint::from_parts`] is the
+    /// inverse.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::from(0x1234_5678u32);
+    /// let (low, high) = n.split_at_bit(12);
+    /// assert_eq!(low, BigUint::from(0x678u32));
+    /// assert_eq!(high, BigUint::from(0x12345u32));
+    /// assert_eq!(BigUint::from_parts(&low, &high, 12), n);
+    /// ```
+    #[must_use]
+    pub fn split_at_bit(&self, k: u64) -> (Self, Self) {
+        shift::split_at_bit(self, k)
+    }
+
+    /// Joins the parts of [`BigUint::split_at_bit`], returning `low + (high << k)`.
+    ///
+    /// Panics if `low` does not fit in `k` bits.
+    #[must_use]
+    pub fn from_parts(low: &Self, high: &Self, k: u64) -> Self {
+        shift::from_parts(low, high, k)
+    }
+
+    /// Returns `self >> n`, with the discarded bits rounded in the direction given by `mode`.
+    ///
+    /// The `>>` operator truncates, like [`RoundingMode::Floor`] and [`RoundingMode::Trunc`],
+    /// while fixed-point arithmetic and conversions to floating point usually need the
+    /// discarded bits rounded to nearest.
+    ///
+    /// ```
+    /// use num_bigint::{BigUint, RoundingMode};
+    ///
+    /// let n = BigUint::from(0b1011_1000u32);
+    /// assert_eq!(n.shr_round(4, RoundingMode::Trunc), BigUint::from(0b1011u32));
+    /// assert_eq!(n.shr_round(4, RoundingMode::HalfEven), BigUint::from(0b1100u32));
+    /// assert_eq!(n.shr_round(5, RoundingMode::Ceil), BigUint::from(0b110u32));
+    /// ```
+    #[must_use]
+    pub fn shr_round(&self, n: u64, mode: RoundingMode) -> Self {
+        shift::shr_round(self, n, false, mode)
+    }
 }
 
 impl num_traits::FromBytes for BigUint {
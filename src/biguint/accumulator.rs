@@ -0,0 +1,158 @@
+use super::{biguint_from_vec, BigUint};
+
+use crate::big_digit::{self, BigDigit, DoubleBigDigit};
+
+use alloc::vec::Vec;
+use core::iter::FromIterator;
+use core::ops::AddAssign;
+
+/// Columns could take `β - 1` more digits before overflowing, but settling the carries far
+/// sooner costs nothing measurable and keeps the column sums short.
+const FLUSH_INTERVAL: BigDigit = 1 << 16;
+
+/// A running sum of [`BigUint`] values, for adding up very many of them.
+///
+/// Summing with `+=` propagates carries through the total on every addition. An `Accumulator`
+/// instead keeps a double-width column sum for each digit position, so adding a value only
+/// touches as many columns as it has digits, and the carries are resolved once, when the
+/// [`sum`](Self::sum) is taken. Values can also be fed as raw little-endian `u64` digit chunks,
+/// straight from a decoded column, without building a `BigUint` for each.
+///
+/// ```
+/// use num_bigint::{Accumulator, BigUint};
+///
+/// let mut acc = Accumulator::new();
+/// acc.push(&BigUint::from(u64::MAX));
+/// acc.push_u64_digits(&[u64::MAX, 1]);
+/// acc += &BigUint::from(2u8);
+/// assert_eq!(acc.sum(), BigUint::from(u64::MAX) * 2u8 + (BigUint::from(1u8) << 64) + 2u8);
+///
+/// let acc: Accumulator = (1..=100u32).map(BigUint::from).collect();
+/// assert_eq!(acc.sum(), BigUint::from(5050u32));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct Accumulator {
+    /// The column sums, each below `β` after a flush plus at most `FLUSH_INTERVAL` digits since.
+    lanes: Vec<DoubleBigDigit>,
+    /// The number of values added since the carries were last propagated.
+    pending: BigDigit,
+}
+
+impl Accumulator {
+    /// Creates an accumulator with a sum of zero.
+    #[must_use]
+    #[inline]
+    pub const fn new() -> Self {
+        Self {
+            lanes: Vec::new(),
+            pending: 0,
+        }
+    }
+
+    /// Adds a value to the sum.
+    pub fn push(&mut self, x: &BigUint) {
+        self.push_digits(x.data.len(), x.data.iter().copied());
+    }
+
+    /// Adds a value given as `u64` digits, least significant first.
+    pub fn push_u64_digits(&mut self, digits: &[u64]) {
+        cfg_digit_expr!(
+            self.push_digits(
+                digits.len() * 2,
+                digits
+                    .iter()
+                    .flat_map(|&d| [d as BigDigit, (d >> 32) as BigDigit]),
+            ),
+            self.push_digits(digits.len(), digits.iter().copied())
+        );
+    }
+
+    fn push_digits(&mut self, len: usize, digits: impl Iterator<Item = BigDigit>) {
+        if self.pending == FLUSH_INTERVAL {
+            self.flush();
+        }
+        if self.lanes.len() < len {
+            self.lanes.resize(len, 0);
+        }
+        for (lane, d) in self.lanes.iter_mut().zip(digits) {
+            *lane += DoubleBigDigit::from(d);
+        }
+        self.pending += 1;
+    }
+
+    /// Propagates the carries, leaving a single digit in each column.
+    fn flush(&mut self) {
+        let mut carry = 0;
+        for lane in &mut self.lanes {
+            let (hi, lo) = big_digit::from_doublebigdigit(*lane + carry);
+            *lane = DoubleBigDigit::from(lo);
+            carry = DoubleBigDigit::from(hi);
+        }
+        if carry != 0 {
+            self.lanes.push(carry);
+        }
+        self.pending = 0;
+    }
+
+    /// Returns the sum of all the values added so far.
+    #[must_use]
+    pub fn sum(&self) -> BigUint {
+        let mut digits = Vec::with_capacity(self.lanes.len() + 1);
+        let mut carry = 0;
+        for &lane in &self.lanes {
+            let (hi, lo) = big_digit::from_doublebigdigit(lane + carry);
+            digits.push(lo);
+            carry = DoubleBigDigit::from(hi);
+        }
+        // A column sum is below `β^2 - β`, so the final carry is a single digit.
+        let (_, lo) = big_digit::from_doublebigdigit(carry);
+        digits.push(lo);
+        biguint_from_vec(digits)
+    }
+}
+
+impl AddAssign<&BigUint> for Accumulator {
+    #[inline]
+    fn add_assign(&mut self, x: &BigUint) {
+        self.push(x);
+    }
+}
+
+impl AddAssign<BigUint> for Accumulator {
+    #[inline]
+    fn add_assign(&mut self, x: BigUint) {
+        self.push(&x);
+    }
+}
+
+impl<'a> Extend<&'a BigUint> for Accumulator {
+    fn extend<I: IntoIterator<Item = &'a BigUint>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(x);
+        }
+    }
+}
+
+impl Extend<BigUint> for Accumulator {
+    fn extend<I: IntoIterator<Item = BigUint>>(&mut self, iter: I) {
+        for x in iter {
+            self.push(&x);
+        }
+    }
+}
+
+impl<'a> FromIterator<&'a BigUint> for Accumulator {
+    fn from_iter<I: IntoIterator<Item = &'a BigUint>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
+
+impl FromIterator<BigUint> for Accumulator {
+    fn from_iter<I: IntoIterator<Item = BigUint>>(iter: I) -> Self {
+        let mut acc = Self::new();
+        acc.extend(iter);
+        acc
+    }
+}
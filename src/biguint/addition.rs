@@ -5,7 +5,7 @@ use crate::UsizePromotion;
 
 use core::iter::Sum;
 use core::ops::{Add, AddAssign};
-use num_traits::CheckedAdd;
+use num_traits::{CheckedAdd, Zero};
 
 #[cfg(target_arch = "x86_64")]
 use core::arch::x86_64 as arch;
@@ -17,7 +17,7 @@ use core::arch::x86 as arch;
 #[cfg(target_arch = "x86_64")]
 cfg_64!(
     #[inline]
-    fn adc(carry: u8, a: u64, b: u64, out: &mut u64) -> u8 {
+    pub(super) fn adc(carry: u8, a: u64, b: u64, out: &mut u64) -> u8 {
         // Safety: There are absolutely no safety concerns with calling `_addcarry_u64`.
         // It's just unsafe for API consistency with other intrinsics.
         unsafe { arch::_addcarry_u64(carry, a, b, out) }
@@ -27,7 +27,7 @@ cfg_64!(
 #[cfg(any(target_arch = "x86", target_arch = "x86_64"))]
 cfg_32!(
     #[inline]
-    fn adc(carry: u8, a: u32, b: u32, out: &mut u32) -> u8 {
+    pub(super) fn adc(carry: u8, a: u32, b: u32, out: &mut u32) -> u8 {
         // Safety: There are absolutely no safety concerns with calling `_addcarry_u32`.
         // It's just unsafe for API consistency with other intrinsics.
         unsafe { arch::_addcarry_u32(carry, a, b, out) }
@@ -38,7 +38,7 @@ cfg_32!(
 // (copied from the standard library's `carrying_add`)
 #[cfg(not(any(target_arch = "x86", target_arch = "x86_64")))]
 #[inline]
-fn adc(carry: u8, lhs: BigDigit, rhs: BigDigit, out: &mut BigDigit) -> u8 {
+pub(super) fn adc(carry: u8, lhs: BigDigit, rhs: BigDigit, out: &mut BigDigit) -> u8 {
     let (a, b) = lhs.overflowing_add(rhs);
     let (c, d) = a.overflowing_add(carry as BigDigit);
     *out = c;
@@ -85,6 +85,51 @@ pub(super) fn add2(a: &mut [BigDigit], b: &[BigDigit]) {
     debug_assert!(carry == 0);
 }
 
+/// Adds `b << shift` to `a` in place, shifting each digit of `b` on the fly instead of
+/// building the shifted value.
+pub(super) fn add_shifted(a: &mut BigUint, b: &BigUint, shift: u64) {
+    if b.is_zero() {
+        return;
+    }
+    let digits = usize::try_from(shift / u64::from(big_digit::BITS)).expect("capacity overflow");
+    let shift = (shift % u64::from(big_digit::BITS)) as u8;
+
+    let len = digits.saturating_add(b.data.len() + 1);
+    if a.data.len() < len {
+        a.data.resize(len, 0);
+    }
+    let a_hi = &mut a.data[digits..];
+    let carry = if shift == 0 {
+        __add2(a_hi, &b.data)
+    } else {
+        let carry_shift = big_digit::BITS - shift;
+        let mut carry = 0;
+        let mut prev = 0;
+        let shifted = b.data.iter().map(|&d| {
+            let out = (d << shift) | (prev >> carry_shift);
+            prev = d;
+            out
+        });
+        let (a_lo, a_hi) = a_hi.split_at_mut(b.data.len());
+        for (a, b) in a_lo.iter_mut().zip(shifted) {
+            carry = adc(carry, *a, b, a);
+        }
+        let (a_top, a_hi) = a_hi.split_first_mut().unwrap();
+        carry = adc(carry, *a_top, prev >> carry_shift, a_top);
+        for a in a_hi {
+            if carry == 0 {
+                break;
+            }
+            carry = adc(carry, *a, 0, a);
+        }
+        BigDigit::from(carry)
+    };
+    if carry != 0 {
+        a.data.push(carry);
+    }
+    a.normalize();
+}
+
 forward_all_binop_to_val_ref_commutative!(impl Add for BigUint, add);
 forward_val_assign!(impl AddAssign for BigUint, add_assign);
 
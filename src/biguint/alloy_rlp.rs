@@ -0,0 +1,38 @@
+#![cfg(feature = "alloy-rlp")]
+#![cfg_attr(docsrs, doc(cfg(feature = "alloy-rlp")))]
+
+use super::BigUint;
+
+use alloy_rlp::{BufMut, Decodable, Encodable, Error, Header};
+use core::convert::TryFrom;
+use num_integer::Integer;
+
+impl Encodable for BigUint {
+    fn encode(&self, out: &mut dyn BufMut) {
+        out.put_slice(&self.to_rlp());
+    }
+
+    fn length(&self) -> usize {
+        // Zero and values below 0x80 are a single byte, with no header.
+        if self.bits() <= 7 {
+            return 1;
+        }
+        let header = Header {
+            list: false,
+            payload_length: usize::try_from(Integer::div_ceil(&self.bits(), &8)).unwrap(),
+        };
+        header.length_with_payload()
+    }
+}
+
+/// Decoding is as strict as [`BigUint::from_rlp`], with the specific `alloy_rlp::Error` for
+/// each kind of invalid input.
+impl Decodable for BigUint {
+    fn decode(buf: &mut &[u8]) -> alloy_rlp::Result<Self> {
+        let bytes = Header::decode_bytes(buf, false)?;
+        if bytes.first() == Some(&0) {
+            return Err(Error::LeadingZero);
+        }
+        Ok(Self::from_bytes_be(bytes))
+    }
+}
@@ -0,0 +1,73 @@
+#![cfg(feature = "bitvec")]
+#![cfg_attr(docsrs, doc(cfg(feature = "bitvec")))]
+
+//! Conversions between [`BigUint`] and the bit sequences of the `bitvec` crate.
+//!
+//! Bit `i` of a [`BitSlice`] or [`BitVec`] is bit `i` of the number, worth `2^i`, so the first
+//! bit of the sequence is the least significant. This holds for both [`Lsb0`](bitvec::order::Lsb0)
+//! and [`Msb0`](bitvec::order::Msb0), whose order only decides where each bit is stored in
+//! memory, and for any storage type.
+
+use super::{biguint_from_vec, BigUint};
+
+use crate::big_digit;
+
+use alloc::vec;
+use bitvec::order::BitOrder;
+use bitvec::slice::BitSlice;
+use bitvec::store::BitStore;
+use bitvec::vec::BitVec;
+use core::convert::TryFrom;
+use num_integer::Integer;
+
+/// A bit sequence becomes the number with those bits, so trailing zeros are dropped.
+impl<T: BitStore, O: BitOrder> From<&BitSlice<T, O>> for BigUint {
+    fn from(bits: &BitSlice<T, O>) -> Self {
+        let digit_bits = usize::from(big_digit::BITS);
+        let mut data = vec![0; Integer::div_ceil(&bits.len(), &digit_bits)];
+        for i in bits.iter_ones() {
+            data[i / digit_bits] |= 1 << (i % digit_bits);
+        }
+        biguint_from_vec(data)
+    }
+}
+
+impl<T: BitStore, O: BitOrder> From<&BitVec<T, O>> for BigUint {
+    #[inline]
+    fn from(bits: &BitVec<T, O>) -> Self {
+        Self::from(bits.as_bitslice())
+    }
+}
+
+impl<T: BitStore, O: BitOrder> From<BitVec<T, O>> for BigUint {
+    #[inline]
+    fn from(bits: BitVec<T, O>) -> Self {
+        Self::from(bits.as_bitslice())
+    }
+}
+
+/// A number becomes the sequence of its [`bits()`](BigUint::bits) bits, which is empty for
+/// zero and otherwise ends with a one.
+impl<T: BitStore, O: BitOrder> From<&BigUint> for BitVec<T, O> {
+    fn from(n: &BigUint) -> Self {
+        let digit_bits = usize::from(big_digit::BITS);
+        let len = usize::try_from(n.bits()).expect("value in memory has a usize bit length");
+        let mut bits = Self::repeat(false, len);
+        for (i, &digit) in n.data.iter().enumerate() {
+            let mut rest = digit;
+            while rest != 0 {
+                let j = rest.trailing_zeros() as usize;
+                bits.set(i * digit_bits + j, true);
+                rest &= rest - 1;
+            }
+        }
+        bits
+    }
+}
+
+impl<T: BitStore, O: BitOrder> From<BigUint> for BitVec<T, O> {
+    #[inline]
+    fn from(n: BigUint) -> Self {
+        Self::from(&n)
+    }
+}
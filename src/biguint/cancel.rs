@@ -0,0 +1,53 @@
+//! Cooperative cancellation for the `_cancellable` methods of [`BigUint`](super::BigUint).
+//!
+//! The long loops of multiplication, division, radix conversion and exponentiation take a
+//! [`Token`] and [`check`](Token::check) it once per step, returning early with its error once
+//! it is set. The ordinary operations pass `()`, whose check can never fail and compiles away,
+//! and take the value out with [`unwrap`].
+
+use crate::Cancelled;
+
+use core::convert::Infallible;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// A cancellation token, checked between the steps of a long computation.
+pub(super) trait Token: Sync {
+    /// The error returned once the computation is cancelled.
+    type Error: Send;
+
+    /// Returns an error if the computation should stop.
+    fn check(&self) -> Result<(), Self::Error>;
+}
+
+/// No token: the computation always runs to the end.
+impl Token for () {
+    type Error = Infallible;
+
+    #[inline]
+    fn check(&self) -> Result<(), Infallible> {
+        Ok(())
+    }
+}
+
+/// A flag that cancels the computation once it is set, from any thread.
+impl Token for AtomicBool {
+    type Error = Cancelled;
+
+    #[inline]
+    fn check(&self) -> Result<(), Cancelled> {
+        if self.load(Ordering::Relaxed) {
+            Err(Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Returns the value of a computation run without a token.
+#[inline]
+pub(super) fn unwrap<R>(result: Result<R, Infallible>) -> R {
+    match result {
+        Ok(value) => value,
+        Err(never) => match never {},
+    }
+}
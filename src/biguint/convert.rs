@@ -4,21 +4,27 @@
 use super::{biguint_from_vec, BigUint, ToBigUint};
 
 use super::addition::add2;
-use super::division::{div_rem_digit, FAST_DIV_WIDE};
+use super::cancel::{self, Token};
+use super::division::{div_rem_cancellable, div_rem_digit, FAST_DIV_WIDE};
 use super::multiplication::mac_with_carry;
 
-use crate::big_digit::{self, BigDigit};
-use crate::ParseBigIntError;
-use crate::TryFromBigIntError;
+use crate::big_digit::{self, BigDigit, DoubleBigDigit};
+use crate::{DigitOrder, ParseBigIntError};
+use crate::{TryFromBigIntError, TryFromFloatError};
 
+use alloc::string::String;
 use alloc::vec::Vec;
-use core::cmp::Ordering::{Equal, Greater, Less};
+use core::cmp::Ordering::{self, Equal, Greater, Less};
 use core::convert::TryFrom;
 use core::mem;
+use core::num::{
+    NonZeroI128, NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroIsize, NonZeroU128,
+    NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize,
+};
 use core::str::FromStr;
 use num_integer::{Integer, Roots};
 use num_traits::float::FloatCore;
-use num_traits::{FromPrimitive, Num, One, PrimInt, ToPrimitive, Zero};
+use num_traits::{FromPrimitive, Num, One, Pow, PrimInt, ToPrimitive, Zero};
 
 /// Find last set bit
 /// fls(0) == 0, fls(u32::MAX) == 32
@@ -118,6 +124,13 @@ fn from_radix_digits_be(v: &[u8], radix: u32) -> BigUint {
     };
 
     let mut data = Vec::with_capacity(big_digits.to_usize().unwrap_or(0));
+    trace_span!(
+        if data.capacity() > 32,
+        "from_radix",
+        digits = v.len(),
+        radix,
+        algorithm = "digitwise",
+    );
 
     let (base, power) = get_radix_base(radix);
     let radix = radix as BigDigit;
@@ -214,6 +227,83 @@ pub(super) fn from_radix_le(buf: &[u8], radix: u32) -> Option<BigUint> {
     Some(res)
 }
 
+/// Reads radix digits from an iterator without collecting them.
+///
+/// The digits are packed as they arrive into words of `power` digits each, which are then
+/// combined by [`combine_chunks`].
+pub(super) fn from_radix_digits_iter<I>(digits: I, radix: u32, order: DigitOrder) -> Option<BigUint>
+where
+    I: IntoIterator<Item = u8>,
+{
+    assert!(
+        2 <= radix && radix <= 256,
+        "The radix must be within 2...256"
+    );
+    let radix = BigDigit::from(radix);
+
+    // The largest power of the radix that fits in a word.
+    let (mut base, mut power) = (radix, 1);
+    while let Some(next) = base.checked_mul(radix) {
+        base = next;
+        power += 1;
+    }
+
+    // Whole chunks in the order they arrive, then the digits of a partial one.
+    let mut chunks = Vec::new();
+    let (mut chunk, mut scale, mut len) = (0, 1, 0);
+    for d in digits {
+        let d = BigDigit::from(d);
+        if d >= radix {
+            return None;
+        }
+        match order {
+            DigitOrder::MostSignificantFirst => chunk = chunk * radix + d,
+            DigitOrder::LeastSignificantFirst => chunk += d * scale,
+        }
+        scale *= radix;
+        len += 1;
+        if len == power {
+            chunks.push(chunk);
+            (chunk, scale, len) = (0, 1, 0);
+        }
+    }
+
+    let res = match order {
+        DigitOrder::MostSignificantFirst => {
+            chunks.reverse();
+            combine_chunks(&chunks, base) * scale + chunk
+        }
+        DigitOrder::LeastSignificantFirst => {
+            chunks.push(chunk);
+            combine_chunks(&chunks, base)
+        }
+    };
+    Some(res)
+}
+
+/// Returns the number whose digits in base `base` are `chunks`, least significant first.
+///
+/// Long runs are split in halves, so the product that joins them is balanced and can use the
+/// subquadratic multiplication algorithms.
+fn combine_chunks(chunks: &[BigDigit], base: BigDigit) -> BigUint {
+    if chunks.len() <= 32 {
+        let mut data = Vec::with_capacity(chunks.len());
+        for &c in chunks.iter().rev() {
+            let mut carry = DoubleBigDigit::from(c);
+            for d in &mut data {
+                *d = mac_with_carry(0, *d, base, &mut carry);
+            }
+            if carry != 0 {
+                data.push(BigDigit::try_from(carry).unwrap());
+            }
+        }
+        return biguint_from_vec(data);
+    }
+
+    let (lo, hi) = chunks.split_at(chunks.len() / 2);
+    combine_chunks(hi, base) * Pow::pow(BigUint::from(base), lo.len()) + combine_chunks(lo, base)
+}
+
 impl Num for BigUint {
     type FromStrRadixErr = ParseBigIntError;
 
@@ -253,6 +343,13 @@ impl Num for BigUint {
             }
         }
 
+        // Each digit after the leading one adds at least `floor(log2(radix))` bits.
+        let significant = v.iter().position(|&d| d != 0).map_or(0, |i| v.len() - i);
+        let min_bits = (significant.saturating_sub(1) as u64).saturating_mul(ilog2(radix).into());
+        if crate::limits::exceeds(min_bits) {
+            return Err(ParseBigIntError::too_large());
+        }
+
         let res = if radix.is_power_of_two() {
             // Powers of two can use bitwise masks and shifting instead of multiplication
             let bits = ilog2(radix);
@@ -265,6 +362,9 @@ impl Num for BigUint {
         } else {
             from_radix_digits_be(&v, radix)
         };
+        if crate::limits::exceeds(res.bits()) {
+            return Err(ParseBigIntError::too_large());
+        }
         Ok(res)
     }
 }
@@ -319,6 +419,23 @@ fn high_bits_to_u64(v: &BigUint) -> u64 {
     }
 }
 
+#[allow(clippy::cast_precision_loss, clippy::float_cmp)]
+pub(super) fn to_f64_exp(x: &BigUint) -> (f64, u64) {
+    let mantissa = high_bits_to_u64(x);
+    if mantissa == 0 {
+        return (0.0, 0);
+    }
+    // Dividing by a power of two is exact, but converting to `f64` may have rounded
+    // the mantissa up to the next power of two.
+    let mut exponent = x.bits();
+    let mut m = (mantissa as f64) / 2.0f64.powi(i32::from(fls(mantissa)));
+    if m == 1.0 {
+        m = 0.5;
+        exponent += 1;
+    }
+    (m, exponent)
+}
+
 impl ToPrimitive for BigUint {
     #[inline]
     fn to_i64(&self) -> Option<i64> {
@@ -366,6 +483,7 @@ impl ToPrimitive for BigUint {
         Some(ret)
     }
 
+    /// Converts to the nearest `f32`, with ties to even, or infinity if it is too large.
     #[inline]
     fn to_f32(&self) -> Option<f32> {
         let mantissa = high_bits_to_u64(self);
@@ -378,6 +496,7 @@ impl ToPrimitive for BigUint {
         }
     }
 
+    /// Converts to the nearest `f64`, with ties to even, or infinity if it is too large.
     #[inline]
     fn to_f64(&self) -> Option<f64> {
         let mantissa = high_bits_to_u64(self);
@@ -407,7 +526,7 @@ macro_rules! impl_try_from_biguint {
 
             #[inline]
             fn try_from(value: BigUint) -> Result<$T, TryFromBigIntError<BigUint>> {
-                <$T>::try_from(&value).map_err(|_| TryFromBigIntError::new(value))
+                <$T>::try_from(&value).map_err(|e| e.with_original(value))
             }
         }
     };
@@ -427,6 +546,84 @@ impl_try_from_biguint!(i64, ToPrimitive::to_i64);
 impl_try_from_biguint!(isize, ToPrimitive::to_isize);
 impl_try_from_biguint!(i128, ToPrimitive::to_i128);
 
+macro_rules! impl_try_from_biguint_nonzero {
+    ($($NonZero:ty => $T:ty),*) => {$(
+        impl TryFrom<&BigUint> for $NonZero {
+            type Error = TryFromBigIntError<()>;
+
+            #[inline]
+            fn try_from(value: &BigUint) -> Result<$NonZero, TryFromBigIntError<()>> {
+                let n = <$T>::try_from(value)?;
+                <$NonZero>::new(n).ok_or(TryFromBigIntError::zero(()))
+            }
+        }
+
+        impl TryFrom<BigUint> for $NonZero {
+            type Error = TryFromBigIntError<BigUint>;
+
+            #[inline]
+            fn try_from(value: BigUint) -> Result<$NonZero, TryFromBigIntError<BigUint>> {
+                <$NonZero>::try_from(&value).map_err(|e| e.with_original(value))
+            }
+        }
+    )*};
+}
+
+impl_try_from_biguint_nonzero!(
+    NonZeroU8 => u8, NonZeroU16 => u16, NonZeroU32 => u32, NonZeroU64 => u64,
+    NonZeroUsize => usize, NonZeroU128 => u128,
+    NonZeroI8 => i8, NonZeroI16 => i16, NonZeroI32 => i32, NonZeroI64 => i64,
+    NonZeroIsize => isize, NonZeroI128 => i128
+);
+
+/// Compares exactly against the float's value, rather than rounding `self` to `f64`.
+/// NaN is unordered, and infinities compare beyond every finite value.
+fn cmp_f64(x: &BigUint, f: f64) -> Option<Ordering> {
+    if f.is_nan() {
+        return None;
+    }
+    if f == f64::INFINITY {
+        return Some(Less);
+    }
+    if f < 0.0 {
+        return Some(Greater);
+    }
+    // `floor` is exact, and every finite integer-valued float converts exactly.
+    let floor = FloatCore::floor(f);
+    match x.cmp(&BigUint::from_f64(floor).unwrap()) {
+        Equal if f > floor => Some(Less),
+        ord => Some(ord),
+    }
+}
+
+impl PartialEq<f64> for BigUint {
+    #[inline]
+    fn eq(&self, other: &f64) -> bool {
+        cmp_f64(self, *other) == Some(Equal)
+    }
+}
+
+impl PartialOrd<f64> for BigUint {
+    #[inline]
+    fn partial_cmp(&self, other: &f64) -> Option<Ordering> {
+        cmp_f64(self, *other)
+    }
+}
+
+impl PartialEq<BigUint> for f64 {
+    #[inline]
+    fn eq(&self, other: &BigUint) -> bool {
+        cmp_f64(other, *self) == Some(Equal)
+    }
+}
+
+impl PartialOrd<BigUint> for f64 {
+    #[inline]
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        cmp_f64(other, *self).map(Ordering::reverse)
+    }
+}
+
 impl FromPrimitive for BigUint {
     #[inline]
     fn from_i64(n: i64) -> Option<Self> {
@@ -479,7 +676,7 @@ impl FromPrimitive for BigUint {
 
         let mut ret = Self::from(mantissa);
         match exponent.cmp(&0) {
-            Greater => ret <<= exponent as usize,
+            Greater => ret = ret.into_shl_unchecked(u64::from(exponent.unsigned_abs())),
             Equal => {}
             Less => ret >>= (-exponent) as usize,
         }
@@ -539,7 +736,7 @@ macro_rules! impl_biguint_try_from_int {
 
             #[inline]
             fn try_from(value: $T) -> Result<BigUint, TryFromBigIntError<()>> {
-                $from_ty(value).ok_or(TryFromBigIntError::new(()))
+                $from_ty(value).ok_or(TryFromBigIntError::negative(()))
             }
         }
     };
@@ -552,6 +749,24 @@ impl_biguint_try_from_int!(i64, FromPrimitive::from_i64);
 impl_biguint_try_from_int!(isize, FromPrimitive::from_isize);
 impl_biguint_try_from_int!(i128, FromPrimitive::from_i128);
 
+/// Converts an `f64` that is exactly a non-negative integer value, unlike
+/// [`FromPrimitive::from_f64`] which silently truncates any fractional part.
+impl TryFrom<f64> for BigUint {
+    type Error = TryFromFloatError;
+
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        if !value.is_finite() {
+            Err(TryFromFloatError::not_finite())
+        } else if FloatCore::fract(value) != 0.0 {
+            Err(TryFromFloatError::fractional())
+        } else if value < 0.0 {
+            Err(TryFromFloatError::negative())
+        } else {
+            Self::from_f64(value).ok_or(TryFromFloatError::not_finite())
+        }
+    }
+}
+
 impl ToBigUint for BigUint {
     #[inline]
     fn to_biguint(&self) -> Option<BigUint> {
@@ -666,9 +881,13 @@ fn to_inexact_bitwise_digits_le(u: &BigUint, bits: u8) -> Vec<u8> {
     res
 }
 
-// Extract little-endian radix digits
+// Extract little-endian radix digits, returning the error of `token` if it is set first
 #[inline(always)] // forced inline to get const-prop for radix=10
-pub(super) fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
+pub(super) fn to_radix_digits_le<T: Token + ?Sized>(
+    u: &BigUint,
+    radix: u32,
+    token: &T,
+) -> Result<Vec<u8>, T::Error> {
     debug_assert!(!u.is_zero() && !radix.is_power_of_two());
 
     #[cfg(feature = "std")]
@@ -686,6 +905,13 @@ pub(super) fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
     let mut res = Vec::with_capacity(radix_digits.to_usize().unwrap_or(0));
 
     let mut digits = u.clone();
+    trace_span!(
+        if digits.data.len() > 32,
+        "to_radix",
+        bits = u.bits(),
+        radix,
+        algorithm = if digits.data.len() >= 64 { "chunked" } else { "digitwise" },
+    );
 
     // X86 DIV can quickly divide by a full digit, otherwise we choose a divisor
     // that's suitable for `div_half` to avoid slow `DoubleBigDigit` division.
@@ -714,7 +940,7 @@ pub(super) fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
         // This outer loop will run approximately √n times.
         while digits > big_base {
             // This is still the dominating factor, with n digits divided by √n digits.
-            let (q, mut big_r) = digits.div_rem(&big_base);
+            let (q, mut big_r) = div_rem_cancellable(&digits, &big_base, token)?;
             digits = q;
 
             // This inner loop now has O(√n²)=O(n) behavior altogether.
@@ -730,6 +956,7 @@ pub(super) fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
     }
 
     while digits.data.len() > 1 {
+        token.check()?;
         let (q, mut r) = div_rem_digit(digits, base);
         for _ in 0..power {
             res.push((r % radix) as u8);
@@ -744,37 +971,199 @@ pub(super) fn to_radix_digits_le(u: &BigUint, radix: u32) -> Vec<u8> {
         r /= radix;
     }
 
-    res
+    Ok(res)
 }
 
 pub(super) fn to_radix_le(u: &BigUint, radix: u32) -> Vec<u8> {
+    cancel::unwrap(to_radix_le_cancellable(u, radix, &()))
+}
+
+/// [`to_radix_le`], returning the error of `token` if it is set before the conversion is done.
+fn to_radix_le_cancellable<T: Token + ?Sized>(
+    u: &BigUint,
+    radix: u32,
+    token: &T,
+) -> Result<Vec<u8>, T::Error> {
+    #[cfg(feature = "rayon")]
+    if u.data.len() >= parallel::THRESHOLD && !radix.is_power_of_two() {
+        return parallel::to_radix_digits_le(u, radix, token);
+    }
+
     if u.is_zero() {
-        vec![0]
+        Ok(vec![0])
     } else if radix.is_power_of_two() {
         // Powers of two can use bitwise masks and shifting instead of division
         let bits = ilog2(radix);
         if big_digit::BITS % bits == 0 {
-            to_bitwise_digits_le(u, bits)
+            Ok(to_bitwise_digits_le(u, bits))
         } else {
-            to_inexact_bitwise_digits_le(u, bits)
+            Ok(to_inexact_bitwise_digits_le(u, bits))
         }
     } else if radix == 10 {
         // 10 is so common that it's worth separating out for const-propagation.
         // Optimizers can often turn constant division into a faster multiplication.
-        to_radix_digits_le(u, 10)
+        to_radix_digits_le(u, 10, token)
     } else {
-        to_radix_digits_le(u, radix)
+        to_radix_digits_le(u, radix, token)
+    }
+}
+
+/// Returns the position of each symbol in `alphabet`, or `u16::MAX` for bytes not in it.
+fn alphabet_indices(alphabet: &[u8]) -> [u16; 256] {
+    assert!(
+        2 <= alphabet.len() && alphabet.len() <= 256,
+        "The alphabet must have 2...256 symbols"
+    );
+    let mut indices = [u16::MAX; 256];
+    for (i, &symbol) in alphabet.iter().enumerate() {
+        let index = &mut indices[usize::from(symbol)];
+        assert!(
+            *index == u16::MAX,
+            "The alphabet has the symbol {:?} more than once",
+            char::from(symbol)
+        );
+        *index = u16::try_from(i).unwrap();
+    }
+    indices
+}
+
+pub(super) fn to_radix_custom(u: &BigUint, alphabet: &[u8]) -> Vec<u8> {
+    alphabet_indices(alphabet);
+    let radix = u32::try_from(alphabet.len()).unwrap();
+    let mut res = to_radix_le(u, radix);
+    res.reverse();
+    for d in &mut res {
+        *d = alphabet[usize::from(*d)];
+    }
+    res
+}
+
+pub(super) fn from_radix_custom(buf: &[u8], alphabet: &[u8]) -> Option<BigUint> {
+    let indices = alphabet_indices(alphabet);
+    if buf.is_empty() {
+        return None;
+    }
+    let digits = buf
+        .iter()
+        .map(|&symbol| u8::try_from(indices[usize::from(symbol)]).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    from_radix_be(&digits, u32::try_from(alphabet.len()).unwrap())
+}
+
+pub(super) fn to_mixed_radix(u: &BigUint, radices: &[u64]) -> (Vec<u64>, BigUint) {
+    let mut high = u.clone();
+    let mut digits = Vec::with_capacity(radices.len());
+    for &radix in radices {
+        assert!(radix != 0, "attempt to divide by zero");
+        let (q, r) = high.div_rem(&BigUint::from(radix));
+        digits.push(r.to_u64().unwrap());
+        high = q;
+    }
+    (digits, high)
+}
+
+pub(super) fn from_mixed_radix(digits: &[u64], radices: &[u64], high: BigUint) -> Option<BigUint> {
+    assert_eq!(
+        digits.len(),
+        radices.len(),
+        "There must be one digit for each radix"
+    );
+    let mut res = high;
+    for (&digit, &radix) in digits.iter().zip(radices).rev() {
+        if digit >= radix {
+            return None;
+        }
+        res *= radix;
+        res += digit;
+    }
+    Some(res)
+}
+
+#[cfg(feature = "rayon")]
+mod parallel {
+    use super::{get_radix_base, to_radix_digits_le as to_radix_digits_le_seq, BigUint};
+    use crate::biguint::cancel::Token;
+    use crate::biguint::division::div_rem_cancellable;
+
+    use alloc::vec::Vec;
+    use num_integer::Integer;
+    use num_traits::Zero;
+
+    /// Values with at least this many digits are split for parallel conversion, and the pieces
+    /// are split again until they are smaller than this.
+    pub(super) const THRESHOLD: usize = 1 << 12;
+
+    /// Converts by splitting around powers `radix^(m * 2^k)`, converting the quotient and the
+    /// remainder on separate threads, and joining them with the remainder zero-padded to its
+    /// full width. Every split point depends only on the value, so the output is the same as
+    /// the sequential conversion.
+    pub(super) fn to_radix_digits_le<T: Token + ?Sized>(
+        u: &BigUint,
+        radix: u32,
+        token: &T,
+    ) -> Result<Vec<u8>, T::Error> {
+        let (base, power) = get_radix_base(radix);
+        let mut powers = vec![(BigUint::from(base), power)];
+        loop {
+            let (last, width) = powers.last().unwrap();
+            if last.data.len() * 2 > u.data.len() {
+                break;
+            }
+            let next = (last * last, width * 2);
+            powers.push(next);
+        }
+        convert(u, radix, &powers, token)
+    }
+
+    fn convert<T: Token + ?Sized>(
+        u: &BigUint,
+        radix: u32,
+        powers: &[(BigUint, usize)],
+        token: &T,
+    ) -> Result<Vec<u8>, T::Error> {
+        if u.is_zero() {
+            return Ok(Vec::new());
+        }
+        if u.data.len() < THRESHOLD {
+            return to_radix_digits_le_seq(u, radix, token);
+        }
+
+        // Split near the middle, so both halves have similar work.
+        let k = powers
+            .iter()
+            .rposition(|(p, _)| p.data.len() * 2 <= u.data.len() + 1)
+            .unwrap_or(0);
+        let (divisor, width) = &powers[k];
+        let (high, low) = div_rem_cancellable(u, divisor, token)?;
+        let (low, high) = rayon::join(
+            || convert(&low, radix, &powers[..k], token),
+            || convert(&high, radix, &powers[..=k], token),
+        );
+        let mut low = low?;
+        low.resize(*width, 0);
+        low.extend(high?);
+        Ok(low)
     }
 }
 
 pub(crate) fn to_str_radix_reversed(u: &BigUint, radix: u32) -> Vec<u8> {
+    cancel::unwrap(to_str_radix_reversed_cancellable(u, radix, &()))
+}
+
+/// [`to_str_radix_reversed`], returning the error of `token` if it is set before the
+/// conversion is done.
+pub(super) fn to_str_radix_reversed_cancellable<T: Token + ?Sized>(
+    u: &BigUint,
+    radix: u32,
+    token: &T,
+) -> Result<Vec<u8>, T::Error> {
     assert!(2 <= radix && radix <= 36, "The radix must be within 2...36");
 
     if u.is_zero() {
-        return vec![b'0'];
+        return Ok(vec![b'0']);
     }
 
-    let mut res = to_radix_le(u, radix);
+    let mut res = to_radix_le_cancellable(u, radix, token)?;
 
     // Now convert everything to ASCII digits.
     for r in &mut res {
@@ -785,7 +1174,159 @@ pub(crate) fn to_str_radix_reversed(u: &BigUint, radix: u32) -> Vec<u8> {
             *r += b'a' - 10;
         }
     }
-    res
+    Ok(res)
+}
+
+/// Returns `(m >> shift, shift)`, with `shift` chosen to leave at most `prec` bits, rounded up
+/// instead of down if `up` is set and any of the bits shifted out are ones.
+fn truncate_bits(m: &BigUint, prec: u64, up: bool) -> (BigUint, u64) {
+    let bits = m.bits();
+    if bits <= prec {
+        return (m.clone(), 0);
+    }
+    let shift = bits - prec;
+    let mut t = m >> shift;
+    if up && m.trailing_zeros().map_or(false, |zeros| zeros < shift) {
+        t += 1u32;
+    }
+    (t, shift)
+}
+
+/// Returns a bound `(m, shift)` on `5^exp`, where `m` has about `prec` bits and `m << shift` is
+/// at most `5^exp`, or at least `5^exp` if `up` is set.
+fn pow5_bound(exp: u64, prec: u64, up: bool) -> (BigUint, u64) {
+    let (mut m, mut shift) = (BigUint::one(), 0);
+    for i in (0..u64::BITS - exp.leading_zeros()).rev() {
+        let (square, s) = truncate_bits(&(&m * &m), prec, up);
+        m = square;
+        shift = 2 * shift + s;
+        if (exp >> i) & 1 == 1 {
+            let (product, s) = truncate_bits(&(m * 5u8), prec, up);
+            m = product;
+            shift += s;
+        }
+    }
+    (m, shift)
+}
+
+/// Returns `floor(a * 2^shift / b)`.
+fn div_shifted(a: &BigUint, shift: i128, b: &BigUint) -> BigUint {
+    if shift >= 0 {
+        a.shl_unchecked(u64::try_from(shift).unwrap()) / b
+    } else {
+        a / b.shl_unchecked(u64::try_from(-shift).unwrap())
+    }
+}
+
+pub(super) fn to_string_approx(u: &BigUint, sig_digits: usize) -> (String, u64) {
+    assert!(sig_digits > 0, "at least one significant digit is required");
+    let k = u64::try_from(sig_digits).unwrap();
+
+    // The number of decimal digits is `floor((bits - 1) * log10(2)) + 1` or one more.
+    let bits = u.bits();
+    let log10_2 = 301_029_995_663_981_195; // 10^18 * log10(2), rounded down
+    let digits = u128::from(bits.saturating_sub(1)) * log10_2 / 1_000_000_000_000_000_000;
+    let digits = u64::try_from(digits).unwrap() + 1;
+    if digits <= k {
+        let mut s = u.to_str_radix(10);
+        let exp = s.len().saturating_sub(sig_digits);
+        s.truncate(sig_digits);
+        return (s, u64::try_from(exp).unwrap());
+    }
+
+    // Bound `u / 10^exp` between two quotients of `prec`-bit numbers, and add precision until
+    // they agree on a value of exactly `sig_digits` digits. Only values extremely close to a
+    // multiple of `10^exp` need much more than the size of the answer.
+    let low = Pow::pow(BigUint::from(10u8), k - 1);
+    let high = &low * 10u8;
+    let mut exp = digits - k;
+    let mut prec = 4 * k + 64;
+    loop {
+        let (x_lo, x_shift) = truncate_bits(u, prec, false);
+        let (x_hi, _) = truncate_bits(u, prec, true);
+        let (p_lo, p_lo_shift) = pow5_bound(exp, prec, false);
+        let (p_hi, p_hi_shift) = pow5_bound(exp, prec, true);
+        let shift = i128::from(x_shift) - i128::from(exp);
+        let q_lo = div_shifted(&x_lo, shift - i128::from(p_hi_shift), &p_hi);
+        let q_hi = div_shifted(&x_hi, shift - i128::from(p_lo_shift), &p_lo);
+
+        if q_hi < low {
+            exp -= 1;
+        } else if q_lo >= high {
+            exp += 1;
+        } else if q_lo == q_hi {
+            return (q_lo.to_str_radix(10), exp);
+        } else {
+            prec *= 2;
+        }
+    }
+}
+
+/// Returns the RLP prefix for a string of `len` bytes.
+fn rlp_header(len: usize) -> Vec<u8> {
+    match u8::try_from(len) {
+        Ok(n) if n <= 55 => vec![0x80 + n],
+        _ => {
+            let len = len.to_be_bytes();
+            let len = &len[len.iter().take_while(|&&b| b == 0).count()..];
+            let mut header = vec![0xb7 + u8::try_from(len.len()).unwrap()];
+            header.extend_from_slice(len);
+            header
+        }
+    }
+}
+
+pub(super) fn to_rlp(u: &BigUint) -> Vec<u8> {
+    // Zero is the empty string, and values below 0x80 are their own encoding.
+    let body = if u.is_zero() {
+        Vec::new()
+    } else {
+        u.to_bytes_be()
+    };
+    if let [b] = *body {
+        if b < 0x80 {
+            return body;
+        }
+    }
+    let mut bytes = rlp_header(body.len());
+    bytes.extend_from_slice(&body);
+    bytes
+}
+
+pub(super) fn from_rlp(bytes: &[u8]) -> Option<(BigUint, &[u8])> {
+    let (&prefix, rest) = bytes.split_first()?;
+    let (len, rest) = match prefix {
+        0x01..=0x7f => return Some((BigUint::from(prefix), rest)),
+        0x80..=0xb7 => (usize::from(prefix - 0x80), rest),
+        0xb8..=0xbf => {
+            let n = usize::from(prefix - 0xb7);
+            if rest.len() < n || rest[0] == 0 {
+                return None;
+            }
+            let (len, rest) = rest.split_at(n);
+            let len = len.iter().try_fold(0usize, |acc, &b| {
+                acc.checked_mul(256).map(|acc| acc + usize::from(b))
+            })?;
+            // The long form is only for strings that the short form cannot hold.
+            if len <= 55 {
+                return None;
+            }
+            (len, rest)
+        }
+        // Zero is the empty string `0x80`, so a zero byte would be a leading zero, and lists
+        // are not integers.
+        0x00 | 0xc0..=0xff => return None,
+    };
+    if len > rest.len() {
+        return None;
+    }
+    let (body, rest) = rest.split_at(len);
+    // Integers have no leading zeros, and a single byte below 0x80 has no prefix.
+    match *body {
+        [0, ..] => None,
+        [b] if b < 0x80 => None,
+        _ => Some((BigUint::from_bytes_be(body), rest)),
+    }
 }
 
 /// Returns the greatest power of the radix for the `BigDigit` bit size
@@ -0,0 +1,70 @@
+#![cfg(feature = "crypto-bigint")]
+#![cfg_attr(docsrs, doc(cfg(feature = "crypto-bigint")))]
+
+use super::{biguint_from_u64_digits, BigUint};
+
+use crate::TryFromBigIntError;
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use crypto_bigint::{Uint, Word};
+
+// `Word` is 32 or 64 bits depending on the target, independent of our own `BigDigit` size.
+const WORD_64: bool = Word::BITS == 64;
+
+impl<const LIMBS: usize> TryFrom<&BigUint> for Uint<LIMBS> {
+    type Error = TryFromBigIntError<()>;
+
+    #[allow(clippy::unnecessary_cast)]
+    fn try_from(value: &BigUint) -> Result<Self, TryFromBigIntError<()>> {
+        let mut words = [0; LIMBS];
+        if WORD_64 {
+            let digits = value.iter_u64_digits();
+            if digits.len() > LIMBS {
+                return Err(TryFromBigIntError::new(()));
+            }
+            for (word, digit) in words.iter_mut().zip(digits) {
+                *word = digit as Word;
+            }
+        } else {
+            let digits = value.iter_u32_digits();
+            if digits.len() > LIMBS {
+                return Err(TryFromBigIntError::new(()));
+            }
+            for (word, digit) in words.iter_mut().zip(digits) {
+                *word = digit as Word;
+            }
+        }
+        Ok(Uint::from_words(words))
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BigUint> for Uint<LIMBS> {
+    type Error = TryFromBigIntError<BigUint>;
+
+    #[inline]
+    fn try_from(value: BigUint) -> Result<Self, TryFromBigIntError<BigUint>> {
+        Uint::try_from(&value).map_err(|e| e.with_original(value))
+    }
+}
+
+impl<const LIMBS: usize> From<&Uint<LIMBS>> for BigUint {
+    fn from(value: &Uint<LIMBS>) -> Self {
+        let words = value.as_words();
+        if WORD_64 {
+            #[allow(clippy::useless_conversion)]
+            biguint_from_u64_digits(words.iter().map(|&word| u64::from(word)))
+        } else {
+            #[allow(clippy::unnecessary_cast)]
+            let digits: Vec<u32> = words.iter().map(|&word| word as u32).collect();
+            BigUint::new(digits)
+        }
+    }
+}
+
+impl<const LIMBS: usize> From<Uint<LIMBS>> for BigUint {
+    #[inline]
+    fn from(value: Uint<LIMBS>) -> Self {
+        Self::from(&value)
+    }
+}
@@ -0,0 +1,24 @@
+#![cfg(feature = "defmt")]
+#![cfg_attr(docsrs, doc(cfg(feature = "defmt")))]
+
+use super::BigUint;
+
+/// Logs the value in hexadecimal, most significant limb first, followed by its bit length, as
+/// in `0x1fffffffffffffffe (65 bits)`.
+///
+/// A decimal conversion would cost a long division per output digit on the target, while hex
+/// is just the limbs as they are stored.
+impl defmt::Format for BigUint {
+    fn format(&self, f: defmt::Formatter<'_>) {
+        let mut limbs = self.iter_u64_digits().rev();
+        if let Some(top) = limbs.next() {
+            defmt::write!(f, "0x{=u64:x}", top);
+        } else {
+            defmt::write!(f, "0x0");
+        }
+        for limb in limbs {
+            defmt::write!(f, "{=u64:016x}", limb);
+        }
+        defmt::write!(f, " ({=u64} bits)", self.bits());
+    }
+}
@@ -1,10 +1,13 @@
 use super::addition::__add2;
-use super::{cmp_slice, BigUint};
+use super::cancel::{self, Token};
+use super::{cmp_slice, lowlevel, scratch, BigUint};
 
 use crate::big_digit::{self, BigDigit, DoubleBigDigit};
 use crate::UsizePromotion;
 
+use alloc::vec::Vec;
 use core::cmp::Ordering::{Equal, Greater, Less};
+use core::convert::TryFrom;
 use core::mem;
 use core::ops::{Div, DivAssign, Rem, RemAssign};
 use num_integer::Integer;
@@ -83,27 +86,71 @@ fn div_half(rem: BigDigit, digit: BigDigit, divisor: BigDigit) -> (BigDigit, Big
 
 #[inline]
 pub(super) fn div_rem_digit(mut a: BigUint, b: BigDigit) -> (BigUint, BigDigit) {
+    let rem = div_rem_digit_in_place(&mut a, b);
+    (a, rem)
+}
+
+/// Divides `a` by the digit `b` in place, returning the remainder.
+#[inline]
+fn div_rem_digit_in_place(a: &mut BigUint, b: BigDigit) -> BigDigit {
+    let rem = div_rem_digits_in_place(&mut a.data, b);
+    a.normalize();
+    rem
+}
+
+/// Divides the digits `a` by the digit `b` in place, returning the remainder, without
+/// trimming the zeros this leaves at the top.
+#[inline]
+pub(super) fn div_rem_digits_in_place(a: &mut [BigDigit], b: BigDigit) -> BigDigit {
     assert!((b != 0), "attempt to divide by zero");
 
     let mut rem = 0;
 
     if !FAST_DIV_WIDE && b <= big_digit::HALF {
-        for d in a.data.iter_mut().rev() {
+        for d in a.iter_mut().rev() {
             let (q, r) = div_half(rem, *d, b);
             *d = q;
             rem = r;
         }
     } else {
-        for d in a.data.iter_mut().rev() {
+        for d in a.iter_mut().rev() {
             let (q, r) = div_wide(rem, *d, b);
             *d = q;
             rem = r;
         }
     }
 
-    (a.normalized(), rem)
+    rem
 }
 
+cfg_digit!(
+    /// Divides `a` by a `u64` in place, returning the remainder, without promoting the divisor
+    /// to a `BigUint`.
+    pub(super) fn div_rem_u64_in_place(a: &mut BigUint, b: u64) -> u64 {
+        if let Ok(b) = BigDigit::try_from(b) {
+            return div_rem_digit_in_place(a, b).into();
+        }
+
+        // Each step divides `[rem, digit]`, which is below `b << BITS`.
+        let mut rem: u64 = 0;
+        for d in a.data.iter_mut().rev() {
+            let n = (u128::from(rem) << big_digit::BITS) | u128::from(*d);
+            let divisor = u128::from(b);
+            *d = (n / divisor) as BigDigit;
+            rem = (n % divisor) as u64;
+        }
+        a.normalize();
+        rem
+    }
+
+    /// Divides `a` by a `u64` in place, returning the remainder, without promoting the divisor
+    /// to a `BigUint`.
+    #[inline]
+    pub(super) fn div_rem_u64_in_place(a: &mut BigUint, b: u64) -> u64 {
+        div_rem_digit_in_place(a, b)
+    }
+);
+
 #[inline]
 fn rem_digit(a: &BigUint, b: BigDigit) -> BigDigit {
     assert!((b != 0), "attempt to divide by zero");
@@ -191,33 +238,48 @@ fn div_rem(mut u: BigUint, mut d: BigUint) -> (BigUint, BigUint) {
 
     if shift == 0 {
         // no need to clone d
-        div_rem_core(u, &d.data)
+        cancel::unwrap(div_rem_core(u, &d.data, &()))
     } else {
-        let (q, r) = div_rem_core(u << shift, &(d << shift).data);
+        let divisor = normalized_divisor(&d, shift);
+        let (q, r) = cancel::unwrap(div_rem_core(
+            u.into_shl_unchecked(shift as u64),
+            &divisor,
+            &(),
+        ));
+        scratch::give(divisor);
         // renormalize the remainder
         (q, r >> shift)
     }
 }
 
 pub(super) fn div_rem_ref(u: &BigUint, d: &BigUint) -> (BigUint, BigUint) {
+    cancel::unwrap(div_rem_cancellable(u, d, &()))
+}
+
+/// [`div_rem_ref`], returning the error of `token` if it is set before the division is done.
+pub(super) fn div_rem_cancellable<T: Token + ?Sized>(
+    u: &BigUint,
+    d: &BigUint,
+    token: &T,
+) -> Result<(BigUint, BigUint), T::Error> {
     assert!(!d.is_zero(), "attempt to divide by zero");
     if u.is_zero() {
-        return (BigUint::ZERO, BigUint::ZERO);
+        return Ok((BigUint::ZERO, BigUint::ZERO));
     }
 
     if d.data.len() == 1 {
         if d.data == [1] {
-            return (u.clone(), BigUint::ZERO);
+            return Ok((u.clone(), BigUint::ZERO));
         }
 
         let (div, rem) = div_rem_digit(u.clone(), d.data[0]);
-        return (div, rem.into());
+        return Ok((div, rem.into()));
     }
 
     // Required or the q_len calculation below can underflow:
     match u.cmp(d) {
-        Less => return (BigUint::ZERO, u.clone()),
-        Equal => return (One::one(), BigUint::ZERO),
+        Less => return Ok((BigUint::ZERO, u.clone())),
+        Equal => return Ok((One::one(), BigUint::ZERO)),
         Greater => {} // Do nothing
     }
 
@@ -231,19 +293,118 @@ pub(super) fn div_rem_ref(u: &BigUint, d: &BigUint) -> (BigUint, BigUint) {
 
     if shift == 0 {
         // no need to clone d
-        div_rem_core(u.clone(), &d.data)
+        div_rem_core(u.clone(), &d.data, token)
     } else {
-        let (q, r) = div_rem_core(u << shift, &(d << shift).data);
+        let divisor = normalized_divisor(d, shift);
+        let (q, r) = div_rem_core(u.shl_unchecked(shift as u64), &divisor, token)?;
+        scratch::give(divisor);
         // renormalize the remainder
-        (q, r >> shift)
+        Ok((q, r >> shift))
     }
 }
 
+/// The remainder of [`div_rem`], without computing the quotient.
+fn rem(u: BigUint, d: &BigUint) -> BigUint {
+    assert!(!d.is_zero(), "attempt to divide by zero");
+    if d.data.len() == 1 {
+        return rem_digit(&u, d.data[0]).into();
+    }
+    if u < *d {
+        return u;
+    }
+
+    let shift = d.data.last().unwrap().leading_zeros() as usize;
+    if shift == 0 {
+        rem_core(u, &d.data)
+    } else {
+        let divisor = normalized_divisor(d, shift);
+        let r = rem_core(u.into_shl_unchecked(shift as u64), &divisor);
+        scratch::give(divisor);
+        r >> shift
+    }
+}
+
+/// The remainder of [`div_rem_ref`], without computing the quotient.
+pub(super) fn rem_ref(u: &BigUint, d: &BigUint) -> BigUint {
+    assert!(!d.is_zero(), "attempt to divide by zero");
+    if d.data.len() == 1 {
+        return rem_digit(u, d.data[0]).into();
+    }
+    if u < d {
+        return u.clone();
+    }
+
+    let shift = d.data.last().unwrap().leading_zeros() as usize;
+    if shift == 0 {
+        rem_core(u.clone(), &d.data)
+    } else {
+        let divisor = normalized_divisor(d, shift);
+        let r = rem_core(u.shl_unchecked(shift as u64), &divisor);
+        scratch::give(divisor);
+        r >> shift
+    }
+}
+
+/// Returns the digits of `d << shift`, for the shift that sets the top bit of its most
+/// significant digit, in a buffer from the scratch pool to [`give`](scratch::give) back after
+/// the division.
+fn normalized_divisor(d: &BigUint, shift: usize) -> Vec<BigDigit> {
+    let mut b = scratch::take(d.data.len());
+    let carry = lowlevel::shl(&mut b, &d.data, u32::try_from(shift).unwrap());
+    debug_assert_eq!(carry, 0);
+    b
+}
+
 /// An implementation of the base division algorithm.
 /// Knuth, TAOCP vol 2 section 4.3.1, algorithm D, with an improvement from exercises 19-21.
-fn div_rem_core(mut a: BigUint, b: &[BigDigit]) -> (BigUint, BigUint) {
+fn div_rem_core<T: Token + ?Sized>(
+    a: BigUint,
+    b: &[BigDigit],
+    token: &T,
+) -> Result<(BigUint, BigUint), T::Error> {
+    let b0 = b[b.len() - 1];
+    let mut q = vec![0; a.data.len() - b.len() + 1];
+    count_stat!(allocations);
+    let r = div_rem_core_by(a, b, |hi, lo| div_wide(hi, lo, b0), Some(&mut q), token)?;
+    Ok((BigUint { data: q }.normalized(), r))
+}
+
+/// [`div_rem_core`] for the remainder alone, never storing the quotient.
+fn rem_core(a: BigUint, b: &[BigDigit]) -> BigUint {
+    let b0 = b[b.len() - 1];
+    cancel::unwrap(div_rem_core_by(
+        a,
+        b,
+        |hi, lo| div_wide(hi, lo, b0),
+        None,
+        &(),
+    ))
+}
+
+/// The body of [`div_rem_core`], with `div_top(hi, lo)` dividing two digits by the most
+/// significant digit of `b`. Returns the remainder, and writes the quotient digits to `q` if
+/// given, or returns the error of `token` if it is set before the division is done.
+fn div_rem_core_by<F, T>(
+    mut a: BigUint,
+    b: &[BigDigit],
+    div_top: F,
+    mut q: Option<&mut [BigDigit]>,
+    token: &T,
+) -> Result<BigUint, T::Error>
+where
+    F: Fn(BigDigit, BigDigit) -> (BigDigit, BigDigit),
+    T: Token + ?Sized,
+{
     debug_assert!(a.data.len() >= b.len() && b.len() > 1);
     debug_assert!(b.last().unwrap().leading_zeros() == 0);
+    trace_span!(
+        if a.data.len() > 32,
+        "div",
+        u_len = a.data.len(),
+        d_len = b.len(),
+        algorithm = "schoolbook",
+    );
+    count_stat!(long_divisions);
 
     // The algorithm works by incrementally calculating "guesses", q0, for the next digit of the
     // quotient. Once we have any number q0 such that (q0 << j) * b <= a, we can set
@@ -270,12 +431,13 @@ fn div_rem_core(mut a: BigUint, b: &[BigDigit]) -> (BigUint, BigUint) {
     let b1 = b[b.len() - 2];
 
     let q_len = a.data.len() - b.len() + 1;
-    let mut q = BigUint {
-        data: vec![0; q_len],
-    };
+    debug_assert!(q.as_deref().map_or(true, |q| q.len() == q_len));
 
     for j in (0..q_len).rev() {
         debug_assert!(a.data.len() == b.len() + j);
+        if j % 64 == 0 {
+            token.check()?;
+        }
 
         let a1 = *a.data.last().unwrap();
         let a2 = a.data[a.data.len() - 2];
@@ -283,7 +445,7 @@ fn div_rem_core(mut a: BigUint, b: &[BigDigit]) -> (BigUint, BigUint) {
         // The first q0 estimate is [a1,a0] / b0. It will never be too small, it may be too large
         // by at most 2.
         let (mut q0, mut r) = if a0 < b0 {
-            let (q0, r) = div_wide(a0, a1, b0);
+            let (q0, r) = div_top(a0, a1);
             (q0, r as DoubleBigDigit)
         } else {
             debug_assert!(a0 == b0);
@@ -320,7 +482,9 @@ fn div_rem_core(mut a: BigUint, b: &[BigDigit]) -> (BigUint, BigUint) {
         // The top digit of a, stored in a0, has now been zeroed.
         debug_assert!(borrow == a0);
 
-        q.data[j] = q0;
+        if let Some(q) = q.as_deref_mut() {
+            q[j] = q0;
+        }
 
         // Pop off the next top digit of a.
         a0 = a.data.pop().unwrap();
@@ -331,7 +495,214 @@ fn div_rem_core(mut a: BigUint, b: &[BigDigit]) -> (BigUint, BigUint) {
 
     debug_assert_eq!(cmp_slice(&a.data, b), Less);
 
-    (q.normalized(), a)
+    Ok(a)
+}
+
+/// Exact division, for a divisor known to divide `u` evenly.
+///
+/// Reference:
+/// Jebelean, "An algorithm for exact division", J. Symbolic Computation 15 (1993)
+///
+/// After removing common factors of two, the divisor is odd and so invertible modulo the digit
+/// base. Each quotient digit then comes from the lowest remaining digit of the dividend times
+/// that inverse, working upward, and only the digits below the quotient length are ever
+/// updated. There are no estimates to correct and no remainder to produce.
+#[allow(clippy::many_single_char_names)]
+pub(super) fn div_exact(u: &BigUint, d: &BigUint) -> BigUint {
+    assert!(!d.is_zero(), "attempt to divide by zero");
+    if u.is_zero() {
+        return BigUint::ZERO;
+    }
+
+    let shift = d.trailing_zeros().unwrap_or(0);
+    let (a, b) = (u >> shift, d >> shift);
+    let q = if b.data.len() == 1 {
+        div_rem_digit(a, b.data[0]).0
+    } else if a.data.len() < b.data.len() {
+        BigUint::ZERO
+    } else {
+        let q_len = a.data.len() - b.data.len() + 1;
+        trace_span!(
+            if q_len > 32,
+            "div",
+            u_len = a.data.len(),
+            d_len = b.data.len(),
+            algorithm = "exact",
+        );
+        count_stat!(exact_divisions);
+        let inv = super::monty::inv_mod_alt(b.data[0]).wrapping_neg();
+        let mut rem = a.data;
+        rem.truncate(q_len);
+        let mut q = vec![0; q_len];
+        count_stat!(allocations);
+        for i in 0..q_len {
+            let qi = rem[i].wrapping_mul(inv);
+            q[i] = qi;
+
+            // rem -= (qi * b) << i, modulo the base to the power of q_len.
+            let mut carry: BigDigit = 0;
+            let divisor = b.data.iter().chain(core::iter::repeat(&0));
+            for (x, &y) in rem[i..].iter_mut().zip(divisor) {
+                let prod = DoubleBigDigit::from(qi) * DoubleBigDigit::from(y)
+                    + DoubleBigDigit::from(carry);
+                let (hi, lo) = big_digit::from_doublebigdigit(prod);
+                let (diff, borrow) = x.overflowing_sub(lo);
+                *x = diff;
+                carry = hi + BigDigit::from(borrow);
+            }
+            debug_assert_eq!(rem[i], 0);
+        }
+        BigUint { data: q }.normalized()
+    };
+    debug_assert!(&q * d == *u, "the division was not exact");
+    q
+}
+
+/// Returns `floor((β² - 1) / d) - β` for a normalized digit `d`, where `β` is the digit base.
+///
+/// Reference:
+/// Möller & Granlund, "Improved division by invariant integers", IEEE Trans. Comput. 60 (2011)
+#[inline]
+fn reciprocal_digit(d: BigDigit) -> BigDigit {
+    debug_assert!(d.leading_zeros() == 0);
+    div_wide(!d, BigDigit::MAX, d).0
+}
+
+/// Divides `[hi, lo]` by the normalized digit `d` with its [`reciprocal_digit`] `v`, using
+/// multiplications in place of a division.
+#[inline]
+fn div_wide_reciprocal(
+    hi: BigDigit,
+    lo: BigDigit,
+    d: BigDigit,
+    v: BigDigit,
+) -> (BigDigit, BigDigit) {
+    debug_assert!(hi < d);
+
+    let prod = DoubleBigDigit::from(v) * DoubleBigDigit::from(hi);
+    let (q1, q0) =
+        big_digit::from_doublebigdigit(prod.wrapping_add(big_digit::to_doublebigdigit(hi, lo)));
+    let mut q = q1.wrapping_add(1);
+    let mut r = lo.wrapping_sub(q.wrapping_mul(d));
+    if r > q0 {
+        q = q.wrapping_sub(1);
+        r = r.wrapping_add(d);
+    }
+    if r >= d {
+        q += 1;
+        r -= d;
+    }
+    (q, r)
+}
+
+/// A divisor prepared for repeated division.
+///
+/// Ordinary division normalizes the divisor and divides by its leading digit with a hardware
+/// division for every quotient digit. This keeps the normalized divisor and a reciprocal of its
+/// leading digit, so each [`div_rem`](Self::div_rem) only shifts the dividend and estimates
+/// quotient digits with multiplications.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, PreparedDivisor};
+///
+/// let divisor = PreparedDivisor::new(BigUint::from(1_000_000_007u32));
+/// let n = BigUint::from(10u8).pow(30u32);
+/// assert_eq!(divisor.div_rem(&n), (&n / 1_000_000_007u32, &n % 1_000_000_007u32));
+/// ```
+#[derive(Clone, Debug)]
+pub struct PreparedDivisor {
+    divisor: BigUint,
+    normalized: BigUint,
+    shift: u32,
+    reciprocal: BigDigit,
+}
+
+impl PreparedDivisor {
+    /// Prepares `divisor` for repeated division.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `divisor` is zero.
+    #[must_use]
+    pub fn new(divisor: BigUint) -> Self {
+        assert!(!divisor.is_zero(), "attempt to divide by zero");
+        let shift = divisor.data.last().unwrap().leading_zeros();
+        let normalized = divisor.shl_unchecked(u64::from(shift));
+        let reciprocal = reciprocal_digit(*normalized.data.last().unwrap());
+        Self {
+            divisor,
+            normalized,
+            shift,
+            reciprocal,
+        }
+    }
+
+    /// Returns the divisor.
+    #[must_use]
+    #[inline]
+    pub const fn divisor(&self) -> &BigUint {
+        &self.divisor
+    }
+
+    /// Returns the quotient and remainder of `n` divided by the divisor.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn div_rem(&self, n: &BigUint) -> (BigUint, BigUint) {
+        if *n < self.divisor {
+            return (BigUint::ZERO, n.clone());
+        }
+        let a = n.shl_unchecked(u64::from(self.shift));
+        let b = &self.normalized.data;
+        let (b0, v) = (b[b.len() - 1], self.reciprocal);
+        let (q, r) = if b.len() == 1 {
+            let mut q = a;
+            let mut rem = 0;
+            for digit in q.data.iter_mut().rev() {
+                let (qi, r) = div_wide_reciprocal(rem, *digit, b0, v);
+                *digit = qi;
+                rem = r;
+            }
+            (q.normalized(), BigUint::from(rem))
+        } else {
+            let mut q = vec![0; a.data.len() - b.len() + 1];
+            let div_top = |hi, lo| div_wide_reciprocal(hi, lo, b0, v);
+            let r = cancel::unwrap(div_rem_core_by(a, b, div_top, Some(&mut q), &()));
+            (BigUint { data: q }.normalized(), r)
+        };
+        (q, r >> self.shift)
+    }
+
+    /// Returns the quotient of `n` divided by the divisor.
+    #[must_use]
+    pub fn div(&self, n: &BigUint) -> BigUint {
+        self.div_rem(n).0
+    }
+
+    /// Returns the remainder of `n` divided by the divisor, without computing the quotient.
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn rem(&self, n: &BigUint) -> BigUint {
+        if *n < self.divisor {
+            return n.clone();
+        }
+        let a = n.shl_unchecked(u64::from(self.shift));
+        let b = &self.normalized.data;
+        let (b0, v) = (b[b.len() - 1], self.reciprocal);
+        let r = if b.len() == 1 {
+            let rem = a
+                .data
+                .iter()
+                .rev()
+                .fold(0, |rem, &digit| div_wide_reciprocal(rem, digit, b0, v).1);
+            BigUint::from(rem)
+        } else {
+            let div_top = |hi, lo| div_wide_reciprocal(hi, lo, b0, v);
+            cancel::unwrap(div_rem_core_by(a, b, div_top, None, &()))
+        };
+        r >> self.shift
+    }
 }
 
 forward_val_ref_binop!(impl Div for BigUint, div);
@@ -504,8 +875,7 @@ impl Rem<Self> for BigUint {
         if let Some(other) = other.to_u32() {
             &self % other
         } else {
-            let (_, r) = div_rem(self, other);
-            r
+            rem(self, &other)
         }
     }
 }
@@ -515,13 +885,9 @@ impl Rem<&BigUint> for &BigUint {
 
     #[inline]
     fn rem(self, other: &BigUint) -> BigUint {
-        other.to_u32().map_or_else(
-            || {
-                let (_, r) = self.div_rem(other);
-                r
-            },
-            |other| self % other,
-        )
+        other
+            .to_u32()
+            .map_or_else(|| rem_ref(self, other), |other| self % other)
     }
 }
 impl RemAssign<&Self> for BigUint {
@@ -597,8 +963,7 @@ impl Rem<u64> for BigUint {
 
     #[inline]
     fn rem(self, other: u64) -> Self {
-        let (_, r) = div_rem(self, From::from(other));
-        r
+        rem(self, &From::from(other))
     }
 }
 impl RemAssign<u64> for BigUint {
@@ -623,8 +988,7 @@ impl Rem<u128> for BigUint {
 
     #[inline]
     fn rem(self, other: u128) -> Self {
-        let (_, r) = div_rem(self, From::from(other));
-        r
+        rem(self, &From::from(other))
     }
 }
 
@@ -673,6 +1037,9 @@ impl CheckedEuclid for BigUint {
     }
 
     fn checked_div_rem_euclid(&self, v: &Self) -> Option<(Self, Self)> {
+        if v.is_zero() {
+            return None;
+        }
         Some(self.div_rem_euclid(v))
     }
 }
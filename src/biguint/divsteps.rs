@@ -0,0 +1,176 @@
+//! Modular inversion by Bernstein–Yang "divsteps", with control flow and memory access that
+//! depend only on the sizes of the inputs.
+//!
+//! Reference:
+//! Bernstein & Yang, Fast constant-time gcd computation and modular inversion, TCHES 2019
+//!
+//! Each divstep halves `g` after possibly swapping it with `f` and adding `f`, and a fixed
+//! number of steps, bounded in terms of the bit length, always reaches `g = 0` with `f` at
+//! plus or minus the gcd. Alongside `f` and `g`, we track `d` and `e` with `f ≡ d·a` and
+//! `g ≡ e·a` modulo `m`, so that `±d` is the inverse at the end. Every choice is applied as a
+//! mask over all the digits, never as a branch.
+
+use super::{biguint_from_vec, BigUint};
+
+use crate::big_digit::{self, BigDigit, DoubleBigDigit};
+
+use alloc::vec::Vec;
+
+/// Returns all ones if `bit` is set, otherwise zero.
+#[inline]
+fn mask(bit: bool) -> BigDigit {
+    BigDigit::from(bit).wrapping_neg()
+}
+
+/// Swaps `a` and `b` where `mask` is all ones.
+fn cswap(mask: BigDigit, a: &mut [BigDigit], b: &mut [BigDigit]) {
+    for (a, b) in a.iter_mut().zip(b) {
+        let t = (*a ^ *b) & mask;
+        *a ^= t;
+        *b ^= t;
+    }
+}
+
+/// Adds `b & mask` to `a`, dropping any final carry.
+fn add_masked(a: &mut [BigDigit], b: &[BigDigit], mask: BigDigit) {
+    let mut carry = 0;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let sum = DoubleBigDigit::from(*a) + DoubleBigDigit::from(b & mask) + carry;
+        let (hi, lo) = big_digit::from_doublebigdigit(sum);
+        *a = lo;
+        carry = DoubleBigDigit::from(hi);
+    }
+}
+
+/// Subtracts `b & mask` from `a`, returning all ones on a borrow.
+fn sub_masked(a: &mut [BigDigit], b: &[BigDigit], mask: BigDigit) -> BigDigit {
+    let mut borrow = 0;
+    for (a, &b) in a.iter_mut().zip(b) {
+        let diff = DoubleBigDigit::from(*a)
+            .wrapping_sub(DoubleBigDigit::from(b & mask))
+            .wrapping_sub(DoubleBigDigit::from(borrow));
+        let (hi, lo) = big_digit::from_doublebigdigit(diff);
+        *a = lo;
+        borrow = hi & 1;
+    }
+    borrow.wrapping_neg()
+}
+
+/// Negates the two's complement value `a` where `mask` is all ones.
+fn cnegate(mask: BigDigit, a: &mut [BigDigit]) {
+    let mut carry = DoubleBigDigit::from(mask & 1);
+    for a in a {
+        let sum = DoubleBigDigit::from(*a ^ mask) + carry;
+        let (hi, lo) = big_digit::from_doublebigdigit(sum);
+        *a = lo;
+        carry = DoubleBigDigit::from(hi);
+    }
+}
+
+/// Shifts the two's complement value `a` right by one bit, keeping its sign.
+fn sar1(a: &mut [BigDigit]) {
+    let top = a[a.len() - 1];
+    let sign = (top >> (big_digit::BITS - 1)).wrapping_neg();
+    for i in 0..a.len() - 1 {
+        a[i] = (a[i] >> 1) | (a[i + 1] << (big_digit::BITS - 1));
+    }
+    a[a.len() - 1] = (top >> 1) | (sign << (big_digit::BITS - 1));
+}
+
+/// Subtracts `m` from `a` unless that would go negative, for `a < 2m`.
+fn reduce_once(a: &mut [BigDigit], m: &[BigDigit]) {
+    let borrow = sub_masked(a, m, BigDigit::MAX);
+    add_masked(a, m, borrow);
+}
+
+/// Replaces `a` with `-a mod m` where `mask` is all ones, for `a < m`, using `neg` as
+/// scratch space.
+fn cnegate_mod(mask: BigDigit, a: &mut [BigDigit], m: &[BigDigit], neg: &mut [BigDigit]) {
+    neg.copy_from_slice(m);
+    sub_masked(neg, a, BigDigit::MAX);
+    // `a = 0` gives `m`, which reduces to zero.
+    reduce_once(neg, m);
+    cswap(mask, a, neg);
+}
+
+/// Replaces `a` with `a / 2 mod m`, for odd `m` and `a < m`.
+fn half_mod(a: &mut [BigDigit], m: &[BigDigit]) {
+    let odd = mask(a[0] & 1 != 0);
+    // The sum is below `2m`, which fits since there is a spare top digit.
+    add_masked(a, m, odd);
+    sar1(a);
+}
+
+/// The number of divsteps that always reaches `g = 0` from inputs of up to `bits` bits.
+const fn iterations(bits: u64) -> u64 {
+    if bits < 46 {
+        (49 * bits + 80) / 17
+    } else {
+        (49 * bits + 57) / 17
+    }
+}
+
+/// Returns the inverse of `a` modulo the odd `m`, if it exists.
+#[allow(clippy::many_single_char_names)]
+pub(super) fn modinv(a: &BigUint, m: &BigUint) -> Option<BigUint> {
+    debug_assert!(m.data.first().map_or(false, |&d| d & 1 == 1));
+    if m.data.len() == 1 && m.data[0] == 1 {
+        return Some(BigUint::ZERO);
+    }
+
+    // Only the length of `a` decides whether it needs reducing first.
+    let reduced;
+    let a = if a.data.len() > m.data.len() {
+        reduced = a % m;
+        &reduced
+    } else {
+        a
+    };
+
+    // One spare digit holds the signs of `f` and `g`, and the carries of `d` and `e`.
+    let len = m.data.len() + 1;
+    let padded = |x: &BigUint| {
+        let mut v = Vec::with_capacity(len);
+        v.extend_from_slice(&x.data);
+        v.resize(len, 0);
+        v
+    };
+    let modulus = padded(m);
+    let mut f = modulus.clone();
+    let mut g = padded(a);
+    let mut d = vec![0; len];
+    let mut e = vec![0; len];
+    e[0] = 1;
+    let mut scratch = vec![0; len];
+    let mut delta: i64 = 1;
+
+    let bits = m.data.len() as u64 * u64::from(big_digit::BITS);
+    for _ in 0..iterations(bits) {
+        // With `delta > 0` and `g` odd: `(f, g) = (g, -f)` and `(d, e) = (e, -d)`.
+        let swap = mask(delta > 0) & mask(g[0] & 1 != 0);
+        cswap(swap, &mut f, &mut g);
+        cnegate(swap, &mut g);
+        cswap(swap, &mut d, &mut e);
+        cnegate_mod(swap, &mut e, &modulus, &mut scratch);
+        let neg = -i64::from(swap & 1 != 0);
+        delta = (delta ^ neg) - neg + 1;
+
+        // Then `g = (g + f) / 2` if `g` is odd, else `g / 2`, and likewise for `e`.
+        let odd = mask(g[0] & 1 != 0);
+        add_masked(&mut g, &f, odd);
+        add_masked(&mut e, &d, odd);
+        reduce_once(&mut e, &modulus);
+        sar1(&mut g);
+        half_mod(&mut e, &modulus);
+    }
+    debug_assert!(g.iter().all(|&x| x == 0));
+
+    // `f` is plus or minus the gcd, and `f ≡ d·a`.
+    let negative = mask(f[len - 1] >> (big_digit::BITS - 1) != 0);
+    cnegate(negative, &mut f);
+    if f[0] != 1 || f[1..].iter().any(|&x| x != 0) {
+        return None;
+    }
+    cnegate_mod(negative, &mut d, &modulus, &mut scratch);
+    Some(biguint_from_vec(d))
+}
@@ -0,0 +1,49 @@
+#![cfg(feature = "ethnum")]
+#![cfg_attr(docsrs, doc(cfg(feature = "ethnum")))]
+
+use super::{biguint_from_u64_digits, BigUint};
+
+use crate::TryFromBigIntError;
+
+use core::convert::TryFrom;
+use ethnum::U256;
+
+impl TryFrom<&BigUint> for U256 {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(value: &BigUint) -> Result<Self, TryFromBigIntError<()>> {
+        if value.bits() > 256 {
+            return Err(TryFromBigIntError::new(()));
+        }
+        let mut words = [0u64; 4];
+        for (word, digit) in words.iter_mut().zip(value.iter_u64_digits()) {
+            *word = digit;
+        }
+        let hi = u128::from(words[2]) | (u128::from(words[3]) << 64);
+        let lo = u128::from(words[0]) | (u128::from(words[1]) << 64);
+        Ok(U256::from_words(hi, lo))
+    }
+}
+
+impl TryFrom<BigUint> for U256 {
+    type Error = TryFromBigIntError<BigUint>;
+
+    #[inline]
+    fn try_from(value: BigUint) -> Result<Self, TryFromBigIntError<BigUint>> {
+        U256::try_from(&value).map_err(|e| e.with_original(value))
+    }
+}
+
+impl From<&U256> for BigUint {
+    #[inline]
+    fn from(value: &U256) -> Self {
+        Self::from(*value)
+    }
+}
+
+impl From<U256> for BigUint {
+    fn from(value: U256) -> Self {
+        let (hi, lo) = value.into_words();
+        biguint_from_u64_digits([lo as u64, (lo >> 64) as u64, hi as u64, (hi >> 64) as u64])
+    }
+}
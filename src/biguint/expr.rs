@@ -0,0 +1,138 @@
+use super::addition::add2;
+use super::multiplication::{mac3, mul3_into};
+use super::BigUint;
+
+use alloc::vec::Vec;
+use core::ops::{Add, Mul};
+use num_traits::Zero;
+
+/// A deferred sum of products of borrowed [`BigUint`] values, started with [`expr`].
+///
+/// Chaining operators on references allocates a new `BigUint` for every intermediate result:
+/// `&a * &b + &c * &d` builds both products and then their sum. An `Expr` only records the
+/// terms, and [`eval_into`](Self::eval_into) then sizes a single output buffer for the whole
+/// sum and accumulates each product straight into it. Products of more than two factors still
+/// need scratch space for their partial products, which is shared by all the terms.
+///
+/// Multiplying by a sum distributes over its terms, so any polynomial in the operands can be
+/// written, though the products are then evaluated separately.
+///
+/// ```
+/// use num_bigint::{expr, BigUint};
+///
+/// let a = BigUint::from(10u8).pow(30u32);
+/// let b = BigUint::from(3u8);
+/// let c = BigUint::from(7u8);
+/// let d = BigUint::from(11u8);
+///
+/// let mut out = BigUint::default();
+/// (expr(&a) * &b + expr(&c) * &d).eval_into(&mut out);
+/// assert_eq!(out, &a * &b + &c * &d);
+///
+/// let e = (expr(&a) + &b) * (expr(&c) + &d);
+/// assert_eq!(e.eval(), (&a + &b) * (&c + &d));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Expr<'a> {
+    /// The products to sum, none of them empty.
+    terms: Vec<Vec<&'a BigUint>>,
+}
+
+/// Starts an [`Expr`] with the single operand `x`.
+#[must_use]
+pub fn expr(x: &BigUint) -> Expr<'_> {
+    Expr {
+        terms: vec![vec![x]],
+    }
+}
+
+impl Expr<'_> {
+    /// Evaluates the expression into `out`, reusing its allocation.
+    pub fn eval_into(&self, out: &mut BigUint) {
+        let live = || {
+            self.terms
+                .iter()
+                .filter(|term| term.iter().all(|x| !x.is_zero()))
+        };
+        // A product fits in the sum of its factors' lengths, and the extra digits leave room
+        // for the carries of fewer than `β` terms, as in `dot`.
+        let longest = live()
+            .map(|term| term.iter().map(|x| x.data.len()).sum::<usize>())
+            .max();
+        out.data.clear();
+        match longest {
+            Some(longest) => out.data.resize(longest + 2, 0),
+            None => return,
+        }
+
+        let mut partial = BigUint::ZERO;
+        let mut next = BigUint::ZERO;
+        for term in live() {
+            match term[..] {
+                [x] => add2(&mut out.data, &x.data),
+                [x, y] => mac3(&mut out.data, &x.data, &y.data),
+                [x, y, ref middle @ .., last] => {
+                    mul3_into(&x.data, &y.data, &mut partial);
+                    for z in middle {
+                        mul3_into(&partial.data, &z.data, &mut next);
+                        core::mem::swap(&mut partial, &mut next);
+                    }
+                    mac3(&mut out.data, &partial.data, &last.data);
+                }
+                [] => unreachable!(),
+            }
+        }
+        out.normalize();
+    }
+
+    /// Evaluates the expression into a new [`BigUint`].
+    #[must_use]
+    pub fn eval(&self) -> BigUint {
+        let mut out = BigUint::ZERO;
+        self.eval_into(&mut out);
+        out
+    }
+}
+
+impl<'a> Add<&'a BigUint> for Expr<'a> {
+    type Output = Self;
+
+    fn add(mut self, other: &'a BigUint) -> Self {
+        self.terms.push(vec![other]);
+        self
+    }
+}
+
+impl Add for Expr<'_> {
+    type Output = Self;
+
+    fn add(mut self, other: Self) -> Self {
+        self.terms.extend(other.terms);
+        self
+    }
+}
+
+impl<'a> Mul<&'a BigUint> for Expr<'a> {
+    type Output = Self;
+
+    fn mul(mut self, other: &'a BigUint) -> Self {
+        for term in &mut self.terms {
+            term.push(other);
+        }
+        self
+    }
+}
+
+impl Mul for Expr<'_> {
+    type Output = Self;
+
+    fn mul(self, other: Self) -> Self {
+        let mut terms = Vec::with_capacity(self.terms.len() * other.terms.len());
+        for x in &self.terms {
+            for y in &other.terms {
+                terms.push(x.iter().chain(y).copied().collect());
+            }
+        }
+        Self { terms }
+    }
+}
@@ -0,0 +1,334 @@
+use super::prime::small_primes;
+use super::BigUint;
+
+use crate::binary_split;
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+use core::cmp::{Ordering, Reverse};
+use core::iter::FusedIterator;
+use core::ops::Mul;
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+/// The prime factorization of a positive integer, as prime–exponent pairs sorted by prime.
+///
+/// This is returned by [`BigUint::factorize`], and can compute several arithmetic functions
+/// directly from the factors without refactoring the value.
+///
+/// ```
+/// use num_bigint::BigUint;
+///
+/// let f = BigUint::from(360u16).factorize();
+/// let primes: Vec<(u32, u32)> = f
+///     .factors()
+///     .iter()
+///     .map(|(p, e)| (u32::try_from(p).unwrap(), *e))
+///     .collect();
+/// assert_eq!(primes, [(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(f.value(), BigUint::from(360u16));
+/// assert_eq!(f.totient(), BigUint::from(96u8));
+/// assert_eq!(f.divisor_count(), BigUint::from(24u8));
+/// assert_eq!(f.divisor_sum(), BigUint::from(1170u16));
+/// assert_eq!(f.radical(), BigUint::from(30u8));
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Factorization {
+    factors: Vec<(BigUint, u32)>,
+}
+
+impl Factorization {
+    /// Builds a factorization from prime–exponent pairs in any order, merging repeated primes
+    /// and dropping zero exponents.
+    ///
+    /// The primes are not checked, so the derived quantities are only meaningful if they are
+    /// all actually prime.
+    #[must_use]
+    pub fn from_prime_powers<I>(factors: I) -> Self
+    where
+        I: IntoIterator<Item = (BigUint, u32)>,
+    {
+        let mut factors: Vec<_> = factors.into_iter().filter(|(_, e)| *e > 0).collect();
+        factors.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut merged: Vec<(BigUint, u32)> = Vec::with_capacity(factors.len());
+        for (p, e) in factors {
+            match merged.last_mut() {
+                Some((last, exp)) if *last == p => *exp += e,
+                _ => merged.push((p, e)),
+            }
+        }
+        Self { factors: merged }
+    }
+
+    /// Returns the prime–exponent pairs, sorted by prime.
+    #[must_use]
+    #[inline]
+    pub fn factors(&self) -> &[(BigUint, u32)] {
+        &self.factors
+    }
+
+    /// Multiplies `f` over the prime–exponent pairs as a balanced product tree.
+    fn product<F>(&self, mut f: F) -> BigUint
+    where
+        F: FnMut(&(BigUint, u32)) -> BigUint,
+    {
+        if self.factors.is_empty() {
+            return BigUint::one();
+        }
+        binary_split(0..self.factors.len(), |i| f(&self.factors[i]), |a, b| a * b)
+    }
+
+    /// Returns the factored value, the product of all prime powers.
+    #[must_use]
+    pub fn value(&self) -> BigUint {
+        self.product(|(p, e)| p.pow(*e))
+    }
+
+    /// Returns Euler's totient, the count of values in `1..=n` coprime to `n`.
+    #[must_use]
+    pub fn totient(&self) -> BigUint {
+        self.product(|(p, e)| p.pow(e - 1) * (p - 1u32))
+    }
+
+    /// Returns the number of divisors, including 1 and the value itself.
+    #[must_use]
+    pub fn divisor_count(&self) -> BigUint {
+        self.product(|(_, e)| BigUint::from(*e) + 1u32)
+    }
+
+    /// Returns the sum of all divisors, including 1 and the value itself.
+    #[must_use]
+    pub fn divisor_sum(&self) -> BigUint {
+        self.product(|(p, e)| (p.pow(e + 1) - 1u32) / (p - 1u32))
+    }
+
+    /// Returns the radical, the product of the distinct primes.
+    #[must_use]
+    pub fn radical(&self) -> BigUint {
+        self.product(|(p, _)| p.clone())
+    }
+
+    /// Returns an iterator over all divisors, including 1 and the value itself, in no
+    /// particular order.
+    ///
+    /// Each divisor costs one multiplication or division from the previous one.
+    #[must_use]
+    pub fn divisors(&self) -> Divisors<'_> {
+        Divisors {
+            factors: &self.factors,
+            exponents: vec![0; self.factors.len()],
+            current: Some(BigUint::one()),
+        }
+    }
+
+    /// Returns an iterator over all divisors in increasing order.
+    ///
+    /// The divisors are produced lazily from a heap, so taking only the smallest few is cheap.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let f = BigUint::from(12u8).factorize();
+    /// let divisors: Vec<BigUint> = f.divisors_sorted().collect();
+    /// assert_eq!(divisors, [1u8, 2, 3, 4, 6, 12].map(BigUint::from));
+    /// ```
+    #[must_use]
+    pub fn divisors_sorted(&self) -> SortedDivisors<'_> {
+        let mut heap = BinaryHeap::new();
+        heap.push(Reverse((BigUint::one(), 0, 0)));
+        SortedDivisors {
+            factors: &self.factors,
+            heap,
+        }
+    }
+
+    /// Returns the factorization of the greatest common divisor of both values.
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        let mut factors = Vec::new();
+        let (mut a, mut b) = (
+            self.factors.iter().peekable(),
+            other.factors.iter().peekable(),
+        );
+        while let (Some((p, e)), Some((q, f))) = (a.peek(), b.peek()) {
+            match p.cmp(q) {
+                Ordering::Less => {
+                    a.next();
+                }
+                Ordering::Greater => {
+                    b.next();
+                }
+                Ordering::Equal => {
+                    factors.push((p.clone(), *e.min(f)));
+                    a.next();
+                    b.next();
+                }
+            }
+        }
+        Self { factors }
+    }
+}
+
+impl Mul<&Factorization> for &Factorization {
+    type Output = Factorization;
+
+    fn mul(self, other: &Factorization) -> Factorization {
+        Factorization::from_prime_powers(self.factors.iter().chain(&other.factors).cloned())
+    }
+}
+
+impl Mul for Factorization {
+    type Output = Self;
+
+    #[inline]
+    fn mul(self, other: Self) -> Self {
+        Self::from_prime_powers(self.factors.into_iter().chain(other.factors))
+    }
+}
+
+/// An iterator over the divisors of a [`Factorization`], created by
+/// [`Factorization::divisors`].
+#[derive(Clone, Debug)]
+pub struct Divisors<'a> {
+    factors: &'a [(BigUint, u32)],
+    exponents: Vec<u32>,
+    current: Option<BigUint>,
+}
+
+impl Iterator for Divisors<'_> {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        let divisor = self.current.take()?;
+        // Count up through the exponents like an odometer.
+        let mut next = divisor.clone();
+        for ((p, e), exponent) in self.factors.iter().zip(&mut self.exponents) {
+            if *exponent < *e {
+                *exponent += 1;
+                next *= p;
+                self.current = Some(next);
+                break;
+            }
+            next /= p.pow(*exponent);
+            *exponent = 0;
+        }
+        Some(divisor)
+    }
+}
+
+impl FusedIterator for Divisors<'_> {}
+
+/// An iterator over the divisors of a [`Factorization`] in increasing order, created by
+/// [`Factorization::divisors_sorted`].
+#[derive(Clone, Debug)]
+pub struct SortedDivisors<'a> {
+    factors: &'a [(BigUint, u32)],
+    /// Pending divisors, each with the index of its largest prime and that prime's exponent.
+    /// Only that prime or larger ones are multiplied in, so each divisor is reached once.
+    heap: BinaryHeap<Reverse<(BigUint, usize, u32)>>,
+}
+
+impl Iterator for SortedDivisors<'_> {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        let Reverse((divisor, index, exponent)) = self.heap.pop()?;
+        if let Some((p, e)) = self.factors.get(index) {
+            if exponent < *e {
+                self.heap.push(Reverse((&divisor * p, index, exponent + 1)));
+            }
+        }
+        for (i, (p, _)) in self.factors.iter().enumerate().skip(index + 1) {
+            self.heap.push(Reverse((&divisor * p, i, 1)));
+        }
+        Some(divisor)
+    }
+}
+
+impl FusedIterator for SortedDivisors<'_> {}
+
+impl BigUint {
+    /// Returns the prime factorization of `self`, which is empty for one.
+    ///
+    /// Small factors are found by trial division and the rest by Pollard's rho method, with
+    /// each factor confirmed by [`is_prime_deterministic`](Self::is_prime_deterministic). This
+    /// is practical while the second-largest prime factor has up to about a dozen digits.
+    ///
+    /// Panics if `self` is zero.
+    #[must_use]
+    pub fn factorize(&self) -> Factorization {
+        assert!(!self.is_zero(), "attempt to factorize zero");
+        let mut factors = Vec::new();
+        let mut n = self.clone();
+        for p in small_primes(1000) {
+            let mut e = 0;
+            while (&n % p).is_zero() {
+                n /= p;
+                e += 1;
+            }
+            if e > 0 {
+                factors.push((Self::from(p), e));
+            }
+        }
+
+        let mut pending = vec![n];
+        while let Some(n) = pending.pop() {
+            if n.is_one() {
+                continue;
+            }
+            if n.is_prime_deterministic() {
+                factors.push((n, 1));
+            } else {
+                let d = pollard_rho(&n);
+                pending.push(&n / &d);
+                pending.push(d);
+            }
+        }
+        Factorization::from_prime_powers(factors)
+    }
+}
+
+/// Returns a non-trivial factor of the odd composite `n`, by Pollard's rho method with
+/// Floyd's cycle finding, batching the gcds.
+#[allow(clippy::many_single_char_names)]
+fn pollard_rho(n: &BigUint) -> BigUint {
+    const BATCH: usize = 64;
+    let diff = |x: &BigUint, y: &BigUint| if x > y { x - y } else { y - x };
+    for c in 1u32.. {
+        let f = |x: &BigUint| (x * x + c) % n;
+        let mut x = BigUint::from(2u8);
+        let mut y = x.clone();
+        loop {
+            let (saved_x, saved_y) = (x.clone(), y.clone());
+            let mut product = BigUint::one();
+            for _ in 0..BATCH {
+                x = f(&x);
+                y = f(&f(&y));
+                product = product * diff(&x, &y) % n;
+            }
+            let mut d = product.gcd(n);
+            if d.is_one() {
+                continue;
+            }
+            if d == *n {
+                // The batch overshot; step through it again one gcd at a time.
+                x = saved_x;
+                y = saved_y;
+                loop {
+                    x = f(&x);
+                    y = f(&f(&y));
+                    d = diff(&x, &y).gcd(n);
+                    if !d.is_one() {
+                        break;
+                    }
+                }
+            }
+            if d != *n {
+                return d;
+            }
+            // The sequence cycled modulo every factor at once, so try another constant.
+            break;
+        }
+    }
+    unreachable!()
+}
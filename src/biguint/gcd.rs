@@ -0,0 +1,73 @@
+use super::BigUint;
+
+use crate::BigInt;
+
+use core::mem;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+
+/// Returns `(g, x)` with `g = gcd(a, b)` and `g = x * a + y * b` for some `y`, where `x` is the
+/// same minimal cofactor that the plain extended Euclidean algorithm would produce.
+///
+/// This is Lehmer's algorithm (Knuth, TAOCP vol. 2, §4.5.2, Algorithm L): while the operands
+/// are large, the quotient sequence is simulated on their leading 64 bits, and the resulting
+/// 2×2 matrix is applied to the full values at once, replacing many multi-precision divisions
+/// with a few multiplications by single words.
+pub(crate) fn gcd_cofactor(a: &BigUint, b: &BigUint) -> (BigUint, BigInt) {
+    // Invariant: a ≡ s0 * a_orig and b ≡ s1 * a_orig, modulo b_orig.
+    let (mut a, mut b) = (a.clone(), b.clone());
+    let (mut s0, mut s1) = (BigInt::one(), BigInt::zero());
+    if a < b {
+        mem::swap(&mut a, &mut b);
+        mem::swap(&mut s0, &mut s1);
+    }
+
+    while b.bits() > 64 {
+        let shift = a.bits() - 64;
+        let mut ah = i128::from((&a >> shift).to_u64().unwrap());
+        let mut bh = i128::from((&b >> shift).to_u64().unwrap());
+        let (mut ca, mut cb, mut cc, mut cd) = (1i128, 0i128, 0i128, 1i128);
+        while bh + cc > 0 && bh + cd > 0 {
+            let q = (ah + ca) / (bh + cc);
+            if q != (ah + cb) / (bh + cd) {
+                break;
+            }
+            (ca, cc) = (cc, ca - q * cc);
+            (cb, cd) = (cd, cb - q * cd);
+            (ah, bh) = (bh, ah - q * bh);
+        }
+
+        if cb == 0 {
+            // No quotient could be determined from the leading bits, so take one full step.
+            let (q, r) = a.div_rem(&b);
+            a = mem::replace(&mut b, r);
+            let s2 = &s0 - BigInt::from(q) * &s1;
+            s0 = mem::replace(&mut s1, s2);
+        } else {
+            let (a2, b2) = (combine(ca, &a, cb, &b), combine(cc, &a, cd, &b));
+            a = a2;
+            b = b2;
+            let (t0, t1) = (&s0 * ca + &s1 * cb, &s0 * cc + &s1 * cd);
+            s0 = t0;
+            s1 = t1;
+        }
+    }
+
+    while !b.is_zero() {
+        let (q, r) = a.div_rem(&b);
+        a = mem::replace(&mut b, r);
+        let s2 = &s0 - BigInt::from(q) * &s1;
+        s0 = mem::replace(&mut s1, s2);
+    }
+    (a, s0)
+}
+
+/// Returns `x * a + y * b`, where the cofactors have opposite signs (or one is zero) and the
+/// result is known to be non-negative.
+fn combine(x: i128, a: &BigUint, y: i128, b: &BigUint) -> BigUint {
+    if x >= 0 {
+        a * x.unsigned_abs() - b * y.unsigned_abs()
+    } else {
+        b * y.unsigned_abs() - a * x.unsigned_abs()
+    }
+}
@@ -0,0 +1,57 @@
+#![cfg(feature = "getrandom")]
+#![cfg_attr(docsrs, doc(cfg(feature = "getrandom")))]
+
+use super::BigUint;
+
+use alloc::vec;
+use num_integer::Integer;
+use num_traits::{ToPrimitive, Zero};
+
+impl BigUint {
+    /// Generates a random `BigUint` of at most `bit_size` bits, reading entropy directly from
+    /// the operating system with [`getrandom`].
+    ///
+    /// Unlike [`RandBigInt`](crate::RandBigInt), this doesn't need the `rand` crate or a
+    /// generator instance, and any failure of the OS source is returned rather than panicking.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let n = BigUint::random_secure(256).unwrap();
+    /// assert!(n.bits() <= 256);
+    /// ```
+    pub fn random_secure(bit_size: u64) -> Result<Self, getrandom::Error> {
+        let len = Integer::div_ceil(&bit_size, &8)
+            .to_usize()
+            .expect("capacity overflow");
+        let mut bytes = vec![0u8; len];
+        getrandom::fill(&mut bytes)?;
+        let rem = bit_size % 8;
+        if rem > 0 {
+            bytes[len - 1] >>= 8 - rem;
+        }
+        Ok(Self::from_bytes_le(&bytes))
+    }
+
+    /// Generates a uniformly random `BigUint` less than `bound`, reading entropy directly from
+    /// the operating system with [`getrandom`].
+    ///
+    /// Values of the bound's bit size are drawn until one falls below it, so the result has no
+    /// modular bias, and on average fewer than two draws are needed.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `bound` is zero.
+    pub fn random_secure_below(bound: &Self) -> Result<Self, getrandom::Error> {
+        assert!(!bound.is_zero(), "bound must be non-zero");
+        let bits = bound.bits();
+        loop {
+            let n = Self::random_secure(bits)?;
+            if n < *bound {
+                return Ok(n);
+            }
+        }
+    }
+}
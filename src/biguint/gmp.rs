@@ -0,0 +1,93 @@
+#![cfg(feature = "gmp")]
+#![cfg_attr(docsrs, doc(cfg(feature = "gmp")))]
+
+use super::{biguint_from_u64_digits, BigUint};
+
+use alloc::vec::Vec;
+use core::slice;
+use gmp_mpfr_sys::gmp;
+
+// GMP limbs may be 32 or 64 bits, independent of our own `BigDigit` size, so every conversion
+// goes through the matching `u32` or `u64` digit view. Nail bits are never enabled in practice.
+const LIMB_64: bool = gmp::LIMB_BITS == 64;
+const _: () = assert!(gmp::NAIL_BITS == 0);
+
+impl BigUint {
+    /// Creates a [`BigUint`] by copying the limbs of a GMP integer, or returns `None` if the
+    /// integer is negative.
+    ///
+    /// This works with any `mpz_t`, including the raw value behind a `rug::Integer`.
+    ///
+    /// # Safety
+    ///
+    /// `z` must have been initialized by GMP, e.g. with `mpz_init`.
+    #[must_use]
+    pub unsafe fn from_mpz(z: &gmp::mpz_t) -> Option<Self> {
+        // SAFETY: the caller guarantees that `z` is initialized.
+        unsafe {
+            if gmp::mpz_sgn(z) < 0 {
+                return None;
+            }
+            Some(from_limbs(mpz_limbs(z)))
+        }
+    }
+
+    /// Assigns the value of this [`BigUint`] to a GMP integer, reusing its allocation when
+    /// it is large enough.
+    ///
+    /// # Safety
+    ///
+    /// `z` must have been initialized by GMP, e.g. with `mpz_init`.
+    pub unsafe fn assign_to_mpz(&self, z: &mut gmp::mpz_t) {
+        // SAFETY: the caller guarantees that `z` is initialized.
+        unsafe { write_mpz(self, false, z) }
+    }
+}
+
+/// Borrows the magnitude limbs of an initialized `mpz_t`.
+pub(crate) unsafe fn mpz_limbs(z: &gmp::mpz_t) -> &[gmp::limb_t] {
+    let len = gmp::mpz_size(z);
+    if len == 0 {
+        &[]
+    } else {
+        slice::from_raw_parts(gmp::mpz_limbs_read(z), len)
+    }
+}
+
+pub(crate) fn from_limbs(limbs: &[gmp::limb_t]) -> BigUint {
+    if LIMB_64 {
+        #[allow(clippy::useless_conversion)]
+        biguint_from_u64_digits(limbs.iter().map(|&limb| u64::from(limb)))
+    } else {
+        #[allow(clippy::unnecessary_cast)]
+        let digits: Vec<u32> = limbs.iter().map(|&limb| limb as u32).collect();
+        BigUint::new(digits)
+    }
+}
+
+/// Writes the magnitude `x` with the given sign into an initialized `mpz_t`.
+pub(crate) unsafe fn write_mpz(x: &BigUint, negative: bool, z: &mut gmp::mpz_t) {
+    let len = if LIMB_64 {
+        x.iter_u64_digits().len()
+    } else {
+        x.iter_u32_digits().len()
+    };
+    if len == 0 {
+        gmp::mpz_set_ui(z, 0);
+        return;
+    }
+
+    let size = len as gmp::size_t;
+    let limbs = slice::from_raw_parts_mut(gmp::mpz_limbs_write(z, size), len);
+    #[allow(clippy::unnecessary_cast)]
+    if LIMB_64 {
+        for (limb, digit) in limbs.iter_mut().zip(x.iter_u64_digits()) {
+            *limb = digit as gmp::limb_t;
+        }
+    } else {
+        for (limb, digit) in limbs.iter_mut().zip(x.iter_u32_digits()) {
+            *limb = digit as gmp::limb_t;
+        }
+    }
+    gmp::mpz_limbs_finish(z, if negative { -size } else { size });
+}
@@ -0,0 +1,139 @@
+//! Arithmetic on raw limb slices, in the style of GMP's `mpn` layer.
+//!
+//! These are the carry-propagating loops that [`BigUint`](crate::BigUint) is built on, exposed
+//! for code that manages its own buffers, such as fixed-size kernels or custom reductions. A
+//! number is a slice of [`Limb`]s, least significant first, of whatever length the caller
+//! chooses; nothing here allocates, trims zeros or resizes, and carries and borrows are
+//! returned rather than stored.
+//!
+//! As in GMP, the output comes first. Unlike GMP, an output cannot alias an input, since the
+//! borrow rules forbid it, so in-place updates need a copy of the operand first.
+//!
+//! ```
+//! use num_bigint::lowlevel::{self, Limb};
+//!
+//! let a: [Limb; 2] = [Limb::MAX, 1];
+//! let b: [Limb; 2] = [1, 2];
+//! let mut sum = [0; 2];
+//! assert_eq!(lowlevel::add_n(&mut sum, &a, &b), 0);
+//! assert_eq!(sum, [0, 4]);
+//!
+//! let mut prod = [0; 4];
+//! lowlevel::mul_basecase(&mut prod, &a, &b);
+//! assert_eq!(prod, [Limb::MAX, Limb::MAX, 3, 0]);
+//!
+//! let mut back = [0; 2];
+//! assert_eq!(lowlevel::sub_n(&mut back, &sum, &b), 0);
+//! assert_eq!(back, a);
+//! ```
+
+use super::addition::adc;
+use super::multiplication::mac_digit;
+use super::subtraction::sbb;
+
+use crate::big_digit::{self, BigDigit};
+
+use core::cmp::Ordering;
+
+/// A single digit of a number, 64 bits wide on 64-bit targets and 32 bits elsewhere.
+pub type Limb = BigDigit;
+
+/// The number of bits in a [`Limb`].
+pub const LIMB_BITS: u32 = big_digit::BITS as u32;
+
+/// Writes `a + b` to `r`, all of the same length, and returns the carry out, 0 or 1.
+///
+/// Panics if the lengths differ.
+pub fn add_n(r: &mut [Limb], a: &[Limb], b: &[Limb]) -> Limb {
+    assert!(
+        r.len() == a.len() && a.len() == b.len(),
+        "add_n operands must have the same length"
+    );
+    let mut carry = 0;
+    for ((r, &a), &b) in r.iter_mut().zip(a).zip(b) {
+        carry = adc(carry, a, b, r);
+    }
+    Limb::from(carry)
+}
+
+/// Writes `a - b` to `r`, all of the same length, and returns the borrow out, 0 or 1.
+///
+/// Panics if the lengths differ.
+pub fn sub_n(r: &mut [Limb], a: &[Limb], b: &[Limb]) -> Limb {
+    assert!(
+        r.len() == a.len() && a.len() == b.len(),
+        "sub_n operands must have the same length"
+    );
+    let mut borrow = 0;
+    for ((r, &a), &b) in r.iter_mut().zip(a).zip(b) {
+        borrow = sbb(borrow, a, b, r);
+    }
+    Limb::from(borrow)
+}
+
+/// Writes the full product `a * b` to `r`, which must be exactly `a.len() + b.len()` limbs,
+/// using schoolbook multiplication.
+///
+/// This takes time proportional to `a.len() * b.len()`, so it is meant for the short operands
+/// of a kernel; [`BigUint`](crate::BigUint) switches to subquadratic algorithms above a few
+/// dozen limbs.
+///
+/// Panics if `r` has the wrong length.
+pub fn mul_basecase(r: &mut [Limb], a: &[Limb], b: &[Limb]) {
+    assert_eq!(
+        r.len(),
+        a.len() + b.len(),
+        "mul_basecase output must have the length of both operands"
+    );
+    r.fill(0);
+    for (i, &d) in b.iter().enumerate() {
+        mac_digit(&mut r[i..], a, d);
+    }
+}
+
+/// Writes `a << shift` to `r`, of the same length, and returns the bits shifted out of the top
+/// in the low bits of the result.
+///
+/// Panics if the lengths differ or `shift` is not below [`LIMB_BITS`].
+pub fn shl(r: &mut [Limb], a: &[Limb], shift: u32) -> Limb {
+    assert_eq!(r.len(), a.len(), "shl operands must have the same length");
+    assert!(shift < LIMB_BITS, "shl shift must be less than LIMB_BITS");
+    if shift == 0 {
+        r.copy_from_slice(a);
+        return 0;
+    }
+    let mut carry = 0;
+    for (r, &a) in r.iter_mut().zip(a) {
+        *r = (a << shift) | carry;
+        carry = a >> (LIMB_BITS - shift);
+    }
+    carry
+}
+
+/// Writes `a >> shift` to `r`, of the same length, and returns the bits shifted out of the
+/// bottom in the high bits of the result.
+///
+/// Panics if the lengths differ or `shift` is not below [`LIMB_BITS`].
+pub fn shr(r: &mut [Limb], a: &[Limb], shift: u32) -> Limb {
+    assert_eq!(r.len(), a.len(), "shr operands must have the same length");
+    assert!(shift < LIMB_BITS, "shr shift must be less than LIMB_BITS");
+    if shift == 0 {
+        r.copy_from_slice(a);
+        return 0;
+    }
+    let mut borrow = 0;
+    for (r, &a) in r.iter_mut().zip(a).rev() {
+        *r = (a >> shift) | borrow;
+        borrow = a << (LIMB_BITS - shift);
+    }
+    borrow
+}
+
+/// Compares `a` and `b`, which must have the same length, as numbers.
+///
+/// Panics if the lengths differ.
+#[must_use]
+pub fn cmp(a: &[Limb], b: &[Limb]) -> Ordering {
+    assert_eq!(a.len(), b.len(), "cmp operands must have the same length");
+    Iterator::cmp(a.iter().rev(), b.iter().rev())
+}
@@ -1,9 +1,9 @@
 use alloc::vec::Vec;
 use core::mem;
-use core::ops::Shl;
 use num_traits::One;
 
 use crate::big_digit::{self, BigDigit, DoubleBigDigit};
+use crate::biguint::cancel::Token;
 use crate::biguint::BigUint;
 
 struct MontyReducer {
@@ -12,7 +12,7 @@ struct MontyReducer {
 
 // k0 = -m**-1 mod 2**BITS. Algorithm from: Dumas, J.G. "On Newton–Raphson
 // Iteration for Multiplicative Inverses Modulo Prime Powers".
-fn inv_mod_alt(b: BigDigit) -> BigDigit {
+pub(super) fn inv_mod_alt(b: BigDigit) -> BigDigit {
     assert_ne!(b & 1, 0);
 
     let mut k0 = BigDigit::wrapping_sub(2, b);
@@ -35,6 +35,52 @@ impl MontyReducer {
     }
 }
 
+/// Montgomery multiplication for a fixed odd modulus, on normalized operands below it.
+#[derive(Clone, Debug)]
+pub(crate) struct MontyContext {
+    n0inv: BigDigit,
+    /// 2**(2*_W*len(m)) mod m, for converting into Montgomery form.
+    rr: BigUint,
+}
+
+impl MontyContext {
+    pub(crate) fn new(m: &BigUint) -> Self {
+        let n0inv = inv_mod_alt(m.data[0]);
+        let rr = BigUint::one()
+            .into_shl_unchecked(2 * m.data.len() as u64 * u64::from(big_digit::BITS))
+            % m;
+        Self { n0inv, rr }
+    }
+
+    /// Computes x * y * 2 ** (-n*_W) mod m, fully reduced.
+    #[allow(clippy::many_single_char_names)]
+    pub(crate) fn mul(&self, x: &BigUint, y: &BigUint, m: &BigUint) -> BigUint {
+        let n = m.data.len();
+        let pad = |v: &BigUint| {
+            let mut v = v.clone();
+            v.data.resize(n, 0);
+            v
+        };
+        let mut z = montgomery(&pad(x), &pad(y), m, self.n0inv, n);
+        z.normalize();
+        // With both inputs below m, the almost-Montgomery result is below 2m.
+        if z >= *m {
+            z -= m;
+        }
+        z
+    }
+
+    /// Converts x into Montgomery form, x * 2 ** (n*_W) mod m.
+    pub(crate) fn encode(&self, x: &BigUint, m: &BigUint) -> BigUint {
+        self.mul(&(x % m), &self.rr, m)
+    }
+
+    /// Converts x back out of Montgomery form.
+    pub(crate) fn decode(&self, x: &BigUint, m: &BigUint) -> BigUint {
+        self.mul(x, &BigUint::one(), m)
+    }
+}
+
 /// Computes z mod m = x * y * 2 ** (-n*_W) mod m
 /// assuming k = -1/m mod 2**_W
 /// See Gueron, "Efficient Software Implementations of Modular Exponentiation".
@@ -130,7 +176,12 @@ const fn mul_add_www(x: BigDigit, y: BigDigit, c: BigDigit) -> (BigDigit, BigDig
 
 /// Calculates x ** y mod m using a fixed, 4-bit window.
 #[allow(clippy::many_single_char_names)]
-pub(super) fn monty_modpow(x: &BigUint, y: &BigUint, m: &BigUint) -> BigUint {
+pub(super) fn monty_modpow<T: Token + ?Sized>(
+    x: &BigUint,
+    y: &BigUint,
+    m: &BigUint,
+    token: &T,
+) -> Result<BigUint, T::Error> {
     assert!(m.data[0] & 1 == 1);
     let mr = MontyReducer::new(m);
     let num_words = m.data.len();
@@ -149,7 +200,7 @@ pub(super) fn monty_modpow(x: &BigUint, y: &BigUint, m: &BigUint) -> BigUint {
 
     // rr = 2**(2*_W*len(m)) mod m
     let mut rr = BigUint::one();
-    rr = (rr.shl(2 * num_words as u64 * u64::from(big_digit::BITS))) % m;
+    rr = rr.into_shl_unchecked(2 * num_words as u64 * u64::from(big_digit::BITS)) % m;
     if rr.data.len() < num_words {
         rr.data.resize(num_words, 0);
     }
@@ -175,6 +226,7 @@ pub(super) fn monty_modpow(x: &BigUint, y: &BigUint, m: &BigUint) -> BigUint {
 
     // same windowed exponent, but with Montgomery multiplications
     for i in (0..y.data.len()).rev() {
+        token.check()?;
         let mut yi = y.data[i];
         let mut j = 0;
         while j < big_digit::BITS {
@@ -218,5 +270,5 @@ pub(super) fn monty_modpow(x: &BigUint, y: &BigUint, m: &BigUint) -> BigUint {
     }
 
     zz.normalize();
-    zz
+    Ok(zz)
 }
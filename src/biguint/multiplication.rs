@@ -1,14 +1,19 @@
 use super::addition::{__add2, add2};
+use super::cancel::{self, Token};
+use super::reserve;
+use super::scratch;
 use super::subtraction::sub2;
 use super::{biguint_from_vec, cmp_slice, BigUint, IntDigits};
 
 use crate::big_digit::{self, BigDigit, DoubleBigDigit};
 use crate::Sign::{self, Minus, NoSign, Plus};
-use crate::{BigInt, UsizePromotion};
+use crate::{BigInt, CapacityError, UsizePromotion};
 
-use core::cmp::Ordering;
+use core::cmp::{self, Ordering};
 use core::iter::Product;
+use core::mem;
 use core::ops::{Mul, MulAssign};
+use num_integer::Integer;
 use num_traits::{CheckedMul, FromPrimitive, One, Zero};
 
 #[inline]
@@ -35,7 +40,7 @@ fn mul_with_carry(a: BigDigit, b: BigDigit, acc: &mut DoubleBigDigit) -> BigDigi
 
 /// Three argument multiply accumulate:
 /// acc += b * c
-fn mac_digit(acc: &mut [BigDigit], b: &[BigDigit], c: BigDigit) {
+pub(super) fn mac_digit(acc: &mut [BigDigit], b: &[BigDigit], c: BigDigit) {
     if c == 0 {
         return;
     }
@@ -63,15 +68,26 @@ fn bigint_from_slice(slice: &[BigDigit]) -> BigInt {
 
 /// Three argument multiply accumulate:
 /// acc += b * c
-#[allow(clippy::many_single_char_names)]
-fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
+pub(super) fn mac3(acc: &mut [BigDigit], b: &[BigDigit], c: &[BigDigit]) {
+    cancel::unwrap(mac3_cancellable(acc, b, c, &()));
+}
+
+/// [`mac3`], checking `token` before each subquadratic step and returning its error, with
+/// `acc` left partly updated, once it is set.
+#[allow(clippy::many_single_char_names, clippy::too_many_lines)]
+fn mac3_cancellable<T: Token + ?Sized>(
+    mut acc: &mut [BigDigit],
+    mut b: &[BigDigit],
+    mut c: &[BigDigit],
+    token: &T,
+) -> Result<(), T::Error> {
     // Least-significant zeros have no effect on the output.
     if let Some(&0) = b.first() {
         if let Some(nz) = b.iter().position(|&d| d != 0) {
             b = &b[nz..];
             acc = &mut acc[nz..];
         } else {
-            return;
+            return Ok(());
         }
     }
     if let Some(&0) = c.first() {
@@ -79,7 +95,7 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
             c = &c[nz..];
             acc = &mut acc[nz..];
         } else {
-            return;
+            return Ok(());
         }
     }
 
@@ -98,8 +114,14 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
     // The thresholds are somewhat arbitrary, chosen by evaluating the results
     // of `cargo bench --bench bigint multiply`.
 
+    if x.len() > 32 {
+        token.check()?;
+    }
+
     if x.len() <= 32 {
         // Long multiplication:
+        count_stat!(long_multiplications);
+        count_stat!(limbs_multiplied, x.len() * y.len());
         for (i, xi) in x.iter().enumerate() {
             mac_digit(&mut acc[i..], y, *xi);
         }
@@ -156,12 +178,20 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         //            = ((z1 - z0) * NBASE ^ m2) + z0
         //            = ((z1 - z0) * NBASE ^ m2) + z0
         //            = (x * high2) * NBASE ^ m2 + z0
+        trace_span!(
+            "mul",
+            x_len = x.len(),
+            y_len = y.len(),
+            algorithm = "half-karatsuba"
+        );
+        count_stat!(half_karatsuba_multiplications);
+
         let m2 = y.len() / 2;
         let (low2, high2) = y.split_at(m2);
 
         // (x * high2) * NBASE ^ m2 + z0
-        mac3(acc, x, low2);
-        mac3(&mut acc[m2..], x, high2);
+        mac3_cancellable(acc, x, low2, token)?;
+        mac3_cancellable(&mut acc[m2..], x, high2, token)?;
     } else if x.len() <= 256 {
         // Karatsuba multiplication:
         //
@@ -224,6 +254,14 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         // The other trick we use is instead of doing explicit shifts, we slice acc at the
         // appropriate offset when doing the add.
 
+        trace_span!(
+            "mul",
+            x_len = x.len(),
+            y_len = y.len(),
+            algorithm = "karatsuba"
+        );
+        count_stat!(karatsuba_multiplications);
+
         // When x is smaller than y, it's significantly faster to pick b such that x is split in
         // half, not y:
         let b = x.len() / 2;
@@ -233,13 +271,15 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         // We reuse the same BigUint for all the intermediate multiplies and have to size p
         // appropriately here: x1.len() >= x0.len and y1.len() >= y0.len():
         let len = x1.len() + y1.len() + 1;
-        let mut p = BigUint { data: vec![0; len] };
+        let mut p = BigUint {
+            data: scratch::take(len),
+        };
 
         // p2 = x1 * y1
-        mac3(&mut p.data, x1, y1);
+        mac3_cancellable(&mut p.data, x1, y1, token)?;
 
         // Not required, but the adds go faster if we drop any unneeded 0s from the end:
-        p.normalize();
+        p.truncate_zeros();
 
         add2(&mut acc[b..], &p.data);
         add2(&mut acc[b * 2..], &p.data);
@@ -249,8 +289,8 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         p.data.resize(len, 0);
 
         // p0 = x0 * y0
-        mac3(&mut p.data, x0, y0);
-        p.normalize();
+        mac3_cancellable(&mut p.data, x0, y0, token)?;
+        p.truncate_zeros();
 
         add2(acc, &p.data);
         add2(&mut acc[b..], &p.data);
@@ -265,16 +305,20 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
                 p.data.truncate(0);
                 p.data.resize(len, 0);
 
-                mac3(&mut p.data, &j0.data, &j1.data);
-                p.normalize();
+                mac3_cancellable(&mut p.data, &j0.data, &j1.data, token)?;
+                p.truncate_zeros();
 
                 sub2(&mut acc[b..], &p.data);
             }
             Minus => {
-                mac3(&mut acc[b..], &j0.data, &j1.data);
+                mac3_cancellable(&mut acc[b..], &j0.data, &j1.data, token)?;
             }
             NoSign => (),
         }
+
+        scratch::give(p.data);
+        scratch::give(j0.data);
+        scratch::give(j1.data);
     } else {
         // Toom-3 multiplication:
         //
@@ -284,6 +328,14 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         // The general idea is to treat the large integers digits as
         // polynomials of a certain degree and determine the coefficients/digits
         // of the product of the two via interpolation of the polynomial product.
+        trace_span!(
+            "mul",
+            x_len = x.len(),
+            y_len = y.len(),
+            algorithm = "toom-3"
+        );
+        count_stat!(toom3_multiplications);
+
         let i = y.len() / 3 + 1;
 
         let x0_len = Ord::min(x.len(), i);
@@ -343,19 +395,19 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         let q2 = &q - &y1;
 
         // w(0)
-        let r0 = &x0 * &y0;
+        let r0 = mul_signed(&x0, &y0, token)?;
 
         // w(inf)
-        let r4 = &x2 * &y2;
+        let r4 = mul_signed(&x2, &y2, token)?;
 
         // w(1)
-        let r1 = (p + x1) * (q + y1);
+        let r1 = mul_signed(&(p + x1), &(q + y1), token)?;
 
         // w(-1)
-        let r2 = &p2 * &q2;
+        let r2 = mul_signed(&p2, &q2, token)?;
 
         // w(-2)
-        let r3 = ((p2 + x2) * 2 - x0) * ((q2 + y2) * 2 - y0);
+        let r3 = mul_signed(&((p2 + x2) * 2 - x0), &((q2 + y2) * 2 - y0), token)?;
 
         // Evaluating these points gives us the following system of linear equations.
         //
@@ -379,7 +431,7 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
         let mut comp3: BigInt = (r3 - &r1) / 3u32;
         let mut comp1: BigInt = (r1 - &r2) >> 1;
         let mut comp2: BigInt = r2 - &r0;
-        comp3 = ((&comp2 - comp3) >> 1) + (&r4 << 1);
+        comp3 = ((&comp2 - comp3) >> 1) + (&r4 + &r4);
         comp2 += &comp1 - &r4;
         comp1 -= &comp3;
 
@@ -405,23 +457,214 @@ fn mac3(mut acc: &mut [BigDigit], mut b: &[BigDigit], mut c: &[BigDigit]) {
             }
         }
     }
+    Ok(())
+}
+
+/// Returns `x * y` for the Toom-3 products, passing `token` on to [`mac3_cancellable`].
+fn mul_signed<T: Token + ?Sized>(x: &BigInt, y: &BigInt, token: &T) -> Result<BigInt, T::Error> {
+    let prod = mul3_cancellable(x.digits(), y.digits(), token)?;
+    Ok(BigInt::from_biguint(x.sign() * y.sign(), prod))
 }
 
 fn mul3(x: &[BigDigit], y: &[BigDigit]) -> BigUint {
+    cancel::unwrap(mul3_cancellable(x, y, &()))
+}
+
+/// [`mul3`], returning the error of `token` if it is set before the product is done.
+pub(super) fn mul3_cancellable<T: Token + ?Sized>(
+    x: &[BigDigit],
+    y: &[BigDigit],
+    token: &T,
+) -> Result<BigUint, T::Error> {
     let len = x.len() + y.len() + 1;
     let mut prod = BigUint { data: vec![0; len] };
+    count_stat!(allocations);
+
+    mac3_cancellable(&mut prod.data, x, y, token)?;
+    Ok(prod.normalized())
+}
+
+/// Writes `x * y` into `out`, reusing its allocation.
+pub(super) fn mul3_into(x: &[BigDigit], y: &[BigDigit], out: &mut BigUint) {
+    out.data.clear();
+    if x.is_empty() || y.is_empty() {
+        return;
+    }
+    out.data.resize(x.len() + y.len() + 1, 0);
+    mac3(&mut out.data, x, y);
+    out.truncate_zeros();
+}
+
+/// Returns `x * y` in a buffer reserved with [`reserve::digits`].
+pub(super) fn try_mul3(x: &[BigDigit], y: &[BigDigit]) -> Result<BigUint, CapacityError> {
+    let mut out = BigUint {
+        data: reserve::digits(x.len() + y.len() + 1)?,
+    };
+    mul3_into(x, y, &mut out);
+    Ok(out)
+}
+
+/// Operands up to this many digits use the truncated long multiplication of [`mul_low_bits`]
+/// and [`mul_high_bits`]; above it the subquadratic full product is faster.
+const TRUNCATED_MUL_MAX_DIGITS: usize = 32;
+
+/// Returns `(x * y) mod 2^bits`, computing only the digits of the product below the cut.
+pub(super) fn mul_low_bits(x: &BigUint, y: &BigUint, bits: u64) -> BigUint {
+    let n = usize::try_from(Integer::div_ceil(&bits, &u64::from(big_digit::BITS)))
+        .unwrap_or(usize::MAX);
+    let x = &x.data[..cmp::min(x.data.len(), n)];
+    let y = &y.data[..cmp::min(y.data.len(), n)];
+    if x.is_empty() || y.is_empty() {
+        return BigUint::ZERO;
+    }
+
+    let mut prod = if cmp::min(x.len(), y.len()) <= TRUNCATED_MUL_MAX_DIGITS {
+        let len = cmp::min(n, x.len() + y.len());
+        let mut acc = vec![0; len];
+        for (i, &xi) in x.iter().enumerate() {
+            let acc = &mut acc[i..];
+            let y = &y[..cmp::min(y.len(), acc.len())];
+            let mut carry = 0;
+            let (acc_lo, acc_hi) = acc.split_at_mut(y.len());
+            for (a, &b) in acc_lo.iter_mut().zip(y) {
+                *a = mac_with_carry(*a, b, xi, &mut carry);
+            }
+            // Whatever carries past the cut is dropped.
+            for a in acc_hi {
+                if carry == 0 {
+                    break;
+                }
+                *a = mac_with_carry(*a, 0, 0, &mut carry);
+            }
+        }
+        biguint_from_vec(acc)
+    } else {
+        mul3(x, y)
+    };
 
-    mac3(&mut prod.data, x, y);
+    prod.data.truncate(n);
+    let partial = (bits % u64::from(big_digit::BITS)) as u8;
+    if partial != 0 {
+        if let Some(top) = prod.data.get_mut(n - 1) {
+            *top &= (1 << partial) - 1;
+        }
+    }
     prod.normalized()
 }
 
+/// Returns `(x * y) >> bits`, skipping the columns of the product far below the cut.
+///
+/// Each column `t` of the long multiplication is below `(t + 1) * β^(t + 2)`, so leaving out
+/// the first `c` columns loses less than `β^(c + 2)`. With `c` three digits under the cut,
+/// the truncated product gives the answer unless its low part is within `β^(c + 2)` of the
+/// next multiple of `2^bits`, which we check, falling back to the full product.
+pub(super) fn mul_high_bits(x: &BigUint, y: &BigUint, bits: u64) -> BigUint {
+    let (x, y) = (&x.data[..], &y.data[..]);
+    let cut = usize::try_from(bits / u64::from(big_digit::BITS)).unwrap_or(usize::MAX);
+    if cut >= x.len() + y.len() {
+        return BigUint::ZERO;
+    }
+    let skip = cut.saturating_sub(3);
+    if skip == 0 || cmp::min(x.len(), y.len()) > TRUNCATED_MUL_MAX_DIGITS {
+        return mul3(x, y) >> bits;
+    }
+
+    let mut acc = vec![0; x.len() + y.len() + 1];
+    for (i, &xi) in x.iter().enumerate() {
+        let j = skip.saturating_sub(i);
+        if j < y.len() {
+            mac_digit(&mut acc[i + j..], &y[j..], xi);
+        }
+    }
+    let partial = biguint_from_vec(acc);
+    let low = &partial >> bits;
+    let error = BigUint::pow2((skip as u64 + 2) * u64::from(big_digit::BITS));
+    if (partial + error) >> bits == low {
+        low
+    } else {
+        mul3(x, y) >> bits
+    }
+}
+
+/// Adds `x * y` to `acc` in place, without forming the product separately.
+pub(crate) fn addmul3(acc: &mut BigUint, x: &[BigDigit], y: &[BigDigit]) {
+    if x.is_empty() || y.is_empty() {
+        return;
+    }
+    let len = cmp::max(acc.data.len(), x.len() + y.len()) + 1;
+    acc.data.resize(len, 0);
+    mac3(&mut acc.data, x, y);
+    acc.normalize();
+}
+
+/// Returns the sum of `x * y` over the pairs of `xs` and `ys`.
+///
+/// Every product is accumulated into one buffer sized up front, one digit past the longest
+/// product so the sum of fewer than `β` products cannot overflow, and normalized once.
+pub(super) fn dot(xs: &[BigUint], ys: &[BigUint]) -> BigUint {
+    assert_eq!(
+        xs.len(),
+        ys.len(),
+        "dot product of slices with different lengths"
+    );
+    let longest = xs
+        .iter()
+        .zip(ys)
+        .map(|(x, y)| x.data.len() + y.data.len())
+        .max()
+        .unwrap_or(0);
+    let mut acc = vec![0; longest + 2];
+    for (x, y) in xs.iter().zip(ys) {
+        if !x.is_zero() && !y.is_zero() {
+            mac3(&mut acc, &x.data, &y.data);
+        }
+    }
+    biguint_from_vec(acc)
+}
+
+/// Replaces `acc` with `|acc - x * y|` in place, returning `true` if the product was larger.
+///
+/// With `L` digits of room, the product is accumulated onto the complement `β^L - acc`. The sum
+/// reaches `β^L` exactly when the product is at least `acc`, leaving `x * y - acc` below it,
+/// and otherwise complementing again gives `acc - x * y`.
+pub(crate) fn submul3(acc: &mut BigUint, x: &[BigDigit], y: &[BigDigit]) -> bool {
+    if x.is_empty() || y.is_empty() {
+        return false;
+    }
+    if acc.is_zero() {
+        addmul3(acc, x, y);
+        return true;
+    }
+
+    let len = cmp::max(acc.data.len(), x.len() + y.len()) + 1;
+    acc.data.resize(len, 0);
+    complement(&mut acc.data);
+    acc.data.push(0);
+    mac3(&mut acc.data, x, y);
+
+    let larger = acc.data.pop() == Some(1);
+    if !larger {
+        complement(&mut acc.data);
+    }
+    acc.normalize();
+    larger
+}
+
+/// Replaces the nonzero `digits` with `β^len - digits`.
+fn complement(digits: &mut [BigDigit]) {
+    for d in digits.iter_mut() {
+        *d = !*d;
+    }
+    __add2(digits, &[1]);
+}
+
 fn scalar_mul(a: &mut BigUint, b: BigDigit) {
     match b {
         0 => a.set_zero(),
         1 => {}
         _ => {
             if b.is_power_of_two() {
-                *a <<= b.trailing_zeros();
+                *a = mem::take(a).into_shl_unchecked(u64::from(b.trailing_zeros()));
             } else {
                 let mut carry = 0;
                 for a in &mut a.data {
@@ -446,14 +689,20 @@ fn sub_sign(mut a: &[BigDigit], mut b: &[BigDigit]) -> (Sign, BigUint) {
 
     match cmp_slice(a, b) {
         Ordering::Greater => {
-            let mut a = a.to_vec();
-            sub2(&mut a, b);
-            (Plus, biguint_from_vec(a))
+            let mut a = BigUint {
+                data: scratch::take_copy(a),
+            };
+            sub2(&mut a.data, b);
+            a.truncate_zeros();
+            (Plus, a)
         }
         Ordering::Less => {
-            let mut b = b.to_vec();
-            sub2(&mut b, a);
-            (Minus, biguint_from_vec(b))
+            let mut b = BigUint {
+                data: scratch::take_copy(b),
+            };
+            sub2(&mut b.data, a);
+            b.truncate_zeros();
+            (Minus, b)
         }
         Ordering::Equal => (NoSign, BigUint::ZERO),
     }
@@ -0,0 +1,241 @@
+use super::BigUint;
+
+use crate::TryFromBigIntError;
+
+use core::borrow::Borrow;
+use core::convert::TryFrom;
+use core::fmt;
+use core::num::{NonZeroU128, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8, NonZeroUsize};
+use core::ops::{Add, Deref, Div, Mul, Rem};
+use num_integer::Integer;
+use num_traits::{One, Pow, Zero};
+
+/// A [`BigUint`] that is known not to be zero, like the `NonZero` integers of `core::num`.
+///
+/// Functions that divide by a value or reduce modulo it can take a `NonZeroBigUint` and have
+/// the caller prove the divisor is non-zero once, at construction, instead of panicking on
+/// zero at run time. Sums, products, powers, and least common multiples of non-zero values
+/// stay non-zero, so they are closed under those operations without another check, and
+/// [`BigUint`] can be divided by a `NonZeroBigUint` without any panic.
+///
+/// ```
+/// use num_bigint::{BigUint, NonZeroBigUint};
+///
+/// let m = NonZeroBigUint::new(BigUint::from(7u8)).unwrap();
+/// assert!(NonZeroBigUint::new(BigUint::from(0u8)).is_none());
+///
+/// let x = BigUint::from(100u8);
+/// assert_eq!(&x % &m, BigUint::from(2u8));
+/// assert_eq!(&x / &m, BigUint::from(14u8));
+///
+/// let m2 = &m * &m + &x;
+/// assert_eq!(m2.get(), &BigUint::from(149u8));
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroBigUint(BigUint);
+
+impl NonZeroBigUint {
+    /// Returns `n` as a `NonZeroBigUint`, or `None` if it is zero.
+    #[must_use]
+    #[inline]
+    pub fn new(n: BigUint) -> Option<Self> {
+        if n.is_zero() {
+            None
+        } else {
+            Some(Self(n))
+        }
+    }
+
+    /// Returns the value 1.
+    #[must_use]
+    #[inline]
+    pub fn one() -> Self {
+        Self(BigUint::one())
+    }
+
+    /// Returns a reference to the value.
+    #[must_use]
+    #[inline]
+    pub const fn get(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// Returns the value.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> BigUint {
+        self.0
+    }
+
+    /// Returns `self` raised to the power `exp`, which is never zero.
+    #[must_use]
+    pub fn pow(&self, exp: u32) -> Self {
+        Self(Pow::pow(&self.0, exp))
+    }
+
+    /// Returns the least common multiple of `self` and `other`, which is never zero.
+    #[must_use]
+    pub fn lcm(&self, other: &Self) -> Self {
+        Self(self.0.lcm(&other.0))
+    }
+
+    /// Returns the greatest common divisor of `self` and `other`, which is never zero.
+    #[must_use]
+    pub fn gcd(&self, other: &Self) -> Self {
+        Self(self.0.gcd(&other.0))
+    }
+}
+
+impl Deref for NonZeroBigUint {
+    type Target = BigUint;
+
+    #[inline]
+    fn deref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl AsRef<BigUint> for NonZeroBigUint {
+    #[inline]
+    fn as_ref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl Borrow<BigUint> for NonZeroBigUint {
+    #[inline]
+    fn borrow(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl From<NonZeroBigUint> for BigUint {
+    #[inline]
+    fn from(n: NonZeroBigUint) -> Self {
+        n.0
+    }
+}
+
+impl TryFrom<BigUint> for NonZeroBigUint {
+    type Error = TryFromBigIntError<BigUint>;
+
+    #[inline]
+    fn try_from(n: BigUint) -> Result<Self, TryFromBigIntError<BigUint>> {
+        if n.is_zero() {
+            Err(TryFromBigIntError::zero(n))
+        } else {
+            Ok(Self(n))
+        }
+    }
+}
+
+macro_rules! impl_nonzero_biguint_from_nonzero {
+    ($($NonZero:ty),*) => {$(
+        impl From<$NonZero> for NonZeroBigUint {
+            #[inline]
+            fn from(n: $NonZero) -> Self {
+                Self(BigUint::from(n.get()))
+            }
+        }
+    )*};
+}
+
+impl_nonzero_biguint_from_nonzero!(
+    NonZeroU8,
+    NonZeroU16,
+    NonZeroU32,
+    NonZeroU64,
+    NonZeroUsize,
+    NonZeroU128
+);
+
+impl fmt::Debug for NonZeroBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for NonZeroBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Add for NonZeroBigUint, add);
+
+impl Add<&NonZeroBigUint> for &NonZeroBigUint {
+    type Output = NonZeroBigUint;
+
+    #[inline]
+    fn add(self, other: &NonZeroBigUint) -> NonZeroBigUint {
+        NonZeroBigUint(&self.0 + &other.0)
+    }
+}
+
+/// Adding any value to a non-zero value leaves it non-zero.
+impl Add<&BigUint> for &NonZeroBigUint {
+    type Output = NonZeroBigUint;
+
+    #[inline]
+    fn add(self, other: &BigUint) -> NonZeroBigUint {
+        NonZeroBigUint(&self.0 + other)
+    }
+}
+
+impl Add<&BigUint> for NonZeroBigUint {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, other: &BigUint) -> Self {
+        Self(self.0 + other)
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Mul for NonZeroBigUint, mul);
+
+impl Mul<&NonZeroBigUint> for &NonZeroBigUint {
+    type Output = NonZeroBigUint;
+
+    #[inline]
+    fn mul(self, other: &NonZeroBigUint) -> NonZeroBigUint {
+        NonZeroBigUint(&self.0 * &other.0)
+    }
+}
+
+/// Division by a value known to be non-zero, which cannot panic.
+impl Div<&NonZeroBigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn div(self, other: &NonZeroBigUint) -> BigUint {
+        self / &other.0
+    }
+}
+
+impl Div<&NonZeroBigUint> for BigUint {
+    type Output = Self;
+
+    #[inline]
+    fn div(self, other: &NonZeroBigUint) -> Self {
+        self / &other.0
+    }
+}
+
+/// The remainder modulo a value known to be non-zero, which cannot panic.
+impl Rem<&NonZeroBigUint> for &BigUint {
+    type Output = BigUint;
+
+    #[inline]
+    fn rem(self, other: &NonZeroBigUint) -> BigUint {
+        self % &other.0
+    }
+}
+
+impl Rem<&NonZeroBigUint> for BigUint {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, other: &NonZeroBigUint) -> Self {
+        self % &other.0
+    }
+}
@@ -0,0 +1,96 @@
+use super::BigUint;
+
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+use num_integer::Integer;
+
+/// A [`BigUint`] that is known to be odd, and so also non-zero.
+///
+/// Montgomery reduction and the constant-time inverse need an odd modulus. Taking an
+/// `OddModulus` moves that check to construction, so that [`BigUint::modpow_odd`],
+/// [`BigUint::modinv_ct_odd`] and [`ModRing::from_odd`](crate::ModRing::from_odd) cannot panic
+/// on their modulus, and a modulus validated once can be reused without checking it again.
+///
+/// ```
+/// use num_bigint::{BigUint, OddModulus};
+///
+/// let m = OddModulus::new(BigUint::from(383u32)).unwrap();
+/// assert!(OddModulus::new(BigUint::from(384u32)).is_none());
+/// assert!(OddModulus::new(BigUint::from(0u32)).is_none());
+///
+/// let x = BigUint::from(271u32);
+/// assert_eq!(x.modinv_ct_odd(&m), Some(BigUint::from(106u32)));
+/// assert_eq!(x.modpow_odd(&BigUint::from(2u32), &m), &x * &x % m.get());
+/// ```
+#[derive(Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct OddModulus(BigUint);
+
+impl OddModulus {
+    /// Returns `n` as an `OddModulus`, or `None` if it is even or zero.
+    #[must_use]
+    #[inline]
+    pub fn new(n: BigUint) -> Option<Self> {
+        if n.is_odd() {
+            Some(Self(n))
+        } else {
+            None
+        }
+    }
+
+    /// Returns a reference to the modulus.
+    #[must_use]
+    #[inline]
+    pub const fn get(&self) -> &BigUint {
+        &self.0
+    }
+
+    /// Returns the modulus.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> BigUint {
+        self.0
+    }
+}
+
+impl Deref for OddModulus {
+    type Target = BigUint;
+
+    #[inline]
+    fn deref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl AsRef<BigUint> for OddModulus {
+    #[inline]
+    fn as_ref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl Borrow<BigUint> for OddModulus {
+    #[inline]
+    fn borrow(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl From<OddModulus> for BigUint {
+    #[inline]
+    fn from(m: OddModulus) -> Self {
+        m.0
+    }
+}
+
+impl fmt::Debug for OddModulus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.0, f)
+    }
+}
+
+impl fmt::Display for OddModulus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
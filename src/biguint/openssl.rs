@@ -0,0 +1,43 @@
+#![cfg(feature = "openssl")]
+#![cfg_attr(docsrs, doc(cfg(feature = "openssl")))]
+
+use super::BigUint;
+
+use openssl::bn::{BigNum, BigNumRef};
+use openssl::error::ErrorStack;
+
+impl BigUint {
+    /// Creates a [`BigUint`] from an OpenSSL [`BigNumRef`], or returns `None` if it's negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    /// use openssl::bn::BigNum;
+    ///
+    /// let bn = BigNum::from_dec_str("1234567890123456789012345678901234567890").unwrap();
+    /// let x = BigUint::from_bn(&bn).unwrap();
+    /// assert_eq!(x.to_string(), "1234567890123456789012345678901234567890");
+    /// ```
+    #[must_use]
+    pub fn from_bn(bn: &BigNumRef) -> Option<Self> {
+        if bn.is_negative() {
+            None
+        } else {
+            Some(Self::from_bytes_be(&bn.to_vec()))
+        }
+    }
+
+    /// Converts this [`BigUint`] to an OpenSSL [`BigNum`].
+    ///
+    /// This only fails if OpenSSL fails to allocate.
+    pub fn to_bn(&self) -> Result<BigNum, ErrorStack> {
+        to_bn(self, false)
+    }
+}
+
+pub(crate) fn to_bn(x: &BigUint, negative: bool) -> Result<BigNum, ErrorStack> {
+    let mut bn = BigNum::from_slice(&x.to_bytes_be())?;
+    bn.set_negative(negative);
+    Ok(bn)
+}
@@ -1,10 +1,14 @@
+use super::cancel::{self, Token};
 use super::monty::monty_modpow;
+use super::multiplication::try_mul3;
 use super::BigUint;
 
 use crate::big_digit::{self, BigDigit};
+use crate::CapacityError;
 
+use core::convert::Infallible;
 use num_integer::Integer;
-use num_traits::{One, Pow, ToPrimitive, Zero};
+use num_traits::{One, Pow, PrimInt, ToPrimitive, Zero};
 
 impl Pow<&Self> for BigUint {
     type Output = Self;
@@ -60,35 +64,64 @@ impl Pow<BigUint> for &BigUint {
     }
 }
 
+/// Returns `base^exp` by repeated squaring, without checking the size limit.
+fn pow_unlimited<T: PrimInt>(base: BigUint, exp: T) -> BigUint {
+    match pow_with(base, exp, |x, y| Ok::<_, Infallible>(x * y)) {
+        Ok(result) => result,
+        Err(never) => match never {},
+    }
+}
+
+/// Returns `base^exp` by repeated squaring, multiplying with `mul`.
+fn pow_with<T: PrimInt, E>(
+    mut base: BigUint,
+    mut exp: T,
+    mul: impl Fn(&BigUint, &BigUint) -> Result<BigUint, E>,
+) -> Result<BigUint, E> {
+    if exp.is_zero() {
+        return Ok(BigUint::one());
+    }
+
+    while (exp & T::one()).is_zero() {
+        base = mul(&base, &base)?;
+        exp = exp >> 1;
+    }
+
+    if exp.is_one() {
+        return Ok(base);
+    }
+
+    let mut acc = base.clone();
+    while exp > T::one() {
+        exp = exp >> 1;
+        base = mul(&base, &base)?;
+        if (exp & T::one()).is_one() {
+            acc = mul(&acc, &base)?;
+        }
+    }
+    Ok(acc)
+}
+
 macro_rules! pow_impl {
     ($T:ty) => {
         impl Pow<$T> for BigUint {
             type Output = BigUint;
 
-            fn pow(self, mut exp: $T) -> BigUint {
+            fn pow(self, exp: $T) -> BigUint {
                 if exp == 0 {
                     return BigUint::one();
                 }
-                let mut base = self;
-
-                while exp & 1 == 0 {
-                    base = &base * &base;
-                    exp >>= 1;
-                }
-
-                if exp == 1 {
-                    return base;
-                }
-
-                let mut acc = base.clone();
-                while exp > 1 {
-                    exp >>= 1;
-                    base = &base * &base;
-                    if exp & 1 == 1 {
-                        acc *= &base;
-                    }
-                }
-                acc
+                // A non-zero result has at least `(bits - 1) * exp + 1` bits.
+                let min_bits = match self.bits() {
+                    0 => 0,
+                    bits => (bits - 1)
+                        .saturating_mul(exp.to_u64().unwrap_or(u64::MAX))
+                        .saturating_add(1),
+                };
+                crate::limits::check(min_bits);
+                let result = pow_unlimited(self, exp);
+                crate::limits::check(result.bits());
+                result
             }
         }
 
@@ -131,7 +164,107 @@ pow_impl!(u64);
 pow_impl!(usize);
 pow_impl!(u128);
 
+/// Returns `x^exp`, or `None` if the result would have more than `max_bits` bits.
+///
+/// Since `2^(b-1) <= x < 2^b` for `b = x.bits()`, the result has between `(b-1)*exp + 1` and
+/// `b*exp` bits, so only a result straddling the budget needs computing to be sure.
+pub(super) fn checked_pow(x: &BigUint, exp: &BigUint, max_bits: u64) -> Option<BigUint> {
+    if exp.is_zero() || x.is_one() {
+        return (max_bits >= 1).then(BigUint::one);
+    }
+    if x.is_zero() {
+        return Some(BigUint::ZERO);
+    }
+
+    let max_bits = Ord::min(max_bits, crate::limits::max_bits());
+    let exp = exp.to_u64()?;
+    let bits = x.bits();
+    let min_bits = (bits - 1).checked_mul(exp)?.checked_add(1)?;
+    if min_bits > max_bits {
+        return None;
+    }
+    let result = pow_unlimited(x.clone(), exp);
+    if result.bits() <= max_bits {
+        Some(result)
+    } else {
+        None
+    }
+}
+
+/// Returns `x^exp`, or an error if the result would be over the size limit.
+///
+/// As in [`checked_pow`], a result that is surely too large is rejected before it is computed.
+pub(super) fn try_pow(x: &BigUint, exp: u64) -> Result<BigUint, CapacityError> {
+    if exp == 0 || x.is_one() {
+        return Ok(BigUint::one());
+    }
+    if x.is_zero() {
+        return Ok(BigUint::ZERO);
+    }
+
+    let min_bits = (x.bits() - 1)
+        .checked_mul(exp)
+        .and_then(|bits| bits.checked_add(1))
+        .ok_or_else(CapacityError::overflow)?;
+    crate::limits::try_check(min_bits)?;
+    let result = pow_with(x.clone(), exp, |x, y| try_mul3(&x.data, &y.data))?;
+    crate::limits::try_check(result.bits())?;
+    Ok(result)
+}
+
+/// Above this many word-sized factors, [`mul_pow`] raises the odd part with `pow` and does one
+/// full multiplication instead.
+const MUL_POW_SCALAR_STEPS: u32 = 32;
+
+/// Returns `x * base^exp`.
+///
+/// The power of two in `base` becomes a shift. The odd part is applied in the largest powers
+/// that fit a `u64`, each a cheap scalar multiplication, unless there are so many that a
+/// single multiplication by the whole power is faster.
+pub(super) fn mul_pow(x: &BigUint, base: u32, exp: u32) -> BigUint {
+    if exp == 0 {
+        return x.clone();
+    }
+    if base == 0 || x.is_zero() {
+        return BigUint::ZERO;
+    }
+
+    let twos = base.trailing_zeros();
+    let odd = u64::from(base >> twos);
+    let mut result = if odd == 1 {
+        x.clone()
+    } else {
+        // The largest power of `odd` in a `u64`, as `odd^per_chunk`.
+        let (mut chunk, mut per_chunk) = (odd, 1);
+        while let Some(next) = chunk.checked_mul(odd) {
+            chunk = next;
+            per_chunk += 1;
+        }
+        if exp / per_chunk <= MUL_POW_SCALAR_STEPS {
+            let mut result = x * odd.pow(exp % per_chunk);
+            for _ in 0..exp / per_chunk {
+                result *= chunk;
+            }
+            result
+        } else {
+            x * BigUint::from(odd).pow(exp)
+        }
+    };
+    result <<= u64::from(twos) * u64::from(exp);
+    result
+}
+
 pub(super) fn modpow(x: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigUint {
+    cancel::unwrap(modpow_cancellable(x, exponent, modulus, &()))
+}
+
+/// [`modpow`], returning the error of `token` if it is set before the exponentiation is done.
+pub(super) fn modpow_cancellable<T: Token + ?Sized>(
+    x: &BigUint,
+    exponent: &BigUint,
+    modulus: &BigUint,
+    token: &T,
+) -> Result<BigUint, T::Error> {
     assert!(
         !modulus.is_zero(),
         "attempt to calculate with zero modulus!"
@@ -139,21 +272,62 @@ pub(super) fn modpow(x: &BigUint, exponent: &BigUint, modulus: &BigUint) -> BigU
 
     if modulus.is_odd() {
         // For an odd modulus, we can use Montgomery multiplication in base 2^32.
-        monty_modpow(x, exponent, modulus)
+        trace_span!(
+            "modpow",
+            exponent_bits = exponent.bits(),
+            modulus_bits = modulus.bits(),
+            algorithm = "montgomery",
+        );
+        count_stat!(montgomery_modpows);
+        monty_modpow(x, exponent, modulus, token)
     } else {
         // Otherwise do basically the same as `num::pow`, but with a modulus.
-        plain_modpow(x, &exponent.data, modulus)
+        trace_span!(
+            "modpow",
+            exponent_bits = exponent.bits(),
+            modulus_bits = modulus.bits(),
+            algorithm = "plain",
+        );
+        count_stat!(plain_modpows);
+        plain_modpow_cancellable(x, &exponent.data, modulus, token)
     }
 }
 
+pub(super) fn modpow_crt(
+    x: &BigUint,
+    dp: &BigUint,
+    dq: &BigUint,
+    p: &BigUint,
+    q: &BigUint,
+    qinv: &BigUint,
+) -> BigUint {
+    let m1 = modpow(x, dp, p);
+    let m2 = modpow(x, dq, q);
+    // h = qinv * (m1 - m2) mod p, keeping the difference non-negative.
+    let m2p = &m2 % p;
+    let diff = if m1 >= m2p { m1 - m2p } else { m1 + p - m2p };
+    let h = qinv * diff % p;
+    m2 + h * q
+}
+
+#[cfg(test)]
 fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> BigUint {
+    cancel::unwrap(plain_modpow_cancellable(base, exp_data, modulus, &()))
+}
+
+fn plain_modpow_cancellable<T: Token + ?Sized>(
+    base: &BigUint,
+    exp_data: &[BigDigit],
+    modulus: &BigUint,
+    token: &T,
+) -> Result<BigUint, T::Error> {
     assert!(
         !modulus.is_zero(),
         "attempt to calculate with zero modulus!"
     );
 
     let i = match exp_data.iter().position(|&r| r != 0) {
-        None => return BigUint::one(),
+        None => return Ok(BigUint::one()),
         Some(i) => i,
     };
 
@@ -174,7 +348,7 @@ fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> Big
 
     let mut exp_iter = exp_data[i + 1..].iter();
     if exp_iter.len() == 0 && r.is_one() {
-        return base;
+        return Ok(base);
     }
 
     let mut acc = base.clone();
@@ -199,6 +373,7 @@ fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> Big
 
             // consume all other digits before the last
             for &r in exp_iter {
+                token.check()?;
                 let mut r = r;
                 for _ in 0..big_digit::BITS {
                     unit(r.is_odd());
@@ -214,7 +389,7 @@ fn plain_modpow(base: &BigUint, exp_data: &[BigDigit], modulus: &BigUint) -> Big
             r >>= 1;
         }
     }
-    acc
+    Ok(acc)
 }
 
 #[test]
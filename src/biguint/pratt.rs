@@ -0,0 +1,147 @@
+use super::BigUint;
+
+use alloc::vec::Vec;
+use num_integer::Integer;
+use num_traits::One;
+
+/// A Pratt certificate of primality, created by [`BigUint::certify_prime`] and checked by
+/// [`BigUint::verify_certificate`].
+///
+/// For an odd prime `p`, the certificate holds a witness `a` of multiplicative order `p - 1`
+/// modulo `p`, which exists only if `p` is prime, along with the factorization of `p - 1` and,
+/// recursively, a certificate for each of its prime factors. Checking it takes a few modular
+/// exponentiations per prime in the tree, without factoring anything, so anyone can confirm a
+/// primality claim far more cheaply than it was found.
+///
+/// ```
+/// use num_bigint::BigUint;
+///
+/// let p = BigUint::from(1_000_000_007u32);
+/// let cert = p.certify_prime().unwrap();
+/// assert!(p.verify_certificate(&cert));
+/// assert!(!BigUint::from(1_000_000_009u32).verify_certificate(&cert));
+/// assert!(BigUint::from(1_000_000_011u32).certify_prime().is_none());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrattCertificate {
+    prime: BigUint,
+    witness: BigUint,
+    /// Certificates for the distinct prime factors of `prime - 1`, with their exponents.
+    factors: Vec<(Self, u32)>,
+}
+
+impl PrattCertificate {
+    /// Assembles a certificate claiming `prime` is prime, with `witness` of order `prime - 1`
+    /// and certificates for the prime factors of `prime - 1` with their exponents.
+    ///
+    /// Nothing is checked here, so a certificate received from elsewhere must still be passed
+    /// to [`BigUint::verify_certificate`].
+    #[must_use]
+    pub const fn new(prime: BigUint, witness: BigUint, factors: Vec<(Self, u32)>) -> Self {
+        Self {
+            prime,
+            witness,
+            factors,
+        }
+    }
+
+    /// Returns the prime this certificate is for.
+    #[must_use]
+    #[inline]
+    pub const fn prime(&self) -> &BigUint {
+        &self.prime
+    }
+
+    /// Returns the witness, whose order modulo the prime is the prime minus one.
+    #[must_use]
+    #[inline]
+    pub const fn witness(&self) -> &BigUint {
+        &self.witness
+    }
+
+    /// Returns the certificates for the prime factors of the prime minus one, with their
+    /// exponents.
+    #[must_use]
+    #[inline]
+    pub fn factors(&self) -> &[(Self, u32)] {
+        &self.factors
+    }
+
+    fn verify(&self) -> bool {
+        let p = &self.prime;
+        if *p == BigUint::from(2u8) {
+            return true;
+        }
+        if *p < BigUint::from(2u8) || p.is_even() {
+            return false;
+        }
+
+        let p1 = p - 1u8;
+        let mut product = BigUint::one();
+        for (cert, e) in &self.factors {
+            if !cert.verify() {
+                return false;
+            }
+            product *= cert.prime.pow(*e);
+        }
+        if product != p1 || !self.witness.modpow(&p1, p).is_one() {
+            return false;
+        }
+        self.factors
+            .iter()
+            .all(|(cert, _)| !self.witness.modpow(&(&p1 / &cert.prime), p).is_one())
+    }
+}
+
+/// Builds a certificate for `p`, which must be prime, or returns `None` if a witness shows
+/// that it is not.
+pub(super) fn certify(p: &BigUint) -> Option<PrattCertificate> {
+    if *p == BigUint::from(2u8) {
+        return Some(PrattCertificate::new(p.clone(), BigUint::one(), Vec::new()));
+    }
+
+    let p1 = p - 1u8;
+    let factors = p1.factorize();
+    let primes: Vec<&BigUint> = factors.factors().iter().map(|(q, _)| q).collect();
+    let cofactors: Vec<BigUint> = primes.iter().map(|&q| &p1 / q).collect();
+
+    // A prime has a primitive root, and the smallest is almost always tiny.
+    let mut witness = BigUint::from(2u8);
+    while witness < *p {
+        if !witness.modpow(&p1, p).is_one() {
+            return None;
+        }
+        if cofactors.iter().all(|c| !witness.modpow(c, p).is_one()) {
+            let mut certs = Vec::with_capacity(primes.len());
+            for (q, e) in factors.factors() {
+                certs.push((certify(q)?, *e));
+            }
+            return Some(PrattCertificate::new(p.clone(), witness, certs));
+        }
+        witness += 1u8;
+    }
+    None
+}
+
+impl BigUint {
+    /// Returns a [`PrattCertificate`] proving that `self` is prime, or `None` if it is not.
+    ///
+    /// Building the certificate factors `self - 1`, and recursively the factors' predecessors,
+    /// so this is only practical when those [factorizations](Self::factorize) are.
+    #[must_use]
+    pub fn certify_prime(&self) -> Option<PrattCertificate> {
+        if !self.is_prime_deterministic() {
+            return None;
+        }
+        certify(self)
+    }
+
+    /// Returns `true` if `cert` is a valid Pratt certificate for the primality of `self`.
+    ///
+    /// Every step is checked from scratch, so a certificate from an untrusted source cannot
+    /// prove a composite prime.
+    #[must_use]
+    pub fn verify_certificate(&self, cert: &PrattCertificate) -> bool {
+        cert.prime == *self && cert.verify()
+    }
+}
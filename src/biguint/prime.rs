@@ -0,0 +1,441 @@
+use super::{BigUint, Factorization};
+use crate::BigInt;
+
+use alloc::vec::Vec;
+use core::iter::FusedIterator;
+use core::ops::Range;
+use num_integer::Integer;
+use num_traits::{One, ToPrimitive, Zero};
+
+impl BigUint {
+    /// Returns `true` if `self` is a strong Lucas probable prime, using the Selfridge
+    /// parameters: the first `D` in `5, -7, 9, -11, ...` with Jacobi symbol `(D/n) = -1`,
+    /// `P = 1` and `Q = (1 - D) / 4`.
+    ///
+    /// Every prime passes. This is the Lucas half of the Baillie–PSW test, exposed on its own
+    /// so that primality pipelines can be composed and cross-checked; no composite is known to
+    /// pass both it and a strong Fermat test to base 2.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert!(BigUint::from(101u8).is_strong_lucas_prp());
+    /// assert!(!BigUint::from(121u8).is_strong_lucas_prp());
+    /// // The smallest strong Lucas pseudoprime.
+    /// assert!(BigUint::from(5459u16).is_strong_lucas_prp());
+    /// ```
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn is_strong_lucas_prp(&self) -> bool {
+        let n = self;
+        if let Some(small) = n.to_u8() {
+            if small < 3 {
+                return small == 2;
+            }
+        }
+        if n.is_even() {
+            return false;
+        }
+
+        // Find D, failing fast on squares, for which no such D exists.
+        let mut d: i64 = 5;
+        loop {
+            match jacobi(&signed_mod(d, n), n) {
+                -1 => break,
+                0 if *n != Self::from(d.unsigned_abs()) => return false,
+                _ => {}
+            }
+            if d == 13 && n.sqrt().pow(2) == *n {
+                return false;
+            }
+            d = if d > 0 { -(d + 2) } else { -d + 2 };
+        }
+        let q = (1 - d) / 4;
+        let d = signed_mod(d, n);
+        let q = signed_mod(q, n);
+
+        // n + 1 = k * 2^s, with k odd.
+        let n_plus_one = n + 1u32;
+        let (k, s) = n_plus_one.remove_twos();
+
+        // Compute U_k, V_k and Q^k from the top bit of k down, with P = 1.
+        let mut u = Self::one();
+        let mut v = Self::one();
+        let mut qk = q.clone();
+        for i in (0..k.bits() - 1).rev() {
+            u = &u * &v % n;
+            v = sub_mod(&(&v * &v), &qk.shl_unchecked(1), n);
+            qk = &qk * &qk % n;
+            if k.bit(i) {
+                let (u1, v1) = (&u, &v);
+                let next_u = half_mod(u1 + v1, n);
+                let next_v = half_mod(&d * u1 + v1, n);
+                u = next_u;
+                v = next_v;
+                qk = &qk * &q % n;
+            }
+        }
+
+        if u.is_zero() || v.is_zero() {
+            return true;
+        }
+        for _ in 1..s {
+            v = sub_mod(&(&v * &v), &qk.shl_unchecked(1), n);
+            if v.is_zero() {
+                return true;
+            }
+            qk = &qk * &qk % n;
+        }
+        false
+    }
+
+    /// Returns `true` if `self` is prime, with a Miller–Rabin test that is deterministic below
+    /// 3.3·10<sup>24</sup>.
+    ///
+    /// Below that bound, the smallest sufficient set of prime bases for the input's size is
+    /// used, so the answer is exact. Above it, all of those bases are tried and then the
+    /// [strong Lucas test](Self::is_strong_lucas_prp), making a Baillie–PSW test: the answer is
+    /// then only probable, though no composite is known to pass.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// assert!(BigUint::from(1_000_000_007u32).is_prime_deterministic());
+    /// // A strong pseudoprime to bases 2, 3 and 5.
+    /// assert!(!BigUint::from(25_326_001u32).is_prime_deterministic());
+    /// ```
+    #[must_use]
+    pub fn is_prime_deterministic(&self) -> bool {
+        if let Some(small) = self.to_u32() {
+            if small < 2 {
+                return false;
+            }
+        }
+        for &p in &SMALL_PRIMES {
+            if self.is_multiple_of(&Self::from(p)) {
+                return *self == Self::from(p);
+            }
+        }
+        if *self < Self::from(SMALL_PRIMES[SMALL_PRIMES.len() - 1]).pow(2) {
+            return true;
+        }
+
+        let exact = MR_BOUNDS
+            .iter()
+            .position(|&bound| self.to_u128().map_or(false, |n| n < bound));
+        let count = exact.map_or(SMALL_PRIMES.len(), |i| i + 1);
+        let n_minus_one = self - 1u32;
+        let (d, s) = n_minus_one.remove_twos();
+        let witness = |base: u32| {
+            let mut x = Self::from(base).modpow(&d, self);
+            if x.is_one() || x == n_minus_one {
+                return true;
+            }
+            for _ in 1..s {
+                x = &x * &x % self;
+                if x == n_minus_one {
+                    return true;
+                }
+            }
+            false
+        };
+        if !SMALL_PRIMES[..count].iter().all(|&p| witness(p)) {
+            return false;
+        }
+        exact.is_some() || self.is_strong_lucas_prp()
+    }
+    /// Returns an iterator over the primes in `range`, in increasing order.
+    ///
+    /// The range is processed in segments, first sieved by all primes below 2<sup>16</sup>, so
+    /// only the few survivors need a [primality test](Self::is_prime_deterministic).
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let primes: Vec<BigUint> = BigUint::primes_in_range(BigUint::from(10u8)..BigUint::from(30u8)).collect();
+    /// assert_eq!(primes, [11u8, 13, 17, 19, 23, 29].map(BigUint::from));
+    ///
+    /// let start = BigUint::from(1u8) << 200u32;
+    /// let first = BigUint::primes_in_range(start.clone()..&start + 1000u32).next().unwrap();
+    /// assert_eq!(first, start + 235u32);
+    /// ```
+    #[must_use]
+    pub fn primes_in_range(range: Range<Self>) -> Primes {
+        Primes {
+            next: range.start,
+            end: range.end,
+            sieve_primes: small_primes(1 << 16),
+            found: Vec::new(),
+        }
+    }
+    /// Returns `true` if `self` is a square modulo the prime `p`, that is, if `x² ≡ self (mod p)`
+    /// has a solution, which includes every multiple of `p`.
+    ///
+    /// For odd `p` this evaluates the Legendre symbol. The result is meaningless if `p` is not
+    /// prime: for a composite modulus, a Jacobi symbol of 1 does not imply a square root, so
+    /// use [`is_quadratic_residue_mod`](Self::is_quadratic_residue_mod) with its factorization.
+    ///
+    /// Panics if `p` is zero.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// let p = BigUint::from(11u8);
+    /// assert!(BigUint::from(5u8).is_quadratic_residue(&p)); // 4² = 16 ≡ 5
+    /// assert!(!BigUint::from(2u8).is_quadratic_residue(&p));
+    /// ```
+    #[must_use]
+    pub fn is_quadratic_residue(&self, p: &Self) -> bool {
+        assert!(!p.is_zero(), "attempt to calculate with zero modulus!");
+        p.is_even() || jacobi(self, p) != -1
+    }
+
+    /// Returns `true` if `self` is a square modulo the factored `modulus`.
+    ///
+    /// By the Chinese remainder theorem this holds exactly when it holds modulo each prime
+    /// power, which is decided from the Legendre symbol for odd primes and from the residue
+    /// modulo 8 for powers of two.
+    ///
+    /// ```
+    /// use num_bigint::BigUint;
+    ///
+    /// // 2 has Jacobi symbol 1 modulo 15, but is a square modulo neither 3 nor 5.
+    /// let m = BigUint::from(15u8).factorize();
+    /// assert!(!BigUint::from(2u8).is_quadratic_residue_mod(&m));
+    /// assert!(BigUint::from(4u8).is_quadratic_residue_mod(&m));
+    /// ```
+    #[must_use]
+    pub fn is_quadratic_residue_mod(&self, modulus: &Factorization) -> bool {
+        modulus.factors().iter().all(|(p, k)| {
+            let a = self % p.pow(*k);
+            if a.is_zero() {
+                return true;
+            }
+            // Split a = p^v * u with u coprime to p, and v < k since a is non-zero.
+            let mut v = 0;
+            let mut u = a;
+            while (&u % p).is_zero() {
+                u /= p;
+                v += 1;
+            }
+            if v % 2 == 1 {
+                return false;
+            }
+            if p.is_even() {
+                // An odd u is a square modulo 2^m when m = 1, or u ≡ 1 mod 4 when m = 2,
+                // or u ≡ 1 mod 8 when m ≥ 3.
+                let mask = (1u32 << (k - v).min(3)) - 1;
+                (u & Self::from(mask)).is_one()
+            } else {
+                jacobi(&u, p) == 1
+            }
+        })
+    }
+    /// Lifts a simple `root` of the polynomial `f` modulo the prime `p` to the unique root
+    /// modulo `p^k` that is congruent to it, by Hensel's lemma.
+    ///
+    /// The coefficients of `f` are given from the constant term up. Returns `None` if `root` is
+    /// not a root modulo `p`, or is a repeated root, so that `f'(root)` is divisible by `p`.
+    /// Each Newton step doubles the precision, so this takes `log2(k)` steps.
+    ///
+    /// Panics if `p` is less than two.
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, BigUint};
+    ///
+    /// // 3² ≡ 2 (mod 7), lifted to a square root of 2 modulo 7⁵.
+    /// let f = [BigInt::from(-2), BigInt::from(0), BigInt::from(1)];
+    /// let p = BigUint::from(7u8);
+    /// let root = BigUint::hensel_lift(&BigUint::from(3u8), &p, 5, &f).unwrap();
+    /// assert_eq!(root.pow(2) % p.pow(5), BigUint::from(2u8));
+    /// ```
+    #[must_use]
+    #[allow(clippy::many_single_char_names)]
+    pub fn hensel_lift(root: &Self, p: &Self, k: u32, f: &[BigInt]) -> Option<Self> {
+        assert!(*p > Self::one(), "the modulus must be prime");
+        let eval = |coeffs: &mut dyn Iterator<Item = BigInt>, x: &Self, m: &Self| {
+            let m = BigInt::from(m.clone());
+            let x = BigInt::from(x.clone());
+            let value = coeffs.fold(BigInt::zero(), |acc, c| (acc * &x + c) % &m);
+            value.mod_floor(&m).into_parts().1
+        };
+        let f_at = |x: &Self, m: &Self| eval(&mut f.iter().rev().cloned(), x, m);
+        let df_at = |x: &Self, m: &Self| {
+            let mut derivative = f.iter().enumerate().skip(1).rev().map(|(i, c)| c * i);
+            eval(&mut derivative, x, m)
+        };
+
+        let mut r = root % p;
+        if !f_at(&r, p).is_zero() || df_at(&r, p).is_zero() {
+            return None;
+        }
+        let target = p.pow(k);
+        let mut m = p.clone();
+        while m < target {
+            m = Ord::min(&m * &m, target.clone());
+            let inverse = df_at(&r, &m).modinv(&m)?;
+            let step = f_at(&r, &m) * inverse % &m;
+            r = (r + &m - step) % &m;
+        }
+        Some(r % target)
+    }
+}
+
+/// The first thirteen primes, used both for trial division and as Miller–Rabin bases.
+const SMALL_PRIMES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// `MR_BOUNDS[i]` is the smallest strong pseudoprime to all of the first `i + 1` prime bases,
+/// so those bases decide primality below it. A bound repeats where the next base adds nothing.
+const MR_BOUNDS: [u128; 13] = [
+    2_047,
+    1_373_653,
+    25_326_001,
+    3_215_031_751,
+    2_152_302_898_747,
+    3_474_749_660_383,
+    341_550_071_728_321,
+    341_550_071_728_321,
+    3_825_123_056_546_413_051,
+    3_825_123_056_546_413_051,
+    3_825_123_056_546_413_051,
+    318_665_857_834_031_151_167_461,
+    3_317_044_064_679_887_385_961_981,
+];
+
+/// Returns `x` reduced into `0..n`.
+fn signed_mod(x: i64, n: &BigUint) -> BigUint {
+    let r = BigUint::from(x.unsigned_abs()) % n;
+    if x < 0 && !r.is_zero() {
+        n - r
+    } else {
+        r
+    }
+}
+
+/// Returns `(a - b) mod n`, for any `a` and `b`.
+fn sub_mod(a: &BigUint, b: &BigUint, n: &BigUint) -> BigUint {
+    let a = a % n;
+    let b = b % n;
+    if a >= b {
+        a - b
+    } else {
+        a + n - b
+    }
+}
+
+/// Returns `x / 2 mod n` for odd `n`.
+fn half_mod(x: BigUint, n: &BigUint) -> BigUint {
+    let x = if x.is_odd() { x + n } else { x };
+    (x >> 1u8) % n
+}
+
+/// Returns the Jacobi symbol `(a/n)` for odd `n`.
+fn jacobi(a: &BigUint, n: &BigUint) -> i8 {
+    let mut a = a % n;
+    let mut n = n.clone();
+    let mut result = 1;
+    while !a.is_zero() {
+        let twos = a.trailing_zeros().unwrap();
+        a >>= twos;
+        // (2/n) = -1 exactly when n = 3 or 5 (mod 8).
+        let n8 = n.iter_u32_digits().next().unwrap_or(0) & 7;
+        if twos % 2 == 1 && (n8 == 3 || n8 == 5) {
+            result = -result;
+        }
+        // Quadratic reciprocity flips the sign when both are 3 (mod 4).
+        let a4 = a.iter_u32_digits().next().unwrap_or(0) & 3;
+        if a4 == 3 && n8 & 3 == 3 {
+            result = -result;
+        }
+        core::mem::swap(&mut a, &mut n);
+        a %= &n;
+    }
+    if n.is_one() {
+        result
+    } else {
+        0
+    }
+}
+
+/// The number of candidates sieved at once by [`Primes`].
+const SEGMENT_LEN: usize = 1 << 12;
+
+/// Returns the primes below `limit`, by the sieve of Eratosthenes.
+pub(super) fn small_primes(limit: u32) -> Vec<u32> {
+    let mut composite = vec![false; limit as usize];
+    let mut primes = Vec::new();
+    for p in 2..limit {
+        if !composite[p as usize] {
+            primes.push(p);
+            for m in (u64::from(p) * u64::from(p)..u64::from(limit)).step_by(p as usize) {
+                composite[m as usize] = true;
+            }
+        }
+    }
+    primes
+}
+
+/// An iterator over the primes in a range of [`BigUint`], created by
+/// [`BigUint::primes_in_range`].
+#[derive(Clone, Debug)]
+pub struct Primes {
+    next: BigUint,
+    end: BigUint,
+    sieve_primes: Vec<u32>,
+    /// Primes found in the current segment, in decreasing order.
+    found: Vec<BigUint>,
+}
+
+impl Primes {
+    /// Sieves the next segment of the range into `found`.
+    fn sieve_segment(&mut self) {
+        let len = (&self.end - &self.next)
+            .to_usize()
+            .map_or(SEGMENT_LEN, |n| n.min(SEGMENT_LEN));
+        let start = self.next.to_u64();
+        let mut composite = vec![false; len];
+        for &p in &self.sieve_primes {
+            let p64 = u64::from(p);
+            let first = match start {
+                // Start at p², so p itself survives and smaller multiples are left to smaller
+                // primes.
+                Some(start) if start <= p64 * p64 => p64 * p64 - start,
+                _ => u64::from((p - (&self.next % p).to_u32().unwrap()) % p),
+            };
+            let first = match usize::try_from(first) {
+                Ok(first) if first < len => first,
+                _ => continue,
+            };
+            for m in (first..len).step_by(p as usize) {
+                composite[m] = true;
+            }
+        }
+
+        // Survivors below 2^32 have no factor below their square root, so they are prime.
+        let bound = 1u64 << 32;
+        for (i, _) in composite.iter().enumerate().rev().filter(|(_, &c)| !c) {
+            let n = &self.next + i;
+            let prime = match n.to_u64() {
+                Some(n) if n < bound => n >= 2,
+                _ => n.is_prime_deterministic(),
+            };
+            if prime {
+                self.found.push(n);
+            }
+        }
+        self.next += len;
+    }
+}
+
+impl Iterator for Primes {
+    type Item = BigUint;
+
+    fn next(&mut self) -> Option<BigUint> {
+        while self.found.is_empty() && self.next < self.end {
+            self.sieve_segment();
+        }
+        self.found.pop()
+    }
+}
+
+impl FusedIterator for Primes {}
@@ -0,0 +1,95 @@
+#![cfg(feature = "primitive-types")]
+#![cfg_attr(docsrs, doc(cfg(feature = "primitive-types")))]
+
+use super::{biguint_from_u64_digits, BigUint};
+
+use crate::TryFromBigIntError;
+
+use core::convert::TryFrom;
+use primitive_types::{H256, U256, U512};
+
+macro_rules! impl_uint_conversions {
+    ($Uint:ident, $words:expr) => {
+        impl TryFrom<&BigUint> for $Uint {
+            type Error = TryFromBigIntError<()>;
+
+            fn try_from(value: &BigUint) -> Result<Self, TryFromBigIntError<()>> {
+                let digits = value.iter_u64_digits();
+                if digits.len() > $words {
+                    return Err(TryFromBigIntError::new(()));
+                }
+                let mut words = [0u64; $words];
+                for (word, digit) in words.iter_mut().zip(digits) {
+                    *word = digit;
+                }
+                Ok($Uint(words))
+            }
+        }
+
+        impl TryFrom<BigUint> for $Uint {
+            type Error = TryFromBigIntError<BigUint>;
+
+            #[inline]
+            fn try_from(value: BigUint) -> Result<Self, TryFromBigIntError<BigUint>> {
+                $Uint::try_from(&value).map_err(|e| e.with_original(value))
+            }
+        }
+
+        impl From<&$Uint> for BigUint {
+            #[inline]
+            fn from(value: &$Uint) -> Self {
+                biguint_from_u64_digits(value.0.iter().copied())
+            }
+        }
+
+        impl From<$Uint> for BigUint {
+            #[inline]
+            fn from(value: $Uint) -> Self {
+                Self::from(&value)
+            }
+        }
+    };
+}
+
+impl_uint_conversions!(U256, 4);
+impl_uint_conversions!(U512, 8);
+
+// Hashes have no numeric meaning of their own, so like `U256::from_big_endian`
+// we interpret their bytes in big-endian order.
+
+impl TryFrom<&BigUint> for H256 {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(value: &BigUint) -> Result<Self, TryFromBigIntError<()>> {
+        if value.bits() > 256 {
+            return Err(TryFromBigIntError::new(()));
+        }
+        let mut hash = H256::zero();
+        let bytes = value.to_bytes_be();
+        hash.0[32 - bytes.len()..].copy_from_slice(&bytes);
+        Ok(hash)
+    }
+}
+
+impl TryFrom<BigUint> for H256 {
+    type Error = TryFromBigIntError<BigUint>;
+
+    #[inline]
+    fn try_from(value: BigUint) -> Result<Self, TryFromBigIntError<BigUint>> {
+        H256::try_from(&value).map_err(|e| e.with_original(value))
+    }
+}
+
+impl From<&H256> for BigUint {
+    #[inline]
+    fn from(value: &H256) -> Self {
+        Self::from_bytes_be(value.as_bytes())
+    }
+}
+
+impl From<H256> for BigUint {
+    #[inline]
+    fn from(value: H256) -> Self {
+        Self::from(&value)
+    }
+}
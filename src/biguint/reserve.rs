@@ -0,0 +1,27 @@
+//! Allocation of the result buffers of the `try_` arithmetic methods.
+//!
+//! With the `try-reserve` feature, these buffers are reserved with `Vec::try_reserve_exact`, so
+//! an allocator that runs out of memory makes the method return a [`CapacityError`] instead of
+//! aborting the process. Without it, they are allocated like any other vector.
+//!
+//! Only the result is reserved this way. The temporaries of the subquadratic multiplication
+//! algorithms are still allocated normally, though they are smaller than the result.
+
+use crate::big_digit::BigDigit;
+use crate::CapacityError;
+
+use alloc::vec::Vec;
+
+/// Returns an empty vector with room for `len` digits.
+#[cfg_attr(not(feature = "try-reserve"), allow(clippy::unnecessary_wraps))]
+pub(super) fn digits(len: usize) -> Result<Vec<BigDigit>, CapacityError> {
+    #[cfg(feature = "try-reserve")]
+    {
+        let mut data = Vec::new();
+        data.try_reserve_exact(len)
+            .map_err(|_| CapacityError::alloc_failed())?;
+        Ok(data)
+    }
+    #[cfg(not(feature = "try-reserve"))]
+    Ok(Vec::with_capacity(len))
+}
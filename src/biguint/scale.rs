@@ -0,0 +1,125 @@
+#![cfg(feature = "scale")]
+#![cfg_attr(docsrs, doc(cfg(feature = "scale")))]
+
+//! SCALE codec support, encoding a value like the `Vec<u8>` of its minimal little-endian bytes:
+//! a compact length prefix followed by that many bytes, with no bytes at all for zero.
+//!
+//! The same encoding is used for [`StackBigUint`], whose fixed capacity also gives it a
+//! [`MaxEncodedLen`], so a runtime can store the bounded type while off-chain tools decode the
+//! same bytes as a `BigUint`.
+
+use super::{BigUint, StackBigUint};
+
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use num_integer::Integer;
+use num_traits::Zero;
+use parity_scale_codec::{
+    Compact, CompactLen, Decode, DecodeWithMemTracking, Encode, EncodeLike, Error, Input,
+    MaxEncodedLen, Output,
+};
+
+/// Returns the compact length prefix for `len` bytes.
+fn length_prefix(len: usize) -> Compact<u32> {
+    Compact(u32::try_from(len).expect("value too large for a SCALE length prefix"))
+}
+
+/// Returns the number of bytes in the minimal encoding of a value of `bits` bits.
+fn byte_len(bits: u64) -> usize {
+    usize::try_from(Integer::div_ceil(&bits, &8))
+        .expect("value too large for a SCALE length prefix")
+}
+
+/// Encodes minimal little-endian bytes, which are empty for zero.
+fn encode_bytes<W: Output + ?Sized>(bytes: &[u8], dest: &mut W) {
+    length_prefix(bytes.len()).encode_to(dest);
+    dest.write(bytes);
+}
+
+/// A most significant zero byte is rejected, so that every value has a single encoding.
+const NON_CANONICAL: &str = "non-canonical big integer: trailing zero byte";
+
+impl Encode for BigUint {
+    fn size_hint(&self) -> usize {
+        let len = byte_len(self.bits());
+        Compact::<u32>::compact_len(&length_prefix(len).0) + len
+    }
+
+    fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+        if self.is_zero() {
+            encode_bytes(&[], dest);
+        } else {
+            encode_bytes(&self.to_bytes_le(), dest);
+        }
+    }
+}
+
+impl EncodeLike for BigUint {}
+
+impl Decode for BigUint {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let bytes = Vec::<u8>::decode(input)?;
+        if bytes.last() == Some(&0) {
+            return Err(NON_CANONICAL.into());
+        }
+        Ok(Self::from_bytes_le(&bytes))
+    }
+}
+
+impl DecodeWithMemTracking for BigUint {}
+
+impl<const LIMBS: usize> Encode for StackBigUint<LIMBS> {
+    fn size_hint(&self) -> usize {
+        let len = byte_len(self.bits());
+        Compact::<u32>::compact_len(&length_prefix(len).0) + len
+    }
+
+    fn encode_to<W: Output + ?Sized>(&self, dest: &mut W) {
+        let len = byte_len(self.bits());
+        length_prefix(len).encode_to(dest);
+        for (i, limb) in self.to_u64_digits().iter().enumerate() {
+            let bytes = limb.to_le_bytes();
+            dest.write(&bytes[..Ord::min(8, len - i * 8)]);
+        }
+    }
+}
+
+impl<const LIMBS: usize> EncodeLike for StackBigUint<LIMBS> {}
+
+/// Values with more bytes than the capacity are rejected before any of them are read.
+impl<const LIMBS: usize> Decode for StackBigUint<LIMBS> {
+    fn decode<I: Input>(input: &mut I) -> Result<Self, Error> {
+        let len = Compact::<u32>::decode(input)?.0;
+        if u64::from(len) * 8 > Self::BITS {
+            return Err("big integer does not fit the capacity".into());
+        }
+        let len = usize::try_from(len).unwrap();
+
+        let mut limbs = [0u64; LIMBS];
+        let mut last = 0;
+        for (i, limb) in limbs
+            .iter_mut()
+            .enumerate()
+            .take(Integer::div_ceil(&len, &8))
+        {
+            let mut bytes = [0u8; 8];
+            let n = Ord::min(8, len - i * 8);
+            input.read(&mut bytes[..n])?;
+            last = bytes[n - 1];
+            *limb = u64::from_le_bytes(bytes);
+        }
+        if len > 0 && last == 0 {
+            return Err(NON_CANONICAL.into());
+        }
+        Ok(Self::from_u64_digits(&limbs).unwrap())
+    }
+}
+
+impl<const LIMBS: usize> DecodeWithMemTracking for StackBigUint<LIMBS> {}
+
+impl<const LIMBS: usize> MaxEncodedLen for StackBigUint<LIMBS> {
+    fn max_encoded_len() -> usize {
+        let len = LIMBS * 8;
+        Compact::<u32>::compact_len(&length_prefix(len).0) + len
+    }
+}
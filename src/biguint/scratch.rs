@@ -0,0 +1,103 @@
+//! Temporary digit buffers for the internals of multiplication and division.
+//!
+//! With the `scratch-pool` feature, released buffers are kept in a small thread-local pool
+//! and handed out again, so tight loops of medium-size operations don't have to go through
+//! the global allocator for every intermediate product or normalized divisor. Otherwise these
+//! are plain `Vec`s.
+
+use crate::big_digit::BigDigit;
+
+use alloc::vec::Vec;
+
+/// Returns a zeroed buffer of `len` digits.
+pub(super) fn take(len: usize) -> Vec<BigDigit> {
+    let mut buf = pool::take(len);
+    buf.resize(len, 0);
+    buf
+}
+
+/// Returns a buffer holding a copy of `digits`.
+pub(super) fn take_copy(digits: &[BigDigit]) -> Vec<BigDigit> {
+    let mut buf = pool::take(digits.len());
+    buf.extend_from_slice(digits);
+    buf
+}
+
+/// Releases a buffer obtained from [`take`] or [`take_copy`].
+pub(super) fn give(buf: Vec<BigDigit>) {
+    pool::give(buf);
+}
+
+#[cfg(not(feature = "scratch-pool"))]
+mod pool {
+    use super::{BigDigit, Vec};
+
+    #[inline]
+    pub(super) fn take(len: usize) -> Vec<BigDigit> {
+        count_stat!(allocations);
+        Vec::with_capacity(len)
+    }
+
+    #[inline]
+    pub(super) fn give(_buf: Vec<BigDigit>) {}
+}
+
+#[cfg(feature = "scratch-pool")]
+mod pool {
+    use super::{BigDigit, Vec};
+
+    use std::cell::RefCell;
+
+    /// The most buffers kept per thread, enough for the nesting of recursive multiplication.
+    const MAX_BUFFERS: usize = 16;
+
+    /// The largest buffer kept, in digits, so that one huge product doesn't pin its memory.
+    const MAX_DIGITS: usize = 1 << 16;
+
+    std::thread_local! {
+        static POOL: RefCell<Vec<Vec<BigDigit>>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn take(len: usize) -> Vec<BigDigit> {
+        let buf = POOL
+            .try_with(|pool| {
+                let mut pool = pool.try_borrow_mut().ok()?;
+                // Prefer the smallest buffer that fits, else grow the largest one.
+                let i = pool
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, buf)| buf.capacity() >= len)
+                    .min_by_key(|(_, buf)| buf.capacity())
+                    .or_else(|| pool.iter().enumerate().max_by_key(|(_, b)| b.capacity()))
+                    .map(|(i, _)| i)?;
+                Some(pool.swap_remove(i))
+            })
+            .ok()
+            .flatten();
+        match buf {
+            Some(mut buf) => {
+                buf.clear();
+                buf.reserve(len);
+                buf
+            }
+            None => {
+                count_stat!(allocations);
+                Vec::with_capacity(len)
+            }
+        }
+    }
+
+    pub(super) fn give(buf: Vec<BigDigit>) {
+        if buf.capacity() == 0 || buf.capacity() > MAX_DIGITS {
+            return;
+        }
+        // The pool may already be gone during thread teardown, in which case we just drop.
+        let _ = POOL.try_with(|pool| {
+            if let Ok(mut pool) = pool.try_borrow_mut() {
+                if pool.len() < MAX_BUFFERS {
+                    pool.push(buf);
+                }
+            }
+        });
+    }
+}
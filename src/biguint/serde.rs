@@ -4,10 +4,42 @@
 use super::{biguint_from_vec, BigUint};
 
 use alloc::vec::Vec;
+use core::convert::TryFrom;
+use core::sync::atomic::{AtomicUsize, Ordering};
 use core::{cmp, fmt, mem};
-use serde::de::{SeqAccess, Visitor};
+use num_integer::Integer;
+use serde::de::{DeserializeSeed, Error, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
+/// The default limit on the size of a deserialized value, 2<sup>30</sup> bits or 128 MiB.
+pub const DEFAULT_SERDE_MAX_BITS: u64 = 1 << 30;
+
+/// The limit of [`set_serde_max_bits`], counted in the 32-bit digits of the serialized form.
+static MAX_U32_DIGITS: AtomicUsize = AtomicUsize::new((DEFAULT_SERDE_MAX_BITS / 32) as usize);
+
+/// Returns the number of 32-bit digits needed for `bits` bits, saturating at `usize::MAX`.
+fn u32_digits(bits: u64) -> usize {
+    usize::try_from(Integer::div_ceil(&bits, &32)).unwrap_or(usize::MAX)
+}
+
+/// Sets the largest value, in bits, that deserializing a `BigInt` or `BigUint` accepts.
+///
+/// The limit applies to the whole program and is rounded up to a multiple of 32 bits. Larger
+/// values fail with an error as soon as their length is known, before their digits are stored.
+///
+/// The default is [`DEFAULT_SERDE_MAX_BITS`]. A single deserialization can use its own limit
+/// with [`BigUint::deserialize_with_max_bits`] or
+/// [`BigInt::deserialize_with_max_bits`](crate::BigInt::deserialize_with_max_bits).
+pub fn set_serde_max_bits(max_bits: u64) {
+    MAX_U32_DIGITS.store(u32_digits(max_bits), Ordering::Relaxed);
+}
+
+/// Returns the limit set by [`set_serde_max_bits`], in bits.
+#[must_use]
+pub fn serde_max_bits() -> u64 {
+    (MAX_U32_DIGITS.load(Ordering::Relaxed) as u64).saturating_mul(32)
+}
+
 // `cautious` is based on the function of the same name in `serde`, but specialized to `u32`:
 // https://github.com/dtolnay/serde/blob/399ef081ecc36d2f165ff1f6debdcbf6a1dc7efb/serde/src/private/size_hint.rs#L11-L22
 fn cautious(hint: Option<usize>) -> usize {
@@ -65,11 +97,72 @@ impl<'de> Deserialize<'de> for BigUint {
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_seq(U32Visitor)
+        deserializer.deserialize_seq(U32Visitor::default())
+    }
+}
+
+impl BigUint {
+    /// Deserializes a value like [`Deserialize`], but with a limit of `max_bits` bits in
+    /// place of the one set by [`set_serde_max_bits`]. The limit is rounded up to a multiple
+    /// of 32 bits.
+    ///
+    /// This has the signature of a deserialization function, which a field can use with
+    /// `#[serde(deserialize_with = "...")]` in a wrapper that chooses the limit.
+    pub fn deserialize_with_max_bits<'de, D>(
+        deserializer: D,
+        max_bits: u64,
+    ) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(U32Visitor::with_max_bits(max_bits))
+    }
+}
+
+/// Deserializes a `BigUint` with at most `max_digits` 32-bit digits.
+#[derive(Clone, Copy)]
+pub(crate) struct U32Visitor {
+    max_digits: usize,
+}
+
+impl Default for U32Visitor {
+    fn default() -> Self {
+        Self {
+            max_digits: MAX_U32_DIGITS.load(Ordering::Relaxed),
+        }
     }
 }
 
-struct U32Visitor;
+impl U32Visitor {
+    pub(crate) fn with_max_bits(max_bits: u64) -> Self {
+        Self {
+            max_digits: u32_digits(max_bits),
+        }
+    }
+
+    /// Fails if `len` digits would exceed the limit.
+    fn check<E: Error>(self, len: usize) -> Result<(), E> {
+        if len > self.max_digits {
+            Err(E::custom(format_args!(
+                "big integer exceeds the limit of {} bits",
+                (self.max_digits as u64).saturating_mul(32)
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for U32Visitor {
+    type Value = BigUint;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<BigUint, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_seq(self)
+    }
+}
 
 impl<'de> Visitor<'de> for U32Visitor {
     type Value = BigUint;
@@ -83,10 +176,12 @@ impl<'de> Visitor<'de> for U32Visitor {
         where
             S: SeqAccess<'de>,
         {
+            self.check(seq.size_hint().unwrap_or(0))?;
             let len = cautious(seq.size_hint());
             let mut data = Vec::with_capacity(len);
 
             while let Some(value) = seq.next_element::<u32>()? {
+                self.check(data.len() + 1)?;
                 data.push(value);
             }
 
@@ -98,15 +193,17 @@ impl<'de> Visitor<'de> for U32Visitor {
             S: SeqAccess<'de>,
         {
             use crate::big_digit::BigDigit;
-            use num_integer::Integer;
 
+            self.check(seq.size_hint().unwrap_or(0))?;
             let u32_len = cautious(seq.size_hint());
             let len = Integer::div_ceil(&u32_len, &2);
             let mut data = Vec::with_capacity(len);
 
             while let Some(lo) = seq.next_element::<u32>()? {
+                self.check(data.len() * 2 + 1)?;
                 let mut value = BigDigit::from(lo);
                 if let Some(hi) = seq.next_element::<u32>()? {
+                    self.check(data.len() * 2 + 2)?;
                     value |= BigDigit::from(hi) << 32;
                     data.push(value);
                 } else {
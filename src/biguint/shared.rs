@@ -0,0 +1,125 @@
+#![cfg(target_has_atomic = "ptr")]
+
+use super::BigUint;
+
+use alloc::sync::Arc;
+use core::borrow::Borrow;
+use core::fmt;
+use core::ops::Deref;
+
+/// A [`BigUint`] behind an [`Arc`], so that clones share one digit buffer.
+///
+/// Large constants such as moduli and generators are often passed to many threads or captured
+/// by many closures, and cloning a `BigUint` copies all of its digits each time. Cloning an
+/// `ArcBigUint` only bumps a reference count. Reads go through [`Deref`], so every `&BigUint`
+/// method and operator is available, and [`make_mut`](Self::make_mut) gives mutable access,
+/// copying the digits first only if they are shared.
+///
+/// ```
+/// use num_bigint::{ArcBigUint, BigUint};
+///
+/// let p = ArcBigUint::new(BigUint::from(2u8).pow(521u32) - 1u8);
+/// let q = p.clone();
+/// assert!(ArcBigUint::ptr_eq(&p, &q));
+/// assert_eq!(BigUint::from(3u8).modpow(&(&*p - 1u8), &p), BigUint::from(1u8));
+///
+/// let mut r = q.clone();
+/// *r.make_mut() += 2u8;
+/// assert!(!ArcBigUint::ptr_eq(&q, &r));
+/// assert_eq!(&*r - &*q, BigUint::from(2u8));
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ArcBigUint(Arc<BigUint>);
+
+impl ArcBigUint {
+    /// Moves `n` into a new shared allocation.
+    #[must_use]
+    #[inline]
+    pub fn new(n: BigUint) -> Self {
+        Self(Arc::new(n))
+    }
+
+    /// Returns a mutable reference to the value, first copying it into a new allocation if
+    /// other clones share it, as [`Arc::make_mut`] does.
+    #[inline]
+    pub fn make_mut(&mut self) -> &mut BigUint {
+        Arc::make_mut(&mut self.0)
+    }
+
+    /// Returns the value, copying it only if other clones share it.
+    #[must_use]
+    #[inline]
+    pub fn into_inner(self) -> BigUint {
+        Arc::try_unwrap(self.0).unwrap_or_else(|n| (*n).clone())
+    }
+
+    /// Returns `true` if both share the same allocation, not just equal values.
+    #[must_use]
+    #[inline]
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.0, &other.0)
+    }
+}
+
+impl Deref for ArcBigUint {
+    type Target = BigUint;
+
+    #[inline]
+    fn deref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl AsRef<BigUint> for ArcBigUint {
+    #[inline]
+    fn as_ref(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl Borrow<BigUint> for ArcBigUint {
+    #[inline]
+    fn borrow(&self) -> &BigUint {
+        &self.0
+    }
+}
+
+impl From<BigUint> for ArcBigUint {
+    #[inline]
+    fn from(n: BigUint) -> Self {
+        Self::new(n)
+    }
+}
+
+impl From<Arc<BigUint>> for ArcBigUint {
+    #[inline]
+    fn from(n: Arc<BigUint>) -> Self {
+        Self(n)
+    }
+}
+
+impl From<ArcBigUint> for Arc<BigUint> {
+    #[inline]
+    fn from(n: ArcBigUint) -> Self {
+        n.0
+    }
+}
+
+impl From<ArcBigUint> for BigUint {
+    #[inline]
+    fn from(n: ArcBigUint) -> Self {
+        n.into_inner()
+    }
+}
+
+impl fmt::Debug for ArcBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.0, f)
+    }
+}
+
+impl fmt::Display for ArcBigUint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&*self.0, f)
+    }
+}
@@ -1,9 +1,13 @@
+use super::reserve;
 use super::{biguint_from_vec, BigUint};
 
-use crate::big_digit;
+use crate::big_digit::{self, BigDigit};
+use crate::{CapacityError, RoundingMode};
 
 use alloc::borrow::Cow;
 use alloc::vec::Vec;
+use core::cmp::{self, Ordering};
+use core::convert::TryFrom;
 use core::mem;
 use core::ops::{Shl, ShlAssign, Shr, ShrAssign};
 use num_traits::{PrimInt, Zero};
@@ -15,13 +19,60 @@ fn biguint_shl<T: PrimInt>(n: Cow<'_, BigUint>, shift: T) -> BigUint {
         return n.into_owned();
     }
     let bits = T::from(big_digit::BITS).unwrap();
+    let total = shift
+        .to_u64()
+        .map_or(u64::MAX, |s| s.saturating_add(n.bits()));
+    crate::limits::check(total);
     let digits = (shift / bits).to_usize().expect("capacity overflow");
     let shift = (shift % bits).to_u8().unwrap();
     biguint_shl2(n, digits, shift)
 }
 
+impl BigUint {
+    /// Returns `self << shift` without checking the size limit of [`limits`](crate::limits),
+    /// for the shifts inside operations whose results are bounded by their inputs.
+    pub(super) fn shl_unchecked(&self, shift: u64) -> Self {
+        biguint_shl_unchecked(Cow::Borrowed(self), shift)
+    }
+
+    /// Returns `self << shift` like [`shl_unchecked`](Self::shl_unchecked), reusing the digits
+    /// of `self`.
+    pub(super) fn into_shl_unchecked(self, shift: u64) -> Self {
+        biguint_shl_unchecked(Cow::Owned(self), shift)
+    }
+}
+
+fn biguint_shl_unchecked(n: Cow<'_, BigUint>, shift: u64) -> BigUint {
+    if n.is_zero() {
+        return n.into_owned();
+    }
+    let bits = u64::from(big_digit::BITS);
+    let digits = usize::try_from(shift / bits).expect("capacity overflow");
+    biguint_shl2(n, digits, u8::try_from(shift % bits).unwrap())
+}
+
+/// Returns `n << shift`, or an error if the result would be over the size limit.
+pub(super) fn try_shl(n: &BigUint, shift: u64) -> Result<BigUint, CapacityError> {
+    if n.is_zero() {
+        return Ok(BigUint::ZERO);
+    }
+    let total = shift
+        .checked_add(n.bits())
+        .ok_or_else(CapacityError::overflow)?;
+    crate::limits::try_check(total)?;
+    let bits = u64::from(big_digit::BITS);
+    let digits = usize::try_from(shift / bits).map_err(|_| CapacityError::overflow())?;
+    let len = digits
+        .checked_add(n.data.len() + 1)
+        .ok_or_else(CapacityError::overflow)?;
+    let mut data = reserve::digits(len)?;
+    data.resize(digits, 0);
+    data.extend(n.data.iter());
+    Ok(shl_bits(data, digits, u8::try_from(shift % bits).unwrap()))
+}
+
 fn biguint_shl2(n: Cow<'_, BigUint>, digits: usize, shift: u8) -> BigUint {
-    let mut data = if digits == 0 {
+    let data = if digits == 0 {
         n.into_owned().data
     } else {
         let len = digits.saturating_add(n.data.len() + 1);
@@ -30,7 +81,11 @@ fn biguint_shl2(n: Cow<'_, BigUint>, digits: usize, shift: u8) -> BigUint {
         data.extend(n.data.iter());
         data
     };
+    shl_bits(data, digits, shift)
+}
 
+/// Shifts the digits of `data` above the first `digits` left by `shift` bits.
+fn shl_bits(mut data: Vec<BigDigit>, digits: usize, shift: u8) -> BigUint {
     if shift > 0 {
         let mut carry = 0;
         let carry_shift = big_digit::BITS - shift;
@@ -86,6 +141,57 @@ fn biguint_shr2(n: Cow<'_, BigUint>, digits: usize, shift: u8) -> BigUint {
     biguint_from_vec(data)
 }
 
+/// Returns `(n mod 2^k, n >> k)`, copying each digit of `n` once.
+pub(super) fn split_at_bit(n: &BigUint, k: u64) -> (BigUint, BigUint) {
+    let digits = usize::try_from(k / u64::from(big_digit::BITS)).unwrap_or(usize::MAX);
+    let shift = (k % u64::from(big_digit::BITS)) as u8;
+
+    let mut low = n.data[..cmp::min(digits, n.data.len())].to_vec();
+    if shift > 0 {
+        if let Some(&d) = n.data.get(digits) {
+            low.push(d & ((1 << shift) - 1));
+        }
+    }
+    let high = biguint_shr2(Cow::Borrowed(n), digits, shift);
+    (biguint_from_vec(low), high)
+}
+
+/// Returns `low + (high << k)` for `low < 2^k`, with the low digits written straight into the
+/// zeros left by the shift.
+pub(super) fn from_parts(low: &BigUint, high: &BigUint, k: u64) -> BigUint {
+    assert!(low.bits() <= k, "the low part does not fit in {k} bits");
+    let mut n = biguint_shl(Cow::Borrowed(high), k);
+    if n.data.len() < low.data.len() {
+        n.data.resize(low.data.len(), 0);
+    }
+    for (d, &l) in n.data.iter_mut().zip(&low.data) {
+        *d |= l;
+    }
+    n
+}
+
+/// Returns `n >> shift`, rounded by `mode` as the magnitude of a value with the given sign.
+pub(crate) fn shr_round(n: &BigUint, shift: u64, negative: bool, mode: RoundingMode) -> BigUint {
+    let q = biguint_shr(Cow::Borrowed(n), shift);
+    let zeros = match n.trailing_zeros() {
+        Some(zeros) if zeros < shift => zeros,
+        _ => return q,
+    };
+    // The discarded bits are nonzero, so compare them with one half by their top bit.
+    let fraction = if !n.bit(shift - 1) {
+        Ordering::Less
+    } else if zeros == shift - 1 {
+        Ordering::Equal
+    } else {
+        Ordering::Greater
+    };
+    if mode.rounds_away(negative, q.bit(0), fraction) {
+        q + 1u8
+    } else {
+        q
+    }
+}
+
 macro_rules! impl_shift {
     (@ref $Shx:ident :: $shx:ident, $ShxAssign:ident :: $shx_assign:ident, $rhs:ty) => {
         impl $Shx<&$rhs> for BigUint {
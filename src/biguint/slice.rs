@@ -0,0 +1,165 @@
+use super::iter::U64Digits;
+use super::{biguint_from_vec, cmp_slice, BigUint};
+
+use crate::big_digit::{self, BigDigit};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Bound, RangeBounds};
+
+/// A borrowed, read-only view of a window of the 64-bit limbs of a [`BigUint`], created by
+/// [`BigUint::limb_window`].
+///
+/// The view is the value of the limbs inside the window, as if the limbs below it had been
+/// shifted out and those above it masked off, but nothing is copied until it is converted to
+/// an owned [`BigUint`]. Views compare by value, with each other and with [`BigUint`].
+///
+/// ```
+/// use num_bigint::BigUint;
+///
+/// let n = (BigUint::from(7u8) << 128u32) + (BigUint::from(5u8) << 64u32) + 3u8;
+/// assert_eq!(n.limb_window(1..2), BigUint::from(5u8));
+/// assert_eq!(n.limb_window(1..), (BigUint::from(7u8) << 64u32) + 5u8);
+/// assert!(n.limb_window(..1) < n.limb_window(1..2));
+/// assert_eq!(n.limb_window(3..).bits(), 0);
+/// ```
+#[derive(Clone, Copy)]
+pub struct BigUintSlice<'a> {
+    /// Least significant digit first, without trailing zeros.
+    data: &'a [BigDigit],
+}
+
+impl<'a> BigUintSlice<'a> {
+    pub(super) fn new<R: RangeBounds<usize>>(n: &'a BigUint, range: R) -> Self {
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i.checked_add(1).expect("limb window start overflows"),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i.checked_add(1).expect("limb window end overflows"),
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => usize::MAX,
+        };
+        assert!(
+            start <= end,
+            "limb window starts at {start} but ends at {end}"
+        );
+
+        // Each 64-bit limb is this many digits.
+        let scale = 64 / big_digit::BITS as usize;
+        let len = n.data.len();
+        let start = Ord::min(start.saturating_mul(scale), len);
+        let end = Ord::min(end.saturating_mul(scale), len);
+        let mut data = &n.data[start..end];
+        while let Some((&0, rest)) = data.split_last() {
+            data = rest;
+        }
+        Self { data }
+    }
+
+    /// Determines the fewest bits necessary to express the value of the view.
+    #[must_use]
+    pub fn bits(&self) -> u64 {
+        self.data.last().map_or(0, |top| {
+            self.data.len() as u64 * u64::from(big_digit::BITS) - u64::from(top.leading_zeros())
+        })
+    }
+
+    /// Returns `true` if every limb in the window is zero.
+    #[must_use]
+    #[inline]
+    pub const fn is_zero(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// Returns an iterator of the `u64` limbs of the view, least significant first, without
+    /// trailing zeros.
+    #[must_use]
+    #[inline]
+    pub fn iter_u64_digits(&self) -> U64Digits<'a> {
+        U64Digits::new(self.data)
+    }
+
+    /// Copies the view into an owned [`BigUint`].
+    #[must_use]
+    pub fn to_biguint(&self) -> BigUint {
+        biguint_from_vec(self.data.to_vec())
+    }
+}
+
+impl<'a> From<&'a BigUint> for BigUintSlice<'a> {
+    #[inline]
+    fn from(n: &'a BigUint) -> Self {
+        Self { data: &n.data }
+    }
+}
+
+impl From<BigUintSlice<'_>> for BigUint {
+    #[inline]
+    fn from(view: BigUintSlice<'_>) -> Self {
+        view.to_biguint()
+    }
+}
+
+impl PartialEq for BigUintSlice<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.data == other.data
+    }
+}
+impl Eq for BigUintSlice<'_> {}
+
+impl PartialOrd for BigUintSlice<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for BigUintSlice<'_> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        cmp_slice(self.data, other.data)
+    }
+}
+
+impl PartialEq<BigUint> for BigUintSlice<'_> {
+    #[inline]
+    fn eq(&self, other: &BigUint) -> bool {
+        self.data == &other.data[..]
+    }
+}
+
+impl PartialEq<BigUintSlice<'_>> for BigUint {
+    #[inline]
+    fn eq(&self, other: &BigUintSlice<'_>) -> bool {
+        other == self
+    }
+}
+
+impl PartialOrd<BigUint> for BigUintSlice<'_> {
+    #[inline]
+    fn partial_cmp(&self, other: &BigUint) -> Option<Ordering> {
+        Some(cmp_slice(self.data, &other.data))
+    }
+}
+
+impl PartialOrd<BigUintSlice<'_>> for BigUint {
+    #[inline]
+    fn partial_cmp(&self, other: &BigUintSlice<'_>) -> Option<Ordering> {
+        Some(cmp_slice(&self.data, other.data))
+    }
+}
+
+impl fmt::Debug for BigUintSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl fmt::Display for BigUintSlice<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
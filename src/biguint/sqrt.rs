@@ -0,0 +1,58 @@
+//! Square root with remainder by Zimmermann's recursive "Karatsuba square root".
+//!
+//! Reference:
+//! Brent & Zimmermann, Modern Computer Arithmetic, v0.5.9, Algorithm 1.12
+//!
+//! Each level takes the root of the top half of the input recursively, then finishes with one
+//! division of half the size and one squaring of a quarter of the size, so the whole root costs
+//! a small multiple of a multiplication instead of several full-size Newton divisions.
+
+use super::BigUint;
+
+use num_integer::{Integer, Roots};
+
+/// Below this many bits, the Newton iteration of [`Roots::sqrt`] is faster.
+pub(super) const NEWTON_THRESHOLD_BITS: u64 = 4096;
+
+/// Returns `(s, r)` with `s = floor(sqrt(n))` and `r = n - s²`.
+pub(super) fn sqrt_rem(n: &BigUint) -> (BigUint, BigUint) {
+    let bits = n.bits();
+    if bits <= NEWTON_THRESHOLD_BITS {
+        let s = Roots::sqrt(n);
+        let r = n - &s * &s;
+        return (s, r);
+    }
+
+    // Normalize to `4k` bits with the top quarter at least `2^(k-2)`, by an even shift so
+    // the root only needs shifting back by half.
+    let k = (bits + 3) / 4;
+    let shift = (4 * k - bits) & !1;
+    if shift == 0 {
+        return sqrt_rem_normalized(n, k);
+    }
+    let (s, _) = sqrt_rem_normalized(&n.shl_unchecked(shift), k);
+    let s = s >> (shift / 2);
+    let r = n - &s * &s;
+    (s, r)
+}
+
+/// The core step, for `n < 2^4k` with `n >= 2^(4k-2)`.
+#[allow(clippy::many_single_char_names)]
+fn sqrt_rem_normalized(n: &BigUint, k: u64) -> (BigUint, BigUint) {
+    let mask = BigUint::mask(k);
+    let a0 = n & &mask;
+    let a1 = (n >> k) & &mask;
+    let (s1, r1) = sqrt_rem(&(n >> (2 * k)));
+
+    let (q, u) = (r1.into_shl_unchecked(k) | a1).div_rem(&s1.shl_unchecked(1));
+    let s = s1.into_shl_unchecked(k) + &q;
+    let t = u.into_shl_unchecked(k) | a0;
+    let q2 = &q * &q;
+    if t >= q2 {
+        (s, t - q2)
+    } else {
+        // The root is overestimated by at most one.
+        let r = t + s.shl_unchecked(1) - 1u32 - q2;
+        (s - 1u32, r)
+    }
+}
@@ -0,0 +1,518 @@
+use super::{biguint_from_u64_digits, BigUint};
+
+use crate::TryFromBigIntError;
+
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+use core::fmt;
+use core::hash;
+use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Rem, RemAssign, Sub, SubAssign};
+use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, One, Zero};
+
+/// A fixed-capacity unsigned integer of `LIMBS` 64-bit limbs, stored inline without any heap
+/// allocation.
+///
+/// This offers the core arithmetic of [`BigUint`] for code that must not allocate, such as
+/// interrupt handlers or heapless embedded targets (note that the crate itself still links
+/// `alloc`). The `checked_*` methods return `None` when a result does not fit in the capacity,
+/// and the operators panic in that case, just like primitive integers in debug builds.
+///
+/// ```
+/// use num_bigint::{BigUint, StackBigUint};
+///
+/// let x = StackBigUint::<4>::from(u64::MAX);
+/// let y = x * x + x;
+/// assert_eq!(y.to_string(), "340282366920938463444927863358058659840");
+/// assert_eq!(BigUint::from(y), BigUint::from(u64::MAX) * (BigUint::from(u64::MAX) + 1u8));
+/// assert_eq!(StackBigUint::<1>::from(u64::MAX).checked_add(&StackBigUint::ONE), None);
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq)]
+pub struct StackBigUint<const LIMBS: usize> {
+    /// Least significant limb first.
+    limbs: [u64; LIMBS],
+}
+
+impl<const LIMBS: usize> StackBigUint<LIMBS> {
+    /// A constant `StackBigUint` with value 0.
+    pub const ZERO: Self = Self { limbs: [0; LIMBS] };
+
+    /// A constant `StackBigUint` with value 1, or 0 if there are no limbs at all.
+    pub const ONE: Self = {
+        let mut limbs = [0; LIMBS];
+        if LIMBS > 0 {
+            limbs[0] = 1;
+        }
+        Self { limbs }
+    };
+
+    /// The number of bits that can be stored.
+    pub const BITS: u64 = LIMBS as u64 * 64;
+
+    /// Creates a value from `u64` limbs, least significant first, or returns `None` if any
+    /// non-zero limbs do not fit.
+    #[must_use]
+    pub fn from_u64_digits(digits: &[u64]) -> Option<Self> {
+        let (lo, hi) = digits.split_at(Ord::min(digits.len(), LIMBS));
+        if hi.iter().any(|&d| d != 0) {
+            return None;
+        }
+        let mut limbs = [0; LIMBS];
+        limbs[..lo.len()].copy_from_slice(lo);
+        Some(Self { limbs })
+    }
+
+    /// Returns the `u64` limbs, least significant first, without trailing zeros.
+    #[must_use]
+    pub fn to_u64_digits(&self) -> &[u64] {
+        &self.limbs[..self.len()]
+    }
+
+    /// Returns the number of limbs in use.
+    fn len(&self) -> usize {
+        self.limbs
+            .iter()
+            .rposition(|&d| d != 0)
+            .map_or(0, |i| i + 1)
+    }
+
+    /// Determines the fewest bits necessary to express the value.
+    #[must_use]
+    pub fn bits(&self) -> u64 {
+        match self.len() {
+            0 => 0,
+            n => n as u64 * 64 - u64::from(self.limbs[n - 1].leading_zeros()),
+        }
+    }
+
+    /// Returns `true` if the value is zero.
+    #[must_use]
+    pub fn is_zero(&self) -> bool {
+        self.limbs.iter().all(|&d| d == 0)
+    }
+
+    /// Returns `self + other`, or `None` if the sum does not fit.
+    #[must_use]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        let mut limbs = [0; LIMBS];
+        if limbs::add(&mut limbs, &self.limbs, &other.limbs) {
+            None
+        } else {
+            Some(Self { limbs })
+        }
+    }
+
+    /// Returns `self - other`, or `None` if `other` is larger.
+    #[must_use]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        match self.overflowing_sub(other) {
+            (diff, false) => Some(diff),
+            (_, true) => None,
+        }
+    }
+
+    /// Returns `self * other`, or `None` if the product does not fit.
+    #[must_use]
+    pub fn checked_mul(&self, other: &Self) -> Option<Self> {
+        let (a, b) = (self.len(), other.len());
+        let mut limbs = [0; LIMBS];
+        for (i, &x) in self.limbs[..a].iter().enumerate() {
+            if x == 0 {
+                continue;
+            }
+            if i + b > LIMBS {
+                return None;
+            }
+            let carry = limbs::mac(&mut limbs[i..i + b], &other.limbs[..b], x);
+            if carry != 0 {
+                *limbs.get_mut(i + b)? = carry;
+            }
+        }
+        Some(Self { limbs })
+    }
+
+    /// Returns `(self / other, self % other)`, or `None` if `other` is zero.
+    #[must_use]
+    pub fn checked_div_rem(&self, other: &Self) -> Option<(Self, Self)> {
+        if other.is_zero() {
+            return None;
+        }
+        if other.len() == 1 {
+            let (q, r) = self.div_rem_u64(other.limbs[0]);
+            return Some((q, Self::from(r)));
+        }
+        // Binary long division, which is fine for the small fixed sizes this is meant for.
+        let mut q = Self::ZERO;
+        let mut r = Self::ZERO;
+        for i in (0..self.len() * 64).rev() {
+            // `r < other` before each shift, so this cannot overflow while `other` has a
+            // free high bit, and the top bit is carried separately otherwise.
+            let top = r.limbs[LIMBS - 1] >> 63;
+            r.shl1(self.bit(i));
+            if top != 0 || r >= *other {
+                r = r.overflowing_sub(other).0;
+                q.limbs[i / 64] |= 1 << (i % 64);
+            }
+        }
+        Some((q, r))
+    }
+
+    /// Returns `self / other`, or `None` if `other` is zero.
+    #[must_use]
+    pub fn checked_div(&self, other: &Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(q, _)| q)
+    }
+
+    /// Returns `self % other`, or `None` if `other` is zero.
+    #[must_use]
+    pub fn checked_rem(&self, other: &Self) -> Option<Self> {
+        self.checked_div_rem(other).map(|(_, r)| r)
+    }
+
+    /// Returns `self ^ exponent`, or `None` if the result does not fit.
+    #[must_use]
+    pub fn checked_pow(&self, mut exponent: u32) -> Option<Self> {
+        let mut base = *self;
+        let mut acc = Self::ONE;
+        while exponent > 0 {
+            if exponent & 1 == 1 {
+                acc = acc.checked_mul(&base)?;
+            }
+            exponent >>= 1;
+            if exponent > 0 {
+                base = base.checked_mul(&base)?;
+            }
+        }
+        Some(acc)
+    }
+
+    const fn bit(&self, i: usize) -> bool {
+        (self.limbs[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn shl1(&mut self, low: bool) {
+        let mut carry = u64::from(low);
+        for limb in &mut self.limbs {
+            let next = *limb >> 63;
+            *limb = (*limb << 1) | carry;
+            carry = next;
+        }
+    }
+
+    fn overflowing_sub(&self, other: &Self) -> (Self, bool) {
+        let mut limbs = [0; LIMBS];
+        let borrow = limbs::sub(&mut limbs, &self.limbs, &other.limbs);
+        (Self { limbs }, borrow)
+    }
+
+    fn div_rem_u64(&self, divisor: u64) -> (Self, u64) {
+        let mut limbs = self.limbs;
+        let rem = limbs::div_rem(&mut limbs, divisor);
+        (Self { limbs }, rem)
+    }
+
+    fn write_decimal(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        const TEN19: u64 = 10_000_000_000_000_000_000;
+        if self.len() <= 1 {
+            return write!(f, "{}", self.limbs.first().copied().unwrap_or(0));
+        }
+        let (q, r) = self.div_rem_u64(TEN19);
+        q.write_decimal(f)?;
+        write!(f, "{r:019}")
+    }
+}
+
+// The crate's low-level digit routines already work on borrowed slices, whatever owns them, so
+// they are shared here directly rather than through a storage trait. The higher algorithms of
+// `BigUint`, like Toom-3 and Knuth division, allocate scratch space and stay `Vec`-backed.
+cfg_digit!(
+    /// Limb arithmetic for targets whose digits are narrower than a limb.
+    mod limbs {
+        /// Writes `a + b` to `r`, returning the carry.
+        pub(super) fn add(r: &mut [u64], a: &[u64], b: &[u64]) -> bool {
+            let mut carry = false;
+            for ((r, &a), &b) in r.iter_mut().zip(a).zip(b) {
+                let (sum, c1) = a.overflowing_add(b);
+                let (sum, c2) = sum.overflowing_add(u64::from(carry));
+                *r = sum;
+                carry = c1 || c2;
+            }
+            carry
+        }
+
+        /// Writes `a - b` to `r`, returning the borrow.
+        pub(super) fn sub(r: &mut [u64], a: &[u64], b: &[u64]) -> bool {
+            let mut borrow = false;
+            for ((r, &a), &b) in r.iter_mut().zip(a).zip(b) {
+                let (diff, b1) = a.overflowing_sub(b);
+                let (diff, b2) = diff.overflowing_sub(u64::from(borrow));
+                *r = diff;
+                borrow = b1 || b2;
+            }
+            borrow
+        }
+
+        /// Adds `b * c` to `acc`, of the same length, returning the carry out.
+        #[allow(clippy::cast_possible_truncation)]
+        pub(super) fn mac(acc: &mut [u64], b: &[u64], c: u64) -> u64 {
+            let mut carry = 0u128;
+            for (a, &b) in acc.iter_mut().zip(b) {
+                carry += u128::from(*a) + u128::from(b) * u128::from(c);
+                *a = carry as u64;
+                carry >>= 64;
+            }
+            carry as u64
+        }
+
+        /// Divides `a` by `d` in place, returning the remainder.
+        #[allow(clippy::cast_possible_truncation)]
+        pub(super) fn div_rem(a: &mut [u64], d: u64) -> u64 {
+            let mut rem = 0u64;
+            for limb in a.iter_mut().rev() {
+                let acc = (u128::from(rem) << 64) | u128::from(*limb);
+                *limb = (acc / u128::from(d)) as u64;
+                rem = (acc % u128::from(d)) as u64;
+            }
+            rem
+        }
+    }
+
+    /// Limb arithmetic on 64-bit targets, where a limb is a digit and the crate's own digit
+    /// routines apply.
+    mod limbs {
+        use crate::big_digit;
+        use crate::biguint::division::div_rem_digits_in_place;
+        use crate::biguint::lowlevel::{add_n, sub_n};
+        use crate::biguint::multiplication::mac_with_carry;
+
+        /// Writes `a + b` to `r`, returning the carry.
+        pub(super) fn add(r: &mut [u64], a: &[u64], b: &[u64]) -> bool {
+            add_n(r, a, b) != 0
+        }
+
+        /// Writes `a - b` to `r`, returning the borrow.
+        pub(super) fn sub(r: &mut [u64], a: &[u64], b: &[u64]) -> bool {
+            sub_n(r, a, b) != 0
+        }
+
+        /// Adds `b * c` to `acc`, of the same length, returning the carry out.
+        pub(super) fn mac(acc: &mut [u64], b: &[u64], c: u64) -> u64 {
+            let mut carry = 0;
+            for (a, &b) in acc.iter_mut().zip(b) {
+                *a = mac_with_carry(*a, b, c, &mut carry);
+            }
+            big_digit::from_doublebigdigit(carry).1
+        }
+
+        /// Divides `a` by `d` in place, returning the remainder.
+        pub(super) fn div_rem(a: &mut [u64], d: u64) -> u64 {
+            div_rem_digits_in_place(a, d)
+        }
+    }
+);
+
+impl<const LIMBS: usize> Default for StackBigUint<LIMBS> {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+impl<const LIMBS: usize> PartialOrd for StackBigUint<LIMBS> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<const LIMBS: usize> Ord for StackBigUint<LIMBS> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
+    }
+}
+
+/// Hashes like a [`BigUint`] of the same value, so the two can be looked up interchangeably.
+impl<const LIMBS: usize> hash::Hash for StackBigUint<LIMBS> {
+    fn hash<H: hash::Hasher>(&self, state: &mut H) {
+        match *self.to_u64_digits() {
+            [] => 0u64.hash(state),
+            [n] => n.hash(state),
+            ref digits => {
+                state.write_usize(digits.len());
+                for &digit in digits {
+                    state.write_u64(digit);
+                }
+            }
+        }
+    }
+}
+
+impl<const LIMBS: usize> fmt::Debug for StackBigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(self, f)
+    }
+}
+
+impl<const LIMBS: usize> fmt::Display for StackBigUint<LIMBS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_decimal(f)
+    }
+}
+
+impl<const LIMBS: usize> From<u64> for StackBigUint<LIMBS> {
+    /// Creates a value from a `u64`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `LIMBS` is zero and the value is non-zero.
+    fn from(n: u64) -> Self {
+        Self::from_u64_digits(&[n]).expect("capacity overflow")
+    }
+}
+
+impl<const LIMBS: usize> From<StackBigUint<LIMBS>> for BigUint {
+    fn from(n: StackBigUint<LIMBS>) -> Self {
+        biguint_from_u64_digits(n.limbs)
+    }
+}
+
+impl<const LIMBS: usize> From<&StackBigUint<LIMBS>> for BigUint {
+    fn from(n: &StackBigUint<LIMBS>) -> Self {
+        biguint_from_u64_digits(n.limbs)
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<&BigUint> for StackBigUint<LIMBS> {
+    type Error = TryFromBigIntError<()>;
+
+    fn try_from(value: &BigUint) -> Result<Self, Self::Error> {
+        let digits = value.iter_u64_digits();
+        if digits.len() > LIMBS {
+            return Err(TryFromBigIntError::new(()));
+        }
+        let mut limbs = [0; LIMBS];
+        for (limb, digit) in limbs.iter_mut().zip(digits) {
+            *limb = digit;
+        }
+        Ok(Self { limbs })
+    }
+}
+
+impl<const LIMBS: usize> TryFrom<BigUint> for StackBigUint<LIMBS> {
+    type Error = TryFromBigIntError<BigUint>;
+
+    fn try_from(value: BigUint) -> Result<Self, Self::Error> {
+        Self::try_from(&value).map_err(|e| e.with_original(value))
+    }
+}
+
+macro_rules! impl_stack_binop {
+    ($Op:ident, $op:ident, $OpAssign:ident, $op_assign:ident, $checked:ident, $msg:expr) => {
+        impl<const LIMBS: usize> $Op for StackBigUint<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, other: Self) -> Self {
+                self.$checked(&other).expect($msg)
+            }
+        }
+
+        impl<const LIMBS: usize> $Op<&Self> for StackBigUint<LIMBS> {
+            type Output = Self;
+
+            #[inline]
+            fn $op(self, other: &Self) -> Self {
+                self.$checked(other).expect($msg)
+            }
+        }
+
+        impl<const LIMBS: usize> $OpAssign for StackBigUint<LIMBS> {
+            #[inline]
+            fn $op_assign(&mut self, other: Self) {
+                *self = self.$checked(&other).expect($msg);
+            }
+        }
+
+        impl<const LIMBS: usize> $OpAssign<&Self> for StackBigUint<LIMBS> {
+            #[inline]
+            fn $op_assign(&mut self, other: &Self) {
+                *self = self.$checked(other).expect($msg);
+            }
+        }
+    };
+}
+
+impl_stack_binop!(
+    Add,
+    add,
+    AddAssign,
+    add_assign,
+    checked_add,
+    "capacity overflow"
+);
+impl_stack_binop!(
+    Sub,
+    sub,
+    SubAssign,
+    sub_assign,
+    checked_sub,
+    "attempt to subtract with overflow"
+);
+impl_stack_binop!(
+    Mul,
+    mul,
+    MulAssign,
+    mul_assign,
+    checked_mul,
+    "capacity overflow"
+);
+impl_stack_binop!(
+    Div,
+    div,
+    DivAssign,
+    div_assign,
+    checked_div,
+    "attempt to divide by zero"
+);
+impl_stack_binop!(
+    Rem,
+    rem,
+    RemAssign,
+    rem_assign,
+    checked_rem,
+    "attempt to divide by zero"
+);
+
+macro_rules! impl_stack_checked {
+    ($Checked:ident, $checked:ident) => {
+        impl<const LIMBS: usize> $Checked for StackBigUint<LIMBS> {
+            #[inline]
+            fn $checked(&self, other: &Self) -> Option<Self> {
+                Self::$checked(self, other)
+            }
+        }
+    };
+}
+
+impl_stack_checked!(CheckedAdd, checked_add);
+impl_stack_checked!(CheckedSub, checked_sub);
+impl_stack_checked!(CheckedMul, checked_mul);
+impl_stack_checked!(CheckedDiv, checked_div);
+
+impl<const LIMBS: usize> Zero for StackBigUint<LIMBS> {
+    #[inline]
+    fn zero() -> Self {
+        Self::ZERO
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        Self::is_zero(self)
+    }
+}
+
+impl<const LIMBS: usize> One for StackBigUint<LIMBS> {
+    #[inline]
+    fn one() -> Self {
+        Self::ONE
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "ufmt")]
+#![cfg_attr(docsrs, doc(cfg(feature = "ufmt")))]
+
+use super::division::div_rem_u64_in_place;
+use super::BigUint;
+
+use alloc::vec::Vec;
+use ufmt::{uDebug, uDisplay, uWrite, Formatter};
+
+/// The largest power of ten in a `u64`, so each chunk holds this many decimal digits.
+const CHUNK_DIGITS: usize = 19;
+const CHUNK: u64 = 10_000_000_000_000_000_000;
+
+/// Writes `n` in decimal, one 19-digit chunk at a time from a buffer on the stack.
+///
+/// Only the chunks are collected on the heap, at less than half the size of the full string.
+fn write_decimal<W>(n: &BigUint, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+where
+    W: uWrite + ?Sized,
+{
+    let mut n = n.clone();
+    let mut chunks = Vec::new();
+    loop {
+        chunks.push(div_rem_u64_in_place(&mut n, CHUNK));
+        if n.data.is_empty() {
+            break;
+        }
+    }
+
+    let mut chunks = chunks.iter().rev();
+    let top = *chunks.next().unwrap();
+    ufmt::uwrite!(f, "{}", top)?;
+
+    let mut buf = [0; CHUNK_DIGITS];
+    for &chunk in chunks {
+        let mut chunk = chunk;
+        for b in buf.iter_mut().rev() {
+            *b = b'0' + (chunk % 10) as u8;
+            chunk /= 10;
+        }
+        f.write_str(core::str::from_utf8(&buf).unwrap())?;
+    }
+    Ok(())
+}
+
+impl uDisplay for BigUint {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        write_decimal(self, f)
+    }
+}
+
+impl uDebug for BigUint {
+    fn fmt<W>(&self, f: &mut Formatter<'_, W>) -> Result<(), W::Error>
+    where
+        W: uWrite + ?Sized,
+    {
+        write_decimal(self, f)
+    }
+}
@@ -0,0 +1,42 @@
+use core::ops::Range;
+
+/// Combines the values of `leaf` over `range` with `combine`, splitting the range in halves
+/// recursively.
+///
+/// This is the binary splitting pattern behind products of many factors and the evaluation of
+/// hypergeometric series: `combine` always joins two neighbouring partial results of similar
+/// size, so the expensive work lands in a few balanced multiplications rather than a long
+/// chain of lopsided ones. The left half always covers the lower indices, so `combine` need
+/// not be commutative, only associative.
+///
+/// Panics if `range` is empty.
+///
+/// ```
+/// use num_bigint::{binary_split, BigUint};
+///
+/// // 20! as a balanced product tree.
+/// let factorial = binary_split(1..21, |i| BigUint::from(i), |a, b| a * b);
+/// assert_eq!(factorial, BigUint::from(2_432_902_008_176_640_000u64));
+/// ```
+pub fn binary_split<T, L, C>(range: Range<usize>, mut leaf: L, mut combine: C) -> T
+where
+    L: FnMut(usize) -> T,
+    C: FnMut(T, T) -> T,
+{
+    assert!(!range.is_empty(), "binary split of an empty range");
+    split(range.start, range.end, &mut leaf, &mut combine)
+}
+
+fn split<T, L, C>(start: usize, end: usize, leaf: &mut L, combine: &mut C) -> T
+where
+    L: FnMut(usize) -> T,
+    C: FnMut(T, T) -> T,
+{
+    if end - start == 1 {
+        return leaf(start);
+    }
+    let mid = start + (end - start) / 2;
+    let left = split(start, mid, leaf, combine);
+    let right = split(mid, end, leaf, combine);
+    combine(left, right)
+}
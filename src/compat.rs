@@ -0,0 +1,150 @@
+//! Byte conversions that match the big integer types of other languages exactly, for values
+//! that cross a language boundary as raw bytes.
+//!
+//! The formats differ in byte order, in whether the sign is stored, and in how zero is written,
+//! so each language has its own module named after the functions it mirrors:
+//!
+//! | Language | Function | Byte order | Sign | Zero |
+//! |----------|----------|------------|------|------|
+//! | Java | `BigInteger.toByteArray` | big-endian | two's complement | `[0]` |
+//! | .NET | `BigInteger.ToByteArray` | little-endian | two's complement | `[0]` |
+//! | Go | `big.Int.Bytes` | big-endian | dropped | `[]` |
+//! | Python | `int.to_bytes` | either | optional | zero-filled |
+//!
+//! Java and .NET use the fewest bytes that keep the sign bit correct, so a positive value may
+//! gain a leading zero byte. Python always writes a caller-chosen length.
+//!
+//! ```
+//! use num_bigint::compat::{dotnet, java};
+//! use num_bigint::BigInt;
+//!
+//! let n = BigInt::from(128);
+//! assert_eq!(java::to_byte_array(&n), [0x00, 0x80]);
+//! assert_eq!(dotnet::to_byte_array(&n), [0x80, 0x00]);
+//! ```
+
+/// Java's `java.math.BigInteger`.
+pub mod java {
+    use crate::BigInt;
+
+    use alloc::vec::Vec;
+
+    /// Returns the bytes of `BigInteger.toByteArray()`: the minimal big-endian two's
+    /// complement, with zero as a single zero byte.
+    #[must_use]
+    pub fn to_byte_array(n: &BigInt) -> Vec<u8> {
+        n.to_signed_bytes_be()
+    }
+
+    /// Reads bytes as the `BigInteger(byte[])` constructor does, or returns `None` for an
+    /// empty slice, which Java rejects with a `NumberFormatException`.
+    #[must_use]
+    pub fn from_byte_array(bytes: &[u8]) -> Option<BigInt> {
+        if bytes.is_empty() {
+            None
+        } else {
+            Some(BigInt::from_signed_bytes_be(bytes))
+        }
+    }
+}
+
+/// .NET's `System.Numerics.BigInteger`.
+pub mod dotnet {
+    use crate::BigInt;
+
+    use alloc::vec::Vec;
+
+    /// Returns the bytes of `BigInteger.ToByteArray()`: the minimal little-endian two's
+    /// complement, with zero as a single zero byte.
+    #[must_use]
+    pub fn to_byte_array(n: &BigInt) -> Vec<u8> {
+        n.to_signed_bytes_le()
+    }
+
+    /// Reads bytes as the `BigInteger(byte[])` constructor does, with an empty slice as zero.
+    #[must_use]
+    pub fn from_byte_array(bytes: &[u8]) -> BigInt {
+        BigInt::from_signed_bytes_le(bytes)
+    }
+}
+
+/// Go's `math/big.Int`.
+pub mod go {
+    use crate::{BigInt, BigUint, Sign};
+
+    use alloc::vec::Vec;
+
+    /// Returns the bytes of `Int.Bytes()`: the big-endian absolute value, with no bytes at all
+    /// for zero. The sign is lost, as in Go.
+    #[must_use]
+    pub fn bytes(n: &BigInt) -> Vec<u8> {
+        if n.sign() == Sign::NoSign {
+            Vec::new()
+        } else {
+            n.magnitude().to_bytes_be()
+        }
+    }
+
+    /// Reads bytes as `Int.SetBytes` does, as a big-endian unsigned value.
+    #[must_use]
+    pub fn set_bytes(bytes: &[u8]) -> BigInt {
+        BigInt::from(BigUint::from_bytes_be(bytes))
+    }
+}
+
+/// Python's `int`.
+pub mod python {
+    use crate::{BigInt, Sign};
+
+    use alloc::vec::Vec;
+    use num_traits::Signed;
+
+    /// The `byteorder` argument of `int.to_bytes` and `int.from_bytes`.
+    #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+    pub enum ByteOrder {
+        /// `"big"`, most significant byte first.
+        Big,
+        /// `"little"`, least significant byte first.
+        Little,
+    }
+
+    /// Returns the bytes of `int.to_bytes(length, byteorder, signed=signed)`, or `None` where
+    /// Python raises an `OverflowError`: when the value does not fit in `length` bytes, or is
+    /// negative without `signed`.
+    #[must_use]
+    pub fn to_bytes(n: &BigInt, length: usize, order: ByteOrder, signed: bool) -> Option<Vec<u8>> {
+        if n.is_negative() && !signed {
+            return None;
+        }
+        let mut bytes = if signed {
+            n.to_signed_bytes_le()
+        } else {
+            n.magnitude().to_bytes_le()
+        };
+        // Both give a single zero byte for zero, which must still fit in no bytes at all.
+        if bytes == [0] {
+            bytes.clear();
+        }
+        if bytes.len() > length {
+            return None;
+        }
+        let fill = if n.is_negative() { 0xff } else { 0 };
+        bytes.resize(length, fill);
+        if order == ByteOrder::Big {
+            bytes.reverse();
+        }
+        Some(bytes)
+    }
+
+    /// Reads bytes as `int.from_bytes(bytes, byteorder, signed=signed)` does, with an empty
+    /// slice as zero.
+    #[must_use]
+    pub fn from_bytes(bytes: &[u8], order: ByteOrder, signed: bool) -> BigInt {
+        match (order, signed) {
+            (ByteOrder::Big, true) => BigInt::from_signed_bytes_be(bytes),
+            (ByteOrder::Little, true) => BigInt::from_signed_bytes_le(bytes),
+            (ByteOrder::Big, false) => BigInt::from_bytes_be(Sign::Plus, bytes),
+            (ByteOrder::Little, false) => BigInt::from_bytes_le(Sign::Plus, bytes),
+        }
+    }
+}
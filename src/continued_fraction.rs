@@ -0,0 +1,147 @@
+use crate::BigInt;
+
+use core::iter::FusedIterator;
+use core::mem;
+use num_integer::Integer;
+use num_traits::{One, Signed, Zero};
+
+/// An iterator over the partial quotients of the continued fraction of a rational number,
+/// created by [`BigInt::continued_fraction`].
+///
+/// The first quotient is the floor of the number, so it may be zero or negative, and all
+/// later ones are positive. The expansion is finite, and is the shorter of the two regular
+/// forms, so its last quotient is greater than one whenever there is more than one quotient.
+#[derive(Clone, Debug)]
+pub struct ContinuedFraction {
+    numer: BigInt,
+    denom: BigInt,
+}
+
+impl ContinuedFraction {
+    pub(crate) fn new(numer: &BigInt, denom: &BigInt) -> Self {
+        assert!(!denom.is_zero(), "attempt to divide by zero");
+        let (numer, denom) = if denom.is_negative() {
+            (-numer, -denom)
+        } else {
+            (numer.clone(), denom.clone())
+        };
+        Self { numer, denom }
+    }
+
+    /// Returns an iterator over the convergents of the remaining expansion.
+    #[must_use]
+    #[inline]
+    pub fn convergents(self) -> Convergents<Self> {
+        Convergents::new(self)
+    }
+}
+
+impl Iterator for ContinuedFraction {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<BigInt> {
+        if self.denom.is_zero() {
+            return None;
+        }
+        let (q, r) = self.numer.div_mod_floor(&self.denom);
+        self.numer = mem::replace(&mut self.denom, r);
+        Some(q)
+    }
+}
+
+impl FusedIterator for ContinuedFraction {}
+
+/// An iterator over the convergents `p / q` of a continued fraction, as `(p, q)` pairs in
+/// lowest terms, computed from its partial quotients.
+///
+/// Any sequence of quotients can be used, such as the periodic expansion of a quadratic
+/// irrational when solving Pell's equation.
+///
+/// ```
+/// use num_bigint::{BigInt, Convergents};
+///
+/// // √2 = [1; 2, 2, 2, ...]
+/// let quotients = core::iter::once(1).chain(core::iter::repeat(2)).map(BigInt::from);
+/// let (p, q) = Convergents::new(quotients).nth(5).unwrap();
+/// assert_eq!((p, q), (BigInt::from(99), BigInt::from(70)));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Convergents<I> {
+    quotients: I,
+    /// The previous two convergents, starting from the conventional `1/0` and `0/1`.
+    p: (BigInt, BigInt),
+    q: (BigInt, BigInt),
+}
+
+impl<I: Iterator<Item = BigInt>> Convergents<I> {
+    /// Creates an iterator over the convergents of the given partial quotients.
+    pub fn new<T>(quotients: T) -> Self
+    where
+        T: IntoIterator<IntoIter = I>,
+    {
+        Self {
+            quotients: quotients.into_iter(),
+            p: (BigInt::zero(), BigInt::one()),
+            q: (BigInt::one(), BigInt::zero()),
+        }
+    }
+}
+
+impl<I: Iterator<Item = BigInt>> Iterator for Convergents<I> {
+    type Item = (BigInt, BigInt);
+
+    fn next(&mut self) -> Option<(BigInt, BigInt)> {
+        let a = self.quotients.next()?;
+        let p = &a * &self.p.1 + &self.p.0;
+        let q = a * &self.q.1 + &self.q.0;
+        self.p.0 = mem::replace(&mut self.p.1, p.clone());
+        self.q.0 = mem::replace(&mut self.q.1, q.clone());
+        Some((p, q))
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.quotients.size_hint()
+    }
+}
+
+impl<I: FusedIterator<Item = BigInt>> FusedIterator for Convergents<I> {}
+
+/// Returns the fraction closest to `numer / denom` with a positive denominator of at most
+/// `max_denom`, in lowest terms.
+pub fn best_rational_approx(
+    numer: &BigInt,
+    denom: &BigInt,
+    max_denom: &BigInt,
+) -> (BigInt, BigInt) {
+    assert!(
+        *max_denom >= BigInt::one(),
+        "the denominator bound must be positive"
+    );
+    let mut quotients = ContinuedFraction::new(numer, denom);
+    let (numer, denom) = (quotients.numer.clone(), quotients.denom.clone());
+
+    // Walk the convergents while their denominators stay in bounds.
+    let (mut p0, mut q0) = (BigInt::zero(), BigInt::one());
+    let (mut p1, mut q1) = (BigInt::one(), BigInt::zero());
+    for a in quotients.by_ref() {
+        let q2 = &a * &q1 + &q0;
+        if q2 > *max_denom {
+            // The best approximation is either the last convergent, or the semiconvergent
+            // with the largest multiple of it that still fits.
+            let k = (max_denom - &q0) / &q1;
+            let ps = &p0 + &k * &p1;
+            let qs = &q0 + &k * &q1;
+            let error = |p: &BigInt, q: &BigInt| (&numer * q - p * &denom).abs();
+            if error(&ps, &qs) * &q1 < error(&p1, &q1) * &qs {
+                return (ps, qs);
+            }
+            return (p1, q1);
+        }
+        let p2 = a * &p1 + &p0;
+        p0 = mem::replace(&mut p1, p2);
+        q0 = mem::replace(&mut q1, q2);
+    }
+    // The number itself fits.
+    (p1, q1)
+}
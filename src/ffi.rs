@@ -0,0 +1,285 @@
+//! A C ABI for [`BigInt`] values, for building bindings in other languages.
+//!
+//! Values are passed as opaque `*mut BigInt` handles which must be released with
+//! [`num_bigint_free`]. Every function that creates a new value returns a fresh handle, or null
+//! if the operation failed, e.g. due to a parse error or division by zero. Input handles are
+//! only borrowed, never consumed.
+//!
+//! The `ffi` feature must be enabled to use this. To produce a shared library, build with
+//! `cargo rustc --release --features ffi --crate-type cdylib`.
+//!
+//! ```c
+//! typedef struct BigInt BigInt;
+//!
+//! BigInt *num_bigint_from_i64(int64_t value);
+//! BigInt *num_bigint_from_u64(uint64_t value);
+//! BigInt *num_bigint_from_str(const char *s, uint32_t radix);
+//! BigInt *num_bigint_from_signed_bytes_be(const uint8_t *bytes, size_t len);
+//! BigInt *num_bigint_clone(const BigInt *x);
+//! void num_bigint_free(BigInt *x);
+//!
+//! char *num_bigint_to_str(const BigInt *x, uint32_t radix);
+//! void num_bigint_str_free(char *s);
+//! uint8_t *num_bigint_to_signed_bytes_be(const BigInt *x, size_t *len);
+//! void num_bigint_bytes_free(uint8_t *bytes, size_t len);
+//!
+//! int num_bigint_sign(const BigInt *x);
+//! int num_bigint_cmp(const BigInt *a, const BigInt *b);
+//! BigInt *num_bigint_neg(const BigInt *x);
+//! BigInt *num_bigint_add(const BigInt *a, const BigInt *b);
+//! BigInt *num_bigint_sub(const BigInt *a, const BigInt *b);
+//! BigInt *num_bigint_mul(const BigInt *a, const BigInt *b);
+//! BigInt *num_bigint_div(const BigInt *a, const BigInt *b);
+//! BigInt *num_bigint_rem(const BigInt *a, const BigInt *b);
+//! BigInt *num_bigint_pow(const BigInt *x, uint32_t exponent);
+//! BigInt *num_bigint_modpow(const BigInt *x, const BigInt *exponent, const BigInt *modulus);
+//! ```
+#![cfg(feature = "ffi")]
+#![cfg_attr(docsrs, doc(cfg(feature = "ffi")))]
+
+use crate::{BigInt, Sign};
+
+use alloc::boxed::Box;
+use core::ptr;
+use core::slice;
+use num_traits::{Num, Signed, Zero};
+use std::ffi::{CStr, CString};
+use std::os::raw::{c_char, c_int};
+
+fn into_raw(x: BigInt) -> *mut BigInt {
+    Box::into_raw(Box::new(x))
+}
+
+/// Creates a new value from a signed integer.
+#[no_mangle]
+pub extern "C" fn num_bigint_from_i64(value: i64) -> *mut BigInt {
+    into_raw(BigInt::from(value))
+}
+
+/// Creates a new value from an unsigned integer.
+#[no_mangle]
+pub extern "C" fn num_bigint_from_u64(value: u64) -> *mut BigInt {
+    into_raw(BigInt::from(value))
+}
+
+/// Parses a new value from a nul-terminated string in the given radix, which must be in the
+/// range `2...36`. Returns null if the string is not a valid number or the radix is invalid.
+///
+/// # Safety
+///
+/// `s` must point to a valid nul-terminated string.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_from_str(s: *const c_char, radix: u32) -> *mut BigInt {
+    if s.is_null() || !(2..=36).contains(&radix) {
+        return ptr::null_mut();
+    }
+    let s = match CStr::from_ptr(s).to_str() {
+        Ok(s) => s,
+        Err(_) => return ptr::null_mut(),
+    };
+    match BigInt::from_str_radix(s, radix) {
+        Ok(x) => into_raw(x),
+        Err(_) => ptr::null_mut(),
+    }
+}
+
+/// Creates a new value from big-endian two's complement bytes.
+///
+/// # Safety
+///
+/// `bytes` must point to `len` readable bytes, or may be null if `len` is zero.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_from_signed_bytes_be(
+    bytes: *const u8,
+    len: usize,
+) -> *mut BigInt {
+    if len == 0 {
+        return into_raw(BigInt::zero());
+    }
+    into_raw(BigInt::from_signed_bytes_be(slice::from_raw_parts(
+        bytes, len,
+    )))
+}
+
+/// Creates a copy of a value.
+///
+/// # Safety
+///
+/// `x` must be a live handle returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_clone(x: *const BigInt) -> *mut BigInt {
+    into_raw((*x).clone())
+}
+
+/// Releases a value. Null is ignored.
+///
+/// # Safety
+///
+/// `x` must be null or a live handle returned by this module, and is invalid afterward.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_free(x: *mut BigInt) {
+    if !x.is_null() {
+        drop(Box::from_raw(x));
+    }
+}
+
+/// Formats a value as a nul-terminated string in the given radix, which must be in the range
+/// `2...36`, or returns null if the radix is invalid. The string must be released with
+/// [`num_bigint_str_free`].
+///
+/// # Safety
+///
+/// `x` must be a live handle returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_to_str(x: *const BigInt, radix: u32) -> *mut c_char {
+    if !(2..=36).contains(&radix) {
+        return ptr::null_mut();
+    }
+    // Digits never contain a nul byte.
+    CString::new((*x).to_str_radix(radix)).unwrap().into_raw()
+}
+
+/// Releases a string returned by [`num_bigint_to_str`]. Null is ignored.
+///
+/// # Safety
+///
+/// `s` must be null or a live string returned by [`num_bigint_to_str`].
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_str_free(s: *mut c_char) {
+    if !s.is_null() {
+        drop(CString::from_raw(s));
+    }
+}
+
+/// Returns the big-endian two's complement bytes of a value, writing their count to `len`.
+/// The bytes must be released with [`num_bigint_bytes_free`].
+///
+/// # Safety
+///
+/// `x` must be a live handle returned by this module, and `len` must be writable.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_to_signed_bytes_be(
+    x: *const BigInt,
+    len: *mut usize,
+) -> *mut u8 {
+    let bytes = (*x).to_signed_bytes_be().into_boxed_slice();
+    *len = bytes.len();
+    Box::into_raw(bytes).cast::<u8>()
+}
+
+/// Releases bytes returned by [`num_bigint_to_signed_bytes_be`]. Null is ignored.
+///
+/// # Safety
+///
+/// `bytes` must be null or live bytes returned by [`num_bigint_to_signed_bytes_be`], along with
+/// the same `len` that was reported.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_bytes_free(bytes: *mut u8, len: usize) {
+    if !bytes.is_null() {
+        drop(Box::from_raw(ptr::slice_from_raw_parts_mut(bytes, len)));
+    }
+}
+
+/// Returns -1, 0, or 1 for negative, zero, or positive values.
+///
+/// # Safety
+///
+/// `x` must be a live handle returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_sign(x: *const BigInt) -> c_int {
+    match (*x).sign() {
+        Sign::Minus => -1,
+        Sign::NoSign => 0,
+        Sign::Plus => 1,
+    }
+}
+
+/// Returns -1, 0, or 1 if `a` is less than, equal to, or greater than `b`.
+///
+/// # Safety
+///
+/// Both `a` and `b` must be live handles returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_cmp(a: *const BigInt, b: *const BigInt) -> c_int {
+    (*a).cmp(&*b) as c_int
+}
+
+/// Returns `-x`.
+///
+/// # Safety
+///
+/// `x` must be a live handle returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_neg(x: *const BigInt) -> *mut BigInt {
+    into_raw(-&*x)
+}
+
+macro_rules! ffi_binop {
+    ($(#[$doc:meta])* $name:ident, |$a:ident, $b:ident| $body:expr) => {
+        $(#[$doc])*
+        ///
+        /// # Safety
+        ///
+        /// Both `a` and `b` must be live handles returned by this module.
+        #[no_mangle]
+        pub unsafe extern "C" fn $name(a: *const BigInt, b: *const BigInt) -> *mut BigInt {
+            let ($a, $b) = (&*a, &*b);
+            $body
+        }
+    };
+}
+
+ffi_binop!(
+    /// Returns `a + b`.
+    num_bigint_add,
+    |a, b| into_raw(a + b)
+);
+ffi_binop!(
+    /// Returns `a - b`.
+    num_bigint_sub,
+    |a, b| into_raw(a - b)
+);
+ffi_binop!(
+    /// Returns `a * b`.
+    num_bigint_mul,
+    |a, b| into_raw(a * b)
+);
+ffi_binop!(
+    /// Returns `a / b`, rounded toward zero, or null if `b` is zero.
+    num_bigint_div,
+    |a, b| if b.is_zero() { ptr::null_mut() } else { into_raw(a / b) }
+);
+ffi_binop!(
+    /// Returns `a % b`, with the sign of `a`, or null if `b` is zero.
+    num_bigint_rem,
+    |a, b| if b.is_zero() { ptr::null_mut() } else { into_raw(a % b) }
+);
+
+/// Returns `x` raised to the power of `exponent`.
+///
+/// # Safety
+///
+/// `x` must be a live handle returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_pow(x: *const BigInt, exponent: u32) -> *mut BigInt {
+    into_raw((*x).pow(exponent))
+}
+
+/// Returns `(x ^ exponent) mod modulus`, like [`BigInt::modpow`], or null if the exponent is
+/// negative or the modulus is zero.
+///
+/// # Safety
+///
+/// All of `x`, `exponent`, and `modulus` must be live handles returned by this module.
+#[no_mangle]
+pub unsafe extern "C" fn num_bigint_modpow(
+    x: *const BigInt,
+    exponent: *const BigInt,
+    modulus: *const BigInt,
+) -> *mut BigInt {
+    let (exponent, modulus) = (&*exponent, &*modulus);
+    if exponent.is_negative() || modulus.is_zero() {
+        return ptr::null_mut();
+    }
+    into_raw((*x).modpow(exponent, modulus))
+}
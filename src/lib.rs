@@ -78,6 +78,10 @@
 //! Note that you must use the version of `rand` that `num-bigint` is compatible
 //! with: `0.8`.
 //!
+//! For cryptographic use without the rest of `rand`, the `getrandom` feature instead adds
+//! `BigUint::random_secure` and `BigUint::random_secure_below`, which read entropy directly from
+//! the operating system via [`getrandom`] and return its errors.
+//!
 //! ### Arbitrary Big Integers
 //!
 //! `num-bigint` supports `arbitrary` and `quickcheck` features to implement
@@ -89,7 +93,114 @@
 //! The `serde` feature adds implementations of [`Serialize`][serde::Serialize] and
 //! [`Deserialize`][serde::Deserialize] for both `BigInt` and `BigUint`. Their serialized data is
 //! generated portably, regardless of platform differences like the internal digit size.
+//! Deserialization rejects values over [`DEFAULT_SERDE_MAX_BITS`], or the limit set with
+//! [`set_serde_max_bits`], so that a hostile payload cannot make it allocate without bound;
+//! `deserialize_with_max_bits` applies a different limit to a single value.
+//!
+//! ### Fixed-Width Integers
+//!
+//! The `crypto-bigint` feature implements `TryFrom` conversions from `BigInt` and `BigUint` to
+//! [`crypto_bigint::Uint`], failing if the value doesn't fit, and `From` conversions back again.
+//! Similarly, the `primitive-types` feature converts `BigUint` to and from
+//! [`primitive_types::U256`], [`U512`][primitive_types::U512], and [`H256`][primitive_types::H256]
+//! (as big-endian bytes), and the `ethnum` feature converts to and from [`ethnum::U256`].
+//! For Arrow and Parquet data, the `arrow-buffer` feature converts `BigInt` to and from
+//! [`arrow_buffer::i256`], the little-endian two's complement integer behind `Decimal256`.
+//!
+//! ### Bit Sequences
+//!
+//! The `bitvec` feature converts `BigUint` to and from [`bitvec::vec::BitVec`] and
+//! [`BitSlice`][bitvec::slice::BitSlice] of either bit order, with the first bit the least
+//! significant.
+//!
+//! ### GMP Interoperability
+//!
+//! The `gmp` feature adds `from_mpz` and `assign_to_mpz` methods to both `BigInt` and `BigUint`,
+//! copying limbs directly to and from a [`gmp_mpfr_sys::gmp::mpz_t`]. This lets values move
+//! between this crate and GMP-based code like `rug` without a round-trip through strings.
+//!
+//! ### C Interface
+//!
+//! The `ffi` feature adds the [`ffi`] module, exporting a C ABI over opaque `BigInt` handles so
+//! that bindings for other languages can share one implementation. It requires `std`.
+//!
+//! ### Embedded Logging
+//!
+//! The `defmt` feature implements [`defmt::Format`] for both `BigInt` and `BigUint`, so they can
+//! be logged over RTT and similar transports. Values are written as hexadecimal limbs with their
+//! bit length, since a decimal conversion is too heavy to run on the target.
+//!
+//! ### `ufmt` Formatting
+//!
+//! The `ufmt` feature implements [`ufmt::uDisplay`] and [`ufmt::uDebug`] for both `BigInt` and
+//! `BigUint`, printing them in decimal for `no_std` firmware that avoids the `core::fmt`
+//! machinery.
+//!
+//! ### RLP Encoding
+//!
+//! `BigUint::to_rlp` and `BigUint::from_rlp` always handle Ethereum's canonical scalar encoding,
+//! and the `alloy-rlp` feature implements [`alloy_rlp::Encodable`] and [`alloy_rlp::Decodable`]
+//! for `BigUint` on top of them.
+//!
+//! ### SCALE Codec
+//!
+//! The `scale` feature implements the `parity-scale-codec` traits `Encode` and `Decode` for
+//! `BigUint` and `StackBigUint`, writing the minimal little-endian bytes after a compact length
+//! prefix. `StackBigUint` also implements `MaxEncodedLen`, for storage that must be bounded.
+//!
+//! ### OpenSSL Interoperability
+//!
+//! The `openssl` feature adds `from_bn` and `to_bn` methods to both `BigInt` and `BigUint`,
+//! converting to and from [`openssl::bn::BigNum`].
 //!
+//! ### Parallel Conversion
+//!
+//! The `rayon` feature splits the radix conversion of very large values, as in `to_str_radix`
+//! and `Display`, into halves that are converted on separate [`rayon`] threads. The output is
+//! identical to the single-threaded conversion. It requires `std`.
+//!
+//! ### Scratch Buffer Pool
+//!
+//! The `scratch-pool` feature keeps the temporary buffers of large multiplications, and the
+//! normalized divisors of long divisions, in a small thread-local pool for reuse, which can
+//! speed up tight loops of medium-size operations. Buffers that become part of a result are
+//! still allocated normally. It requires `std`.
+//!
+//! ### Operation Counters
+//!
+//! The `stats` feature counts allocations, digit products, and the calls to each
+//! multiplication, division and exponentiation algorithm in global atomic counters, which
+//! [`stats()`] returns and [`reset_stats`] clears. This can help to tune thresholds and to find
+//! accidentally quadratic behavior. It needs a target with atomic pointer-size integers.
+//!
+//! ### Tracing
+//!
+//! The `tracing` feature enters a [`tracing`] span at debug level around each expensive
+//! operation: multiplications and divisions with more than a few dozen digits, modular
+//! exponentiation, and conversions of large values to and from a non-power-of-two radix. The
+//! spans record the operand sizes and the chosen algorithm, so a subscriber can show where
+//! the time goes. Without the feature they are compiled out entirely.
+//!
+//! ### Reference Algorithms
+//!
+//! The `reference` feature adds the [`reference`] module, with naive schoolbook versions of
+//! multiplication, division and radix conversion, for testing the optimized algorithms
+//! against a simple oracle.
+//!
+//! ### Fallible Allocation
+//!
+//! The `try-reserve` feature makes the `try_` arithmetic methods, such as
+//! [`BigUint::try_mul`], reserve their result with `Vec::try_reserve_exact`, so that running
+//! out of memory returns a [`CapacityError`] of kind [`CapacityErrorKind::AllocFailed`] instead
+//! of aborting. This matters most on WASM and embedded targets, where memory is small and an
+//! abort takes the whole program down.
+//!
+//! ### Localized Formatting
+//!
+//! The `locale` feature adds the [`locale`] module, whose [`NumberFormat`][locale::NumberFormat]
+//! writes and strictly reads `BigUint`, `BigInt` and `Scaled` values with the group separator,
+//! group sizes, decimal separator and minus sign supplied by the caller, such as `1.234.567,89`
+//! or the Indian `1,23,45,678`.
 //!
 //! ## Compatibility
 //!
@@ -106,6 +217,7 @@ extern crate alloc;
 #[cfg(feature = "std")]
 extern crate std;
 
+use core::cmp::Ordering;
 use core::fmt;
 
 #[macro_use]
@@ -114,6 +226,18 @@ mod macros;
 mod bigint;
 mod bigrand;
 mod biguint;
+mod binary_split;
+mod continued_fraction;
+mod modring;
+mod range;
+mod scaled;
+mod stats;
+
+pub mod compat;
+pub mod ffi;
+pub mod limits;
+pub mod locale;
+pub mod reference;
 
 #[cfg(target_pointer_width = "32")]
 type UsizePromotion = u32;
@@ -134,14 +258,16 @@ pub struct ParseBigIntError {
 enum BigIntErrorKind {
     Empty,
     InvalidDigit,
+    TooLarge,
 }
 
 impl ParseBigIntError {
     const fn __description(&self) -> &'static str {
-        use crate::BigIntErrorKind::{Empty, InvalidDigit};
+        use crate::BigIntErrorKind::{Empty, InvalidDigit, TooLarge};
         match self.kind {
             Empty => "cannot parse integer from empty string",
             InvalidDigit => "invalid digit found in string",
+            TooLarge => "number exceeds the size limit",
         }
     }
 
@@ -156,6 +282,12 @@ impl ParseBigIntError {
             kind: BigIntErrorKind::InvalidDigit,
         }
     }
+
+    const fn too_large() -> Self {
+        Self {
+            kind: BigIntErrorKind::TooLarge,
+        }
+    }
 }
 
 impl fmt::Display for ParseBigIntError {
@@ -172,19 +304,166 @@ impl std::error::Error for ParseBigIntError {
     }
 }
 
+/// The error returned by a `_cancellable` operation when its cancellation token is set before
+/// it finishes.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("big integer operation was cancelled")
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for Cancelled {}
+
+/// The error returned by the `try_` arithmetic methods, such as [`BigUint::try_mul`], when the
+/// result would be too large.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct CapacityError {
+    kind: CapacityErrorKind,
+}
+
+/// Why a result was too large, as returned by [`CapacityError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CapacityErrorKind {
+    /// The result would exceed the limit set in [`limits`].
+    LimitExceeded,
+    /// The result would be larger than any value that can be stored on the target.
+    Overflow,
+    /// The memory for the result could not be allocated, which is only detected with the
+    /// `try-reserve` feature.
+    AllocFailed,
+}
+
+impl CapacityError {
+    const fn limit_exceeded() -> Self {
+        Self {
+            kind: CapacityErrorKind::LimitExceeded,
+        }
+    }
+
+    const fn overflow() -> Self {
+        Self {
+            kind: CapacityErrorKind::Overflow,
+        }
+    }
+
+    #[cfg(feature = "try-reserve")]
+    const fn alloc_failed() -> Self {
+        Self {
+            kind: CapacityErrorKind::AllocFailed,
+        }
+    }
+
+    const fn __description(self) -> &'static str {
+        match self.kind {
+            CapacityErrorKind::LimitExceeded => "big integer result exceeds the size limit",
+            CapacityErrorKind::Overflow => "big integer result exceeds the capacity of the target",
+            CapacityErrorKind::AllocFailed => "memory allocation for big integer result failed",
+        }
+    }
+
+    /// Returns why the result was too large.
+    #[must_use]
+    pub const fn kind(&self) -> CapacityErrorKind {
+        self.kind
+    }
+}
+
+impl fmt::Display for CapacityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for CapacityError {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
 /// The error type returned when a checked conversion regarding big integer fails.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
 pub struct TryFromBigIntError<T> {
     original: T,
+    kind: TryFromBigIntErrorKind,
+}
+
+/// Why a checked conversion regarding big integer failed, as returned by
+/// [`TryFromBigIntError::kind`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum TryFromBigIntErrorKind {
+    /// The value is beyond the range of the target type.
+    Overflow,
+    /// The value is negative and the target type is unsigned.
+    Negative,
+    /// The value is zero and the target type is a `NonZero` integer.
+    Zero,
 }
 
 impl<T> TryFromBigIntError<T> {
     const fn new(original: T) -> Self {
-        Self { original }
+        Self {
+            original,
+            kind: TryFromBigIntErrorKind::Overflow,
+        }
+    }
+
+    const fn negative(original: T) -> Self {
+        Self {
+            original,
+            kind: TryFromBigIntErrorKind::Negative,
+        }
+    }
+
+    const fn zero(original: T) -> Self {
+        Self {
+            original,
+            kind: TryFromBigIntErrorKind::Zero,
+        }
+    }
+
+    /// Replaces the original value, keeping the kind of failure.
+    fn with_original<U>(self, original: U) -> TryFromBigIntError<U> {
+        TryFromBigIntError {
+            original,
+            kind: self.kind,
+        }
     }
 
     const fn __description(&self) -> &'static str {
-        "out of range conversion regarding big integer attempted"
+        match self.kind {
+            TryFromBigIntErrorKind::Overflow => {
+                "out of range conversion regarding big integer attempted"
+            }
+            TryFromBigIntErrorKind::Negative => {
+                "cannot convert negative big integer to unsigned integer"
+            }
+            TryFromBigIntErrorKind::Zero => "cannot convert zero big integer to non-zero integer",
+        }
+    }
+
+    /// Returns why the conversion failed.
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, TryFromBigIntErrorKind};
+    /// use std::convert::TryFrom;
+    /// use std::num::NonZeroU8;
+    ///
+    /// let kind = |n: i32| NonZeroU8::try_from(&BigInt::from(n)).unwrap_err().kind();
+    /// assert_eq!(kind(256), TryFromBigIntErrorKind::Overflow);
+    /// assert_eq!(kind(-1), TryFromBigIntErrorKind::Negative);
+    /// assert_eq!(kind(0), TryFromBigIntErrorKind::Zero);
+    /// ```
+    pub const fn kind(&self) -> TryFromBigIntErrorKind {
+        self.kind
     }
 
     /// Extract the original value, if available. The value will be available
@@ -211,18 +490,156 @@ impl<T> fmt::Display for TryFromBigIntError<T> {
     }
 }
 
+/// The error type returned when converting a float that is not an exact integer value.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromFloatError {
+    kind: FloatErrorKind,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum FloatErrorKind {
+    NotFinite,
+    Fractional,
+    Negative,
+}
+
+impl TryFromFloatError {
+    const fn __description(self) -> &'static str {
+        use crate::FloatErrorKind::{Fractional, Negative, NotFinite};
+        match self.kind {
+            NotFinite => "cannot convert NaN or infinite float to integer",
+            Fractional => "cannot convert float with a fractional part to integer",
+            Negative => "cannot convert negative float to unsigned integer",
+        }
+    }
+
+    const fn not_finite() -> Self {
+        Self {
+            kind: FloatErrorKind::NotFinite,
+        }
+    }
+
+    const fn fractional() -> Self {
+        Self {
+            kind: FloatErrorKind::Fractional,
+        }
+    }
+
+    const fn negative() -> Self {
+        Self {
+            kind: FloatErrorKind::Negative,
+        }
+    }
+}
+
+impl fmt::Display for TryFromFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.__description().fmt(f)
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+impl std::error::Error for TryFromFloatError {
+    fn description(&self) -> &str {
+        self.__description()
+    }
+}
+
+/// The direction in which to round a value that is not exactly representable.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum RoundingMode {
+    /// Round toward negative infinity.
+    Floor,
+    /// Round toward positive infinity.
+    Ceil,
+    /// Round toward zero.
+    Trunc,
+    /// Round to the nearest value, with ties going away from zero.
+    HalfUp,
+    /// Round to the nearest value, with ties going to the even neighbor.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Returns whether an inexact result, truncated toward zero to a value of the given
+    /// parity, should step one unit away from zero instead, given the sign of the exact
+    /// result and how the discarded fraction compares with one half.
+    pub(crate) fn rounds_away(self, negative: bool, odd: bool, fraction: Ordering) -> bool {
+        match self {
+            Self::Floor => negative,
+            Self::Ceil => !negative,
+            Self::Trunc => false,
+            Self::HalfUp => fraction != Ordering::Less,
+            Self::HalfEven => match fraction {
+                Ordering::Less => false,
+                Ordering::Equal => odd,
+                Ordering::Greater => true,
+            },
+        }
+    }
+}
+
+/// How closely a converted value matches the original.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Exactness {
+    /// The value was represented exactly.
+    Exact,
+    /// The value was rounded to the nearest representable value, with ties to even.
+    Rounded,
+    /// The value was too large in magnitude and became infinite.
+    Overflow,
+}
+
+/// The order of the digits of a number in a sequence.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum DigitOrder {
+    /// The most significant digit comes first, as in [`BigUint::from_radix_be`].
+    MostSignificantFirst,
+    /// The least significant digit comes first, as in [`BigUint::from_radix_le`].
+    LeastSignificantFirst,
+}
+
+pub use crate::biguint::lowlevel;
+pub use crate::biguint::Accumulator;
+#[cfg(target_has_atomic = "ptr")]
+pub use crate::biguint::ArcBigUint;
 pub use crate::biguint::BigUint;
+pub use crate::biguint::BigUintSlice;
+pub use crate::biguint::NonZeroBigUint;
+pub use crate::biguint::OddModulus;
+pub use crate::biguint::PrattCertificate;
+pub use crate::biguint::PreparedDivisor;
+pub use crate::biguint::Primes;
+pub use crate::biguint::StackBigUint;
 pub use crate::biguint::ToBigUint;
 pub use crate::biguint::U32Digits;
 pub use crate::biguint::U64Digits;
+pub use crate::biguint::{expr, Expr};
+#[cfg(feature = "serde")]
+pub use crate::biguint::{serde_max_bits, set_serde_max_bits, DEFAULT_SERDE_MAX_BITS};
+pub use crate::biguint::{Divisors, Factorization, SortedDivisors};
 
 pub use crate::bigint::BigInt;
 pub use crate::bigint::Sign;
 pub use crate::bigint::ToBigInt;
 
+pub use crate::binary_split::binary_split;
+
+pub use crate::continued_fraction::{ContinuedFraction, Convergents};
+pub use crate::modring::{FixedBasePow, ModInt, ModRing};
+pub use crate::range::{BigRange, BigRangeInclusive, Step};
+pub use crate::scaled::Scaled;
+#[cfg(feature = "stats")]
+pub use crate::stats::{reset_stats, stats, Stats};
+
 #[cfg(feature = "rand")]
 #[cfg_attr(docsrs, doc(cfg(feature = "rand")))]
-pub use crate::bigrand::{RandBigInt, RandomBits, UniformBigInt, UniformBigUint};
+pub use crate::bigrand::{
+    GeometricBits, RandBigInt, RandomBits, RandomBitsWeighted, TryRandBigInt, UniformBigInt,
+    UniformBigUint,
+};
 
 mod big_digit {
     // A [`BigDigit`] is a [`BigUint`]'s composing element.
@@ -0,0 +1,134 @@
+//! Limits on the size of values produced by operations that can grow without bound.
+//!
+//! A program that evaluates untrusted expressions, like a calculator or a script sandbox, can
+//! otherwise be made to exhaust its memory by a single `1 << 10_000_000_000`. With a limit in
+//! place, every operation that can produce a value much larger than its inputs checks the size
+//! of the result before doing the work:
+//!
+//! - Shifting left and raising to a power panic, with a message naming the limit, since their
+//!   operator traits have no way to return an error. The panic can be caught at the boundary
+//!   of the sandbox with `std::panic::catch_unwind`.
+//! - Parsing with `FromStr`, `from_str_radix` or `parse_bytes` returns an error.
+//! - `checked_pow` and `checked_pow_big` return `None`.
+//!
+//! The other arithmetic operations produce values at most about as large as the sum of their
+//! inputs, so bounding the inputs bounds them too.
+//!
+//! The `try_` methods, such as [`BigUint::try_mul`](crate::BigUint::try_mul) and
+//! [`BigUint::try_shl`](crate::BigUint::try_shl), return a [`CapacityError`] instead of
+//! panicking when their result would exceed the limit.
+//!
+//! The limit is set for the whole program with [`set_max_bits`], and with the `std` feature it
+//! can be lowered for the current thread until a [`LimitGuard`] returned by [`scoped_max_bits`]
+//! is dropped. There is no limit by default.
+//!
+//! ```
+//! # #[cfg(feature = "std")]
+//! # {
+//! use num_bigint::{limits, BigUint};
+//! use num_traits::One;
+//!
+//! let guard = limits::scoped_max_bits(1 << 20);
+//! assert!(std::panic::catch_unwind(|| BigUint::one() << 10_000_000_000u64).is_err());
+//! assert!("1".repeat(1_000_000).parse::<BigUint>().is_err());
+//! drop(guard);
+//! assert_eq!((BigUint::one() << 100u32).bits(), 101);
+//! # }
+//! ```
+
+use crate::CapacityError;
+
+use core::convert::TryFrom;
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+/// The program-wide limit in bits, with `usize::MAX` for none.
+static MAX_BITS: AtomicUsize = AtomicUsize::new(usize::MAX);
+
+#[cfg(feature = "std")]
+std::thread_local! {
+    /// The limit of the innermost [`LimitGuard`] on this thread, if any.
+    static SCOPED_MAX_BITS: core::cell::Cell<u64> = const { core::cell::Cell::new(u64::MAX) };
+}
+
+/// Sets the largest value, in bits, that the limited operations may produce in any thread, or
+/// removes the limit with `u64::MAX`.
+///
+/// Limits above `usize::MAX` bits cannot be reached on the target, and act as no limit.
+pub fn set_max_bits(max_bits: u64) {
+    let max_bits = usize::try_from(max_bits).unwrap_or(usize::MAX);
+    MAX_BITS.store(max_bits, Ordering::Relaxed);
+}
+
+/// Returns the limit in effect on the current thread, the lower of the program-wide limit and
+/// that of any [`LimitGuard`], or `u64::MAX` if there is none.
+#[must_use]
+pub fn max_bits() -> u64 {
+    let global = match MAX_BITS.load(Ordering::Relaxed) {
+        usize::MAX => u64::MAX,
+        max_bits => max_bits as u64,
+    };
+    #[cfg(feature = "std")]
+    let global = Ord::min(global, SCOPED_MAX_BITS.with(core::cell::Cell::get));
+    global
+}
+
+/// Lowers the limit on the current thread to `max_bits` until the returned guard is dropped.
+///
+/// Guards nest: an inner guard can only lower the limit further, and dropping it restores the
+/// limit of the outer one.
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub fn scoped_max_bits(max_bits: u64) -> LimitGuard {
+    let previous = SCOPED_MAX_BITS.with(|limit| limit.replace(Ord::min(max_bits, limit.get())));
+    LimitGuard {
+        previous,
+        _not_send: core::marker::PhantomData,
+    }
+}
+
+/// Restores the previous limit of the current thread when dropped, as returned by
+/// [`scoped_max_bits`].
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+#[must_use = "the limit is lifted again as soon as the guard is dropped"]
+#[derive(Debug)]
+pub struct LimitGuard {
+    previous: u64,
+    // The guard belongs to the thread whose limit it changed.
+    _not_send: core::marker::PhantomData<*const ()>,
+}
+
+#[cfg(feature = "std")]
+impl Drop for LimitGuard {
+    fn drop(&mut self) {
+        SCOPED_MAX_BITS.with(|limit| limit.set(self.previous));
+    }
+}
+
+/// Returns `true` if a value of `bits` bits is over the limit.
+pub(crate) fn exceeds(bits: u64) -> bool {
+    bits > max_bits()
+}
+
+/// Returns an error if a value of `bits` bits is over the limit, or could not be stored at all
+/// because its digits would take more than `isize::MAX` bytes.
+pub(crate) fn try_check(bits: u64) -> Result<(), CapacityError> {
+    let addressable = u64::try_from(isize::MAX).map_or(u64::MAX, |bytes| bytes.saturating_mul(8));
+    if bits > addressable {
+        Err(CapacityError::overflow())
+    } else if exceeds(bits) {
+        Err(CapacityError::limit_exceeded())
+    } else {
+        Ok(())
+    }
+}
+
+/// Panics if a value of `bits` bits is over the limit.
+pub(crate) fn check(bits: u64) {
+    assert!(
+        !exceeds(bits),
+        "big integer of at least {} bits exceeds the limit of {} bits",
+        bits,
+        max_bits()
+    );
+}
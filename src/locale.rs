@@ -0,0 +1,259 @@
+#![cfg(feature = "locale")]
+#![cfg_attr(docsrs, doc(cfg(feature = "locale")))]
+
+//! Formatting and parsing with the digit grouping and decimal conventions of a locale.
+//!
+//! [`Display`](core::fmt::Display) and [`FromStr`](core::str::FromStr) only handle the plain
+//! `-1234.5` form. A [`NumberFormat`] instead holds the conventions for user-facing text, as
+//! supplied by the caller: the decimal separator, the group separator and the sizes of the
+//! groups, and the minus sign. It doesn't know any locales by itself, so the conventions can
+//! come from wherever the application keeps them.
+//!
+//! ```
+//! use num_bigint::locale::NumberFormat;
+//! use num_bigint::{BigInt, Scaled};
+//!
+//! let de = NumberFormat::new()
+//!     .with_group_separator('.')
+//!     .with_decimal_separator(',');
+//! let price = Scaled::new(BigInt::from(-123_456_789), -2);
+//! assert_eq!(de.format_scaled(&price), "-1.234.567,89");
+//! assert_eq!(de.parse_scaled("-1.234.567,89"), Ok(price));
+//!
+//! // Indian grouping puts the last three digits together and the rest in pairs.
+//! let en_in = NumberFormat::new().with_group_separator(',').with_grouping(3, 2);
+//! let n = BigInt::from(12_345_678);
+//! assert_eq!(en_in.format_int(&n), "1,23,45,678");
+//! assert_eq!(en_in.parse_int("1,23,45,678"), Ok(n));
+//! ```
+
+use crate::{BigInt, BigUint, ParseBigIntError, Scaled, Sign};
+
+use alloc::string::{String, ToString};
+use alloc::vec::Vec;
+use core::convert::TryFrom;
+use num_traits::Signed;
+
+/// Conventions for writing and reading numbers in a locale.
+///
+/// The default, from [`NumberFormat::new`], matches the plain output of `Display`: a `.`
+/// decimal separator, no grouping, and a `-` minus sign.
+///
+/// Parsing is strict, so that a misplaced separator is reported rather than read as a
+/// different number: the group separators must be exactly where formatting would put them,
+/// although a number may also be written without any, and the minus sign must be the one
+/// configured.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct NumberFormat {
+    decimal_separator: char,
+    group_separator: Option<char>,
+    primary_group: u8,
+    secondary_group: u8,
+    minus_sign: char,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NumberFormat {
+    /// Creates the conventions of `Display`, with a `.` decimal separator, no grouping, and a
+    /// `-` minus sign.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            decimal_separator: '.',
+            group_separator: None,
+            primary_group: 3,
+            secondary_group: 3,
+            minus_sign: '-',
+        }
+    }
+
+    /// Separates the groups of integer digits with `separator`, such as `,`, `.`, `'`, or the
+    /// narrow no-break space `'\u{202f}'`.
+    #[must_use]
+    pub const fn with_group_separator(mut self, separator: char) -> Self {
+        self.group_separator = Some(separator);
+        self
+    }
+
+    /// Sets the size of the group of least significant integer digits to `primary`, and of
+    /// the groups above it to `secondary`. The default is three digits for both, and Indian
+    /// grouping uses 3 and 2.
+    ///
+    /// Panics if either size is zero.
+    #[must_use]
+    pub const fn with_grouping(mut self, primary: u8, secondary: u8) -> Self {
+        assert!(
+            primary != 0 && secondary != 0,
+            "group sizes must be nonzero"
+        );
+        self.primary_group = primary;
+        self.secondary_group = secondary;
+        self
+    }
+
+    /// Separates the integer digits from the fractional digits with `separator`, such as `,`.
+    #[must_use]
+    pub const fn with_decimal_separator(mut self, separator: char) -> Self {
+        self.decimal_separator = separator;
+        self
+    }
+
+    /// Writes negative numbers with `sign`, such as the minus sign `'\u{2212}'`, in place of
+    /// the hyphen-minus `-`.
+    #[must_use]
+    pub const fn with_minus_sign(mut self, sign: char) -> Self {
+        self.minus_sign = sign;
+        self
+    }
+
+    /// Appends the decimal digits in `digits` to `out`, with group separators between them.
+    fn push_grouped(&self, out: &mut String, digits: &str) {
+        let separator = match self.group_separator {
+            Some(separator) => separator,
+            None => return out.push_str(digits),
+        };
+        let mut groups = Vec::new();
+        let mut end = digits.len();
+        let mut size = usize::from(self.primary_group);
+        while end > size {
+            groups.push(&digits[end - size..end]);
+            end -= size;
+            size = usize::from(self.secondary_group);
+        }
+        out.push_str(&digits[..end]);
+        for group in groups.into_iter().rev() {
+            out.push(separator);
+            out.push_str(group);
+        }
+    }
+
+    /// Returns the digits of `digits`, which may have group separators in the right places,
+    /// as values below ten.
+    fn parse_grouped(&self, digits: &str) -> Result<Vec<u8>, ParseBigIntError> {
+        if digits.is_empty() {
+            return Err(ParseBigIntError::empty());
+        }
+        let groups: Vec<&str> = self.group_separator.map_or_else(
+            || alloc::vec![digits],
+            |separator| digits.split(separator).collect(),
+        );
+        if let [first, middle @ .., last] = &groups[..] {
+            let valid = (1..=usize::from(self.secondary_group)).contains(&first.len())
+                && middle
+                    .iter()
+                    .all(|group| group.len() == usize::from(self.secondary_group))
+                && last.len() == usize::from(self.primary_group);
+            if !valid {
+                return Err(ParseBigIntError::invalid());
+            }
+        }
+        groups
+            .iter()
+            .flat_map(|group| group.bytes())
+            .map(|b| match b {
+                b'0'..=b'9' => Ok(b - b'0'),
+                _ => Err(ParseBigIntError::invalid()),
+            })
+            .collect()
+    }
+
+    /// Splits off the minus sign of `s`, if any.
+    fn split_sign<'a>(&self, s: &'a str) -> (Sign, &'a str) {
+        s.strip_prefix(self.minus_sign)
+            .map_or((Sign::Plus, s), |rest| (Sign::Minus, rest))
+    }
+
+    /// Returns `n` in decimal with these conventions.
+    #[must_use]
+    pub fn format_uint(&self, n: &BigUint) -> String {
+        let mut out = String::new();
+        self.push_grouped(&mut out, &n.to_str_radix(10));
+        out
+    }
+
+    /// Returns `n` in decimal with these conventions.
+    #[must_use]
+    pub fn format_int(&self, n: &BigInt) -> String {
+        let mut out = String::new();
+        if n.is_negative() {
+            out.push(self.minus_sign);
+        }
+        self.push_grouped(&mut out, &n.magnitude().to_str_radix(10));
+        out
+    }
+
+    /// Returns `n` in plain positional notation with these conventions, with exactly
+    /// `-exponent` fractional digits when the exponent is negative, like its `Display`.
+    #[must_use]
+    pub fn format_scaled(&self, n: &Scaled) -> String {
+        let plain = n.to_string();
+        let plain = plain.strip_prefix('-').unwrap_or(&plain);
+        let (int, frac) = match plain.split_once('.') {
+            Some((int, frac)) => (int, Some(frac)),
+            None => (plain, None),
+        };
+        let mut out = String::new();
+        if n.mantissa().is_negative() {
+            out.push(self.minus_sign);
+        }
+        self.push_grouped(&mut out, int);
+        if let Some(frac) = frac {
+            out.push(self.decimal_separator);
+            out.push_str(frac);
+        }
+        out
+    }
+
+    /// Reads a decimal integer written with these conventions, without a sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` is empty, or has a character other than a digit or a correctly
+    /// placed group separator.
+    pub fn parse_uint(&self, s: &str) -> Result<BigUint, ParseBigIntError> {
+        let digits = self.parse_grouped(s)?;
+        Ok(BigUint::from_radix_be(&digits, 10).unwrap())
+    }
+
+    /// Reads a decimal integer written with these conventions, with an optional minus sign.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` has no digits, or has a character other than a leading minus
+    /// sign, a digit or a correctly placed group separator.
+    pub fn parse_int(&self, s: &str) -> Result<BigInt, ParseBigIntError> {
+        let (sign, s) = self.split_sign(s);
+        Ok(BigInt::from_biguint(sign, self.parse_uint(s)?))
+    }
+
+    /// Reads a decimal number written with these conventions, with an optional minus sign and
+    /// optional fractional digits, whose count becomes the negated exponent of the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `s` has no digits on either side of the decimal separator, or has a
+    /// character other than a leading minus sign, a digit, a correctly placed group separator
+    /// in the integer part, or a single decimal separator.
+    pub fn parse_scaled(&self, s: &str) -> Result<Scaled, ParseBigIntError> {
+        let (sign, s) = self.split_sign(s);
+        let (int, frac) = match s.split_once(self.decimal_separator) {
+            Some((int, frac)) => (int, frac),
+            None => (s, ""),
+        };
+        let mut digits = self.parse_grouped(int)?;
+        if s.len() > int.len() {
+            if frac.is_empty() {
+                return Err(ParseBigIntError::invalid());
+            }
+            digits.extend(Self::new().parse_grouped(frac)?);
+        }
+        let exponent = i64::try_from(frac.len()).map_err(|_| ParseBigIntError::too_large())?;
+        let mantissa = BigUint::from_radix_be(&digits, 10).unwrap();
+        Ok(Scaled::new(BigInt::from_biguint(sign, mantissa), -exponent))
+    }
+}
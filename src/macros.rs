@@ -32,6 +32,38 @@ macro_rules! cfg_digit_expr {
     };
 }
 
+/// Enters a debug-level `tracing` span until the end of the enclosing block, with the `tracing`
+/// feature, or does nothing without it. A leading `if cond,` enters the span only when `cond`
+/// holds, so that cheap calls are not recorded.
+macro_rules! trace_span {
+    (if $cond:expr, $name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = if $cond {
+            tracing::debug_span!($name $(, $($fields)*)?)
+        } else {
+            tracing::Span::none()
+        }
+        .entered();
+    };
+    ($name:literal $(, $($fields:tt)*)?) => {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!($name $(, $($fields)*)?).entered();
+    };
+}
+
+/// Adds `n`, or one, to a counter of the `stats` feature, or does nothing without it.
+macro_rules! count_stat {
+    ($field:ident) => {
+        count_stat!($field, 1)
+    };
+    ($field:ident, $n:expr) => {
+        #[cfg(feature = "stats")]
+        crate::stats::COUNTERS
+            .$field
+            .fetch_add($n, core::sync::atomic::Ordering::Relaxed);
+    };
+}
+
 macro_rules! forward_val_val_binop {
     (impl $imp:ident for $res:ty, $method:ident) => {
         impl $imp<$res> for $res {
@@ -0,0 +1,424 @@
+use crate::biguint::monty::MontyContext;
+use crate::{BigUint, OddModulus};
+
+use alloc::vec::Vec;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use num_integer::Integer;
+use num_traits::{One, Zero};
+
+#[derive(Clone, Debug)]
+enum Reducer {
+    /// Odd moduli keep their elements in Montgomery form.
+    Montgomery(MontyContext),
+    /// Even moduli reduce products with a precomputed `floor(4^k / m)`, where `k` is the bit
+    /// size of the modulus.
+    Barrett { mu: BigUint, k: u64 },
+}
+
+/// The integers modulo a fixed [`BigUint`], producing [`ModInt`] elements.
+///
+/// The ring does the precomputation for its modulus once, choosing Montgomery reduction for odd
+/// moduli and Barrett reduction otherwise, so that arithmetic on its elements avoids a full
+/// division for every product.
+///
+/// # Examples
+///
+/// ```
+/// use num_bigint::{BigUint, ModRing};
+///
+/// let ring = ModRing::new(BigUint::from(101u8));
+/// let a = ring.element(&BigUint::from(7u8));
+/// let b = ring.element(&BigUint::from(50u8));
+/// assert_eq!((&a * &b).to_biguint(), BigUint::from(47u8));
+/// assert_eq!((&a - &b).to_biguint(), BigUint::from(58u8));
+/// assert_eq!((&a * a.inv().unwrap()).to_biguint(), BigUint::from(1u8));
+/// ```
+#[derive(Clone, Debug)]
+pub struct ModRing {
+    modulus: BigUint,
+    reducer: Reducer,
+}
+
+impl ModRing {
+    /// Creates the ring of integers modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    #[must_use]
+    pub fn new(modulus: BigUint) -> Self {
+        assert!(
+            !modulus.is_zero(),
+            "attempt to create a ring with zero modulus!"
+        );
+        let reducer = if modulus.is_odd() {
+            Reducer::Montgomery(MontyContext::new(&modulus))
+        } else {
+            let k = modulus.bits();
+            let mu = BigUint::pow2(2 * k) / &modulus;
+            Reducer::Barrett { mu, k }
+        };
+        Self { modulus, reducer }
+    }
+
+    /// Creates the ring of integers modulo an odd `modulus`, which always uses Montgomery
+    /// reduction and cannot panic.
+    #[must_use]
+    pub fn from_odd(modulus: OddModulus) -> Self {
+        let modulus = modulus.into_inner();
+        let reducer = Reducer::Montgomery(MontyContext::new(&modulus));
+        Self { modulus, reducer }
+    }
+
+    /// Returns the modulus of this ring.
+    #[must_use]
+    #[inline]
+    pub const fn modulus(&self) -> &BigUint {
+        &self.modulus
+    }
+
+    /// Returns the element congruent to `value`.
+    #[must_use]
+    pub fn element(&self, value: &BigUint) -> ModInt<'_> {
+        let value = match &self.reducer {
+            Reducer::Montgomery(monty) => monty.encode(value, &self.modulus),
+            Reducer::Barrett { .. } => value % &self.modulus,
+        };
+        ModInt { ring: self, value }
+    }
+
+    /// Returns the additive identity.
+    #[must_use]
+    pub const fn zero(&self) -> ModInt<'_> {
+        ModInt {
+            ring: self,
+            value: BigUint::ZERO,
+        }
+    }
+
+    /// Returns the multiplicative identity, which is zero in the trivial ring modulo one.
+    #[must_use]
+    pub fn one(&self) -> ModInt<'_> {
+        self.element(&BigUint::one())
+    }
+
+    fn mul(&self, x: &BigUint, y: &BigUint) -> BigUint {
+        match &self.reducer {
+            Reducer::Montgomery(monty) => monty.mul(x, y, &self.modulus),
+            Reducer::Barrett { mu, k } => {
+                let x = x * y;
+                let q = ((&x >> (k - 1)) * mu) >> (k + 1);
+                // The estimate is at most two below the true quotient.
+                let mut r = x - q * &self.modulus;
+                while r >= self.modulus {
+                    r -= &self.modulus;
+                }
+                r
+            }
+        }
+    }
+
+    fn to_biguint(&self, x: &BigUint) -> BigUint {
+        match &self.reducer {
+            Reducer::Montgomery(monty) => monty.decode(x, &self.modulus),
+            Reducer::Barrett { .. } => x.clone(),
+        }
+    }
+}
+
+/// An element of a [`ModRing`], supporting `+`, `-`, `*`, negation, powers and inverses.
+///
+/// Operations on elements of different rings panic.
+#[derive(Clone)]
+pub struct ModInt<'a> {
+    ring: &'a ModRing,
+    /// The residue, in Montgomery form for an odd modulus.
+    value: BigUint,
+}
+
+impl<'a> ModInt<'a> {
+    /// Returns the ring this element belongs to.
+    #[must_use]
+    #[inline]
+    pub const fn ring(&self) -> &'a ModRing {
+        self.ring
+    }
+
+    /// Returns the residue of this element, in the range `0..modulus`.
+    #[must_use]
+    pub fn to_biguint(&self) -> BigUint {
+        self.ring.to_biguint(&self.value)
+    }
+
+    /// Returns `true` if this is the additive identity.
+    #[must_use]
+    #[inline]
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    /// Returns `self ^ exponent`.
+    #[must_use]
+    pub fn pow(&self, exponent: &BigUint) -> Self {
+        let mut acc = self.ring.one().value;
+        for i in (0..exponent.bits()).rev() {
+            acc = self.ring.mul(&acc, &acc);
+            if exponent.bit(i) {
+                acc = self.ring.mul(&acc, &self.value);
+            }
+        }
+        self.with_value(acc)
+    }
+
+    /// Returns the multiplicative inverse, or `None` if this element is not coprime to the
+    /// modulus.
+    #[must_use]
+    pub fn inv(&self) -> Option<Self> {
+        let inverse = self.to_biguint().modinv(&self.ring.modulus)?;
+        Some(self.ring.element(&inverse))
+    }
+
+    fn with_value(&self, value: BigUint) -> Self {
+        Self {
+            ring: self.ring,
+            value,
+        }
+    }
+
+    fn check_ring(&self, other: &Self) {
+        assert!(
+            core::ptr::eq(self.ring, other.ring) || self.ring.modulus == other.ring.modulus,
+            "attempt to combine elements of different rings!"
+        );
+    }
+}
+
+impl From<ModInt<'_>> for BigUint {
+    #[inline]
+    fn from(x: ModInt<'_>) -> Self {
+        x.to_biguint()
+    }
+}
+
+impl PartialEq for ModInt<'_> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.ring.modulus == other.ring.modulus && self.value == other.value
+    }
+}
+
+impl Eq for ModInt<'_> {}
+
+impl fmt::Debug for ModInt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (mod {})", self.to_biguint(), self.ring.modulus)
+    }
+}
+
+impl fmt::Display for ModInt<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_biguint(), f)
+    }
+}
+
+impl<'a> Add<&ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn add(self, other: &ModInt<'a>) -> ModInt<'a> {
+        self.check_ring(other);
+        let mut value = &self.value + &other.value;
+        if value >= self.ring.modulus {
+            value -= &self.ring.modulus;
+        }
+        self.with_value(value)
+    }
+}
+
+impl<'a> Sub<&ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn sub(self, other: &ModInt<'a>) -> ModInt<'a> {
+        self.check_ring(other);
+        let value = if self.value >= other.value {
+            &self.value - &other.value
+        } else {
+            &self.ring.modulus - &other.value + &self.value
+        };
+        self.with_value(value)
+    }
+}
+
+impl<'a> Mul<&ModInt<'a>> for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn mul(self, other: &ModInt<'a>) -> ModInt<'a> {
+        self.check_ring(other);
+        self.with_value(self.ring.mul(&self.value, &other.value))
+    }
+}
+
+impl<'a> Neg for &ModInt<'a> {
+    type Output = ModInt<'a>;
+
+    fn neg(self) -> ModInt<'a> {
+        if self.value.is_zero() {
+            self.clone()
+        } else {
+            self.with_value(&self.ring.modulus - &self.value)
+        }
+    }
+}
+
+impl Neg for ModInt<'_> {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        -&self
+    }
+}
+
+macro_rules! forward_modint_binop {
+    (impl $imp:ident, $method:ident) => {
+        impl<'a> $imp<ModInt<'a>> for ModInt<'a> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: Self) -> Self {
+                $imp::$method(&self, &other)
+            }
+        }
+
+        impl<'a> $imp<&ModInt<'a>> for ModInt<'a> {
+            type Output = Self;
+
+            #[inline]
+            fn $method(self, other: &Self) -> Self {
+                $imp::$method(&self, other)
+            }
+        }
+
+        impl<'a> $imp<ModInt<'a>> for &ModInt<'a> {
+            type Output = ModInt<'a>;
+
+            #[inline]
+            fn $method(self, other: ModInt<'a>) -> ModInt<'a> {
+                $imp::$method(self, &other)
+            }
+        }
+    };
+}
+
+forward_modint_binop!(impl Add, add);
+forward_modint_binop!(impl Sub, sub);
+forward_modint_binop!(impl Mul, mul);
+
+/// Precomputed powers of a fixed base modulo a fixed modulus, for computing many `g^x mod m`
+/// with the same `g` and `m`, as in Diffie–Hellman key exchange.
+///
+/// The table holds `g^(2^(w·i))` for each `w`-bit window `i` of the exponent, and [`pow`]
+/// combines them with Yao's method: about one multiplication per window plus two per window
+/// value, and no squarings at all. The window width is chosen to minimize that count, which
+/// for 2048-bit exponents is several times fewer multiplications than a plain `modpow`.
+///
+/// [`pow`]: FixedBasePow::pow
+///
+/// ```
+/// use num_bigint::{BigUint, FixedBasePow};
+///
+/// let p = (BigUint::from(1u8) << 127u32) - 1u8;
+/// let g = BigUint::from(3u8);
+/// let table = FixedBasePow::new(&g, p.clone(), 127);
+/// let x = BigUint::from(123_456_789u32);
+/// assert_eq!(table.pow(&x), g.modpow(&x, &p));
+/// ```
+#[derive(Clone, Debug)]
+pub struct FixedBasePow {
+    ring: ModRing,
+    /// The window width in bits.
+    window: u32,
+    /// `g^(2^(window·i))` in the ring's representation.
+    table: Vec<BigUint>,
+}
+
+impl FixedBasePow {
+    /// Precomputes the powers of `base` modulo `modulus` for exponents of up to
+    /// `max_exponent_bits` bits. Larger exponents still work, but fall back to ordinary
+    /// square-and-multiply.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `modulus` is zero.
+    #[must_use]
+    pub fn new(base: &BigUint, modulus: BigUint, max_exponent_bits: u64) -> Self {
+        let ring = ModRing::new(modulus);
+        let bits = max_exponent_bits.max(1);
+
+        // Yao's method takes one multiplication per window, plus two per window value.
+        let cost = |w: u32| Integer::div_ceil(&bits, &u64::from(w)) + (2 << w);
+        let window = (1..=8).min_by_key(|&w| cost(w)).unwrap();
+        let len = Integer::div_ceil(&bits, &u64::from(window));
+
+        let mut power = ring.element(base).value;
+        let mut table = Vec::with_capacity(usize::try_from(len).unwrap_or(0));
+        for i in 0..len {
+            if i > 0 {
+                for _ in 0..window {
+                    power = ring.mul(&power, &power);
+                }
+            }
+            table.push(power.clone());
+        }
+        Self {
+            ring,
+            window,
+            table,
+        }
+    }
+
+    /// Returns the modulus of the precomputed powers.
+    #[must_use]
+    #[inline]
+    pub const fn modulus(&self) -> &BigUint {
+        self.ring.modulus()
+    }
+
+    /// Returns `base ^ exponent % modulus`.
+    #[must_use]
+    pub fn pow(&self, exponent: &BigUint) -> BigUint {
+        let window = u64::from(self.window);
+        if exponent.bits() > self.table.len() as u64 * window {
+            let base = ModInt {
+                ring: &self.ring,
+                value: self.table[0].clone(),
+            };
+            return base.pow(exponent).to_biguint();
+        }
+
+        // The exponent in base `2^window`, least significant first.
+        let digits: Vec<u32> = (0..self.table.len() as u64)
+            .map(|i| (0..window).fold(0, |d, b| d | (u32::from(exponent.bit(i * window + b)) << b)))
+            .collect();
+
+        // For each digit value `j` from the top, `run` is the product of the table entries
+        // with digits at least `j`, and multiplying it into `acc` at every step weights each
+        // entry by its digit.
+        let mut run: Option<BigUint> = None;
+        let mut acc: Option<BigUint> = None;
+        for j in (1..1u32 << self.window).rev() {
+            for (d, t) in digits.iter().zip(&self.table) {
+                if *d == j {
+                    run = Some(run.map_or_else(|| t.clone(), |r| self.ring.mul(&r, t)));
+                }
+            }
+            if let Some(r) = &run {
+                acc = Some(acc.map_or_else(|| r.clone(), |a| self.ring.mul(&a, r)));
+            }
+        }
+        acc.map_or_else(
+            || self.ring.one().to_biguint(),
+            |a| self.ring.to_biguint(&a),
+        )
+    }
+}
@@ -0,0 +1,144 @@
+use crate::{BigInt, BigUint};
+
+use core::iter::FusedIterator;
+use core::ops::{AddAssign, SubAssign};
+use num_traits::ToPrimitive;
+
+/// Integers that can be stepped through one at a time, updating in place.
+///
+/// This bounds the item type of [`BigRange`] and [`BigRangeInclusive`], and is implemented for
+/// [`BigUint`] and [`BigInt`].
+pub trait Step: Clone + Ord + AddAssign<u32> + SubAssign<u32> {
+    /// Returns the number of values in `start..end`, if it fits in a `usize`.
+    fn steps_between(start: &Self, end: &Self) -> Option<usize>;
+}
+
+impl Step for BigUint {
+    fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+        if start < end {
+            (end - start).to_usize()
+        } else {
+            Some(0)
+        }
+    }
+}
+
+impl Step for BigInt {
+    fn steps_between(start: &Self, end: &Self) -> Option<usize> {
+        if start < end {
+            (end - start).to_usize()
+        } else {
+            Some(0)
+        }
+    }
+}
+
+fn size_hint(count: Option<usize>) -> (usize, Option<usize>) {
+    count.map_or((usize::MAX, None), |n| (n, Some(n)))
+}
+
+/// An iterator over the values in a half-open range of [`BigUint`] or [`BigInt`], created by
+/// [`BigUint::range`] or [`BigInt::range`].
+///
+/// Each bound is incremented or decremented in place, so stepping only allocates when the
+/// value grows into a new digit, apart from the clone of each yielded item.
+#[derive(Clone, Debug)]
+pub struct BigRange<T> {
+    start: T,
+    end: T,
+}
+
+impl<T> BigRange<T> {
+    pub(crate) const fn new(start: T, end: T) -> Self {
+        Self { start, end }
+    }
+}
+
+impl<T: Step> Iterator for BigRange<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.start < self.end {
+            let next = self.start.clone();
+            self.start += 1u32;
+            Some(next)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        size_hint(T::steps_between(&self.start, &self.end))
+    }
+}
+
+impl<T: Step> DoubleEndedIterator for BigRange<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.start < self.end {
+            self.end -= 1u32;
+            Some(self.end.clone())
+        } else {
+            None
+        }
+    }
+}
+
+impl<T: Step> FusedIterator for BigRange<T> {}
+
+/// An iterator over the values in a closed range of [`BigUint`] or [`BigInt`], created by
+/// [`BigUint::range_inclusive`] or [`BigInt::range_inclusive`].
+#[derive(Clone, Debug)]
+pub struct BigRangeInclusive<T> {
+    start: T,
+    end: T,
+    done: bool,
+}
+
+impl<T: Step> BigRangeInclusive<T> {
+    pub(crate) fn new(start: T, end: T) -> Self {
+        let done = start > end;
+        Self { start, end, done }
+    }
+}
+
+impl<T: Step> Iterator for BigRangeInclusive<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let next = self.start.clone();
+        if self.start < self.end {
+            self.start += 1u32;
+        } else {
+            self.done = true;
+        }
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.done {
+            return (0, Some(0));
+        }
+        size_hint(T::steps_between(&self.start, &self.end).and_then(|n| n.checked_add(1)))
+    }
+}
+
+impl<T: Step> DoubleEndedIterator for BigRangeInclusive<T> {
+    fn next_back(&mut self) -> Option<T> {
+        if self.done {
+            return None;
+        }
+        let next = self.end.clone();
+        if self.start < self.end {
+            // The end never drops below zero here, since it is still above the start.
+            self.end -= 1u32;
+        } else {
+            self.done = true;
+        }
+        Some(next)
+    }
+}
+
+impl<T: Step> FusedIterator for BigRangeInclusive<T> {}
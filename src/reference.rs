@@ -0,0 +1,184 @@
+#![cfg(feature = "reference")]
+#![cfg_attr(docsrs, doc(cfg(feature = "reference")))]
+
+//! Naive reference implementations of multiplication, division and radix conversion.
+//!
+//! [`BigUint`] switches to Karatsuba and Toom-3 multiplication and chunked radix conversion as
+//! its operands grow, and divides with estimated quotient digits that are rarely corrected,
+//! which leaves many paths that small tests never reach. The functions here instead use the
+//! textbook methods on 32-bit digits: schoolbook multiplication, binary long division, and
+//! conversion one digit at a time. They share no arithmetic with the optimized code beyond
+//! moving digits in and out of a [`BigUint`], so they can serve as an oracle for differential
+//! tests and fuzzers in the same process.
+//!
+//! They take time quadratic in the size of the operands and are only meant for testing.
+//!
+//! ```
+//! use num_bigint::{reference, BigUint};
+//!
+//! let x = BigUint::from(7u8).pow(5000);
+//! let y = BigUint::from(3u8).pow(7000);
+//! assert_eq!(reference::mul(&x, &y), &x * &y);
+//! assert_eq!(reference::div_rem(&y, &x), (&y / &x, &y % &x));
+//! assert_eq!(reference::to_str_radix(&x, 10), x.to_str_radix(10));
+//! ```
+
+use crate::BigUint;
+
+use alloc::string::String;
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+use core::convert::TryFrom;
+
+/// Returns the low and high halves of `t`.
+#[allow(clippy::cast_possible_truncation)]
+const fn split(t: u64) -> (u32, u32) {
+    (t as u32, (t >> 32) as u32)
+}
+
+/// Removes the most significant zero digits of `a`.
+fn trim(a: &mut Vec<u32>) {
+    while a.last() == Some(&0) {
+        a.pop();
+    }
+}
+
+/// Compares two numbers without most significant zero digits.
+fn cmp(a: &[u32], b: &[u32]) -> Ordering {
+    a.len()
+        .cmp(&b.len())
+        .then_with(|| a.iter().rev().cmp(b.iter().rev()))
+}
+
+/// Subtracts `b` from `a`, which must be at least as large.
+fn sub_assign(a: &mut Vec<u32>, b: &[u32]) {
+    let mut borrow = 0;
+    for (i, ai) in a.iter_mut().enumerate() {
+        let bi = b.get(i).copied().unwrap_or(0);
+        let (d, b1) = ai.overflowing_sub(bi);
+        let (d, b2) = d.overflowing_sub(borrow);
+        *ai = d;
+        borrow = u32::from(b1 || b2);
+    }
+    debug_assert_eq!(borrow, 0);
+    trim(a);
+}
+
+/// Multiplies `a` by `m` and adds `c`, in place.
+fn mul_add_small(a: &mut Vec<u32>, m: u32, c: u32) {
+    let mut carry = c;
+    for ai in a.iter_mut() {
+        let (lo, hi) = split(u64::from(*ai) * u64::from(m) + u64::from(carry));
+        *ai = lo;
+        carry = hi;
+    }
+    if carry != 0 {
+        a.push(carry);
+    }
+    trim(a);
+}
+
+/// Divides `a` by `d` in place and returns the remainder.
+fn div_rem_small(a: &mut Vec<u32>, d: u32) -> u32 {
+    let mut rem = 0;
+    for ai in a.iter_mut().rev() {
+        let t = (u64::from(rem) << 32) | u64::from(*ai);
+        *ai = u32::try_from(t / u64::from(d)).unwrap();
+        rem = u32::try_from(t % u64::from(d)).unwrap();
+    }
+    trim(a);
+    rem
+}
+
+/// Returns `x * y` by schoolbook multiplication.
+#[must_use]
+pub fn mul(x: &BigUint, y: &BigUint) -> BigUint {
+    let (x, y) = (x.to_u32_digits(), y.to_u32_digits());
+    let mut prod = alloc::vec![0u32; x.len() + y.len()];
+    for (i, &xi) in x.iter().enumerate() {
+        let mut carry = 0;
+        for (j, &yj) in y.iter().enumerate() {
+            let t = u64::from(xi) * u64::from(yj) + u64::from(prod[i + j]) + u64::from(carry);
+            let (lo, hi) = split(t);
+            prod[i + j] = lo;
+            carry = hi;
+        }
+        prod[i + y.len()] = carry;
+    }
+    BigUint::new(prod)
+}
+
+/// Returns `(x / y, x % y)` by binary long division, one bit of the quotient at a time.
+///
+/// Panics if `y` is zero.
+#[must_use]
+pub fn div_rem(x: &BigUint, y: &BigUint) -> (BigUint, BigUint) {
+    let (x, y) = (x.to_u32_digits(), y.to_u32_digits());
+    assert!(!y.is_empty(), "attempt to divide by zero");
+
+    let mut quotient = alloc::vec![0u32; x.len()];
+    let mut rem = Vec::with_capacity(y.len() + 1);
+    for i in (0..x.len() * 32).rev() {
+        let bit = (x[i / 32] >> (i % 32)) & 1;
+        mul_add_small(&mut rem, 2, bit);
+        if cmp(&rem, &y) != Ordering::Less {
+            sub_assign(&mut rem, &y);
+            quotient[i / 32] |= 1 << (i % 32);
+        }
+    }
+    (BigUint::new(quotient), BigUint::new(rem))
+}
+
+/// Returns the digits of `x` in base `radix`, most significant first, found by repeated
+/// division by the radix, like [`BigUint::to_radix_be`].
+///
+/// Panics if the radix is not in the range `2...256`.
+#[must_use]
+pub fn to_radix_be(x: &BigUint, radix: u32) -> Vec<u8> {
+    assert!(
+        (2..=256).contains(&radix),
+        "The radix must be within 2...256"
+    );
+    let mut a = x.to_u32_digits();
+    let mut res = Vec::new();
+    while !a.is_empty() {
+        res.push(u8::try_from(div_rem_small(&mut a, radix)).unwrap());
+    }
+    if res.is_empty() {
+        res.push(0);
+    }
+    res.reverse();
+    res
+}
+
+/// Returns the number with the given digits in base `radix`, most significant first, found by
+/// Horner's rule, or `None` if a digit is not below the radix, like [`BigUint::from_radix_be`].
+///
+/// Panics if the radix is not in the range `2...256`.
+#[must_use]
+pub fn from_radix_be(buf: &[u8], radix: u32) -> Option<BigUint> {
+    assert!(
+        (2..=256).contains(&radix),
+        "The radix must be within 2...256"
+    );
+    let mut a = Vec::new();
+    for &d in buf {
+        if u32::from(d) >= radix {
+            return None;
+        }
+        mul_add_small(&mut a, radix, u32::from(d));
+    }
+    Some(BigUint::new(a))
+}
+
+/// Returns the digits of `x` in base `radix` as a string, like [`BigUint::to_str_radix`].
+///
+/// Panics if the radix is not in the range `2...36`.
+#[must_use]
+pub fn to_str_radix(x: &BigUint, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "The radix must be within 2...36");
+    to_radix_be(x, radix)
+        .into_iter()
+        .map(|d| char::from_digit(u32::from(d), radix).unwrap())
+        .collect()
+}
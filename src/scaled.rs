@@ -0,0 +1,263 @@
+use crate::{BigInt, RoundingMode, Sign};
+
+use core::cmp::Ordering;
+use core::fmt;
+use core::ops::{Add, Mul, Neg, Sub};
+use num_traits::{Pow, Signed, Zero};
+
+/// A decimal number `mantissa × 10^exponent`, with an arbitrary precision mantissa.
+///
+/// This is a minimal building block for exchanging values with decimal libraries, not a full
+/// decimal type: sums, differences and products are exact, taking the smaller exponent or the
+/// sum of the exponents, and [`round_to_scale`](Self::round_to_scale) brings the result back
+/// to a fixed number of fractional digits. Comparison is by numeric value, so `1.0` and `1.00`
+/// are equal even though their parts differ.
+///
+/// Being exact, a sum or difference of numbers whose exponents are `n` apart has a mantissa of
+/// at least `n` digits, and [`Display`](fmt::Display) writes out every zero of a positive
+/// exponent, so both take time and memory in proportion to the exponents. Comparison only
+/// rescales numbers whose leading digits are close, so it is bounded by the mantissas instead.
+///
+/// ```
+/// use num_bigint::{BigInt, Scaled};
+///
+/// let price = Scaled::new(BigInt::from(1999), -2); // 19.99
+/// let rate = Scaled::new(BigInt::from(1075), -3); // 1.075
+/// let total = &price * &rate;
+/// assert_eq!(total.to_string(), "21.48925");
+/// assert_eq!(total.round_to_scale(2).to_string(), "21.49");
+/// assert!(total > price);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Scaled {
+    mantissa: BigInt,
+    exponent: i64,
+}
+
+/// Returns `10^n`.
+fn pow10(n: u64) -> BigInt {
+    BigInt::from(10u8).pow(n)
+}
+
+impl Scaled {
+    /// Creates the number `mantissa × 10^exponent`.
+    #[must_use]
+    #[inline]
+    pub const fn new(mantissa: BigInt, exponent: i64) -> Self {
+        Self { mantissa, exponent }
+    }
+
+    /// Returns the mantissa.
+    #[must_use]
+    #[inline]
+    pub const fn mantissa(&self) -> &BigInt {
+        &self.mantissa
+    }
+
+    /// Returns the decimal exponent.
+    #[must_use]
+    #[inline]
+    pub const fn exponent(&self) -> i64 {
+        self.exponent
+    }
+
+    /// Returns the mantissa and the decimal exponent.
+    #[must_use]
+    #[inline]
+    pub fn into_parts(self) -> (BigInt, i64) {
+        (self.mantissa, self.exponent)
+    }
+
+    /// Returns the mantissa this number has with the given exponent, which must be no greater
+    /// than its own.
+    fn mantissa_at(&self, exponent: i64) -> BigInt {
+        debug_assert!(exponent <= self.exponent);
+        match self.exponent.abs_diff(exponent) {
+            0 => self.mantissa.clone(),
+            n => &self.mantissa * pow10(n),
+        }
+    }
+
+    /// Returns bounds `(lo, hi)` with `10^(lo - 1) <= |self| < 10^hi`, for a nonzero number,
+    /// from the bit length of the mantissa alone.
+    fn magnitude_bounds(&self) -> (i128, i128) {
+        // 30102 / 100000 < log10(2) < 30103 / 100000, so the mantissa has at least `lo` and
+        // at most `hi` decimal digits.
+        let bits = i128::from(self.mantissa.bits());
+        let lo = (bits - 1) * 30102 / 100_000 + 1;
+        let hi = bits * 30103 / 100_000 + 1;
+        let exponent = i128::from(self.exponent);
+        (lo + exponent, hi + exponent)
+    }
+
+    /// Rounds to `scale` fractional digits, that is to exponent `-scale`, with ties going to
+    /// the even neighbour.
+    ///
+    /// A scale beyond the current one only appends zeros to the mantissa, and a negative scale
+    /// rounds to a multiple of a power of ten.
+    ///
+    /// ```
+    /// use num_bigint::{BigInt, Scaled};
+    ///
+    /// let x = Scaled::new(BigInt::from(-1250), -3); // -1.250
+    /// assert_eq!(x.round_to_scale(1).to_string(), "-1.2");
+    /// assert_eq!(x.round_to_scale(5).to_string(), "-1.25000");
+    /// assert_eq!(x.round_to_scale(-1).to_string(), "0");
+    /// ```
+    #[must_use]
+    pub fn round_to_scale(&self, scale: i64) -> Self {
+        let exponent = 0i64.checked_sub(scale).expect("scale overflow");
+        if exponent <= self.exponent {
+            return Self::new(self.mantissa_at(exponent), exponent);
+        }
+
+        let d = pow10(exponent.abs_diff(self.exponent));
+        let q = self.mantissa.div_round(&d, RoundingMode::HalfEven);
+        Self::new(q, exponent)
+    }
+}
+
+impl From<BigInt> for Scaled {
+    #[inline]
+    fn from(n: BigInt) -> Self {
+        Self::new(n, 0)
+    }
+}
+
+impl Zero for Scaled {
+    #[inline]
+    fn zero() -> Self {
+        Self::new(BigInt::ZERO, 0)
+    }
+
+    #[inline]
+    fn is_zero(&self) -> bool {
+        self.mantissa.is_zero()
+    }
+}
+
+impl PartialEq for Scaled {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for Scaled {}
+
+impl PartialOrd for Scaled {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scaled {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let (a, b) = (self.mantissa.sign(), other.mantissa.sign());
+        if a != b || a == Sign::NoSign {
+            return a.cmp(&b);
+        }
+        // Numbers whose leading digits are far apart compare without rescaling, which could
+        // otherwise take a mantissa as long as the gap between the exponents.
+        let (self_lo, self_hi) = self.magnitude_bounds();
+        let (other_lo, other_hi) = other.magnitude_bounds();
+        let magnitude = if self_hi < other_lo {
+            Ordering::Less
+        } else if other_hi < self_lo {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        };
+        if magnitude != Ordering::Equal {
+            return if a == Sign::Minus {
+                magnitude.reverse()
+            } else {
+                magnitude
+            };
+        }
+        match self.exponent.cmp(&other.exponent) {
+            Ordering::Equal => self.mantissa.cmp(&other.mantissa),
+            Ordering::Less => self.mantissa.cmp(&other.mantissa_at(self.exponent)),
+            Ordering::Greater => self.mantissa_at(other.exponent).cmp(&other.mantissa),
+        }
+    }
+}
+
+impl Neg for Scaled {
+    type Output = Self;
+
+    #[inline]
+    fn neg(self) -> Self {
+        Self::new(-self.mantissa, self.exponent)
+    }
+}
+
+impl Neg for &Scaled {
+    type Output = Scaled;
+
+    #[inline]
+    fn neg(self) -> Scaled {
+        Scaled::new(-&self.mantissa, self.exponent)
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Add for Scaled, add);
+
+impl Add<&Scaled> for &Scaled {
+    type Output = Scaled;
+
+    fn add(self, other: &Scaled) -> Scaled {
+        let exponent = self.exponent.min(other.exponent);
+        let mantissa = self.mantissa_at(exponent) + other.mantissa_at(exponent);
+        Scaled::new(mantissa, exponent)
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Sub for Scaled, sub);
+
+impl Sub<&Scaled> for &Scaled {
+    type Output = Scaled;
+
+    fn sub(self, other: &Scaled) -> Scaled {
+        let exponent = self.exponent.min(other.exponent);
+        let mantissa = self.mantissa_at(exponent) - other.mantissa_at(exponent);
+        Scaled::new(mantissa, exponent)
+    }
+}
+
+forward_all_binop_to_ref_ref!(impl Mul for Scaled, mul);
+
+impl Mul<&Scaled> for &Scaled {
+    type Output = Scaled;
+
+    fn mul(self, other: &Scaled) -> Scaled {
+        let exponent = self
+            .exponent
+            .checked_add(other.exponent)
+            .expect("exponent overflow");
+        Scaled::new(&self.mantissa * &other.mantissa, exponent)
+    }
+}
+
+/// Writes the number in plain positional notation, with exactly `-exponent` fractional digits
+/// when the exponent is negative.
+impl fmt::Display for Scaled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut digits = self.mantissa.magnitude().to_str_radix(10);
+        if self.exponent >= 0 {
+            if !self.mantissa.is_zero() {
+                let zeros = usize::try_from(self.exponent).expect("exponent overflow");
+                digits.extend(core::iter::repeat('0').take(zeros));
+            }
+        } else {
+            let frac = usize::try_from(self.exponent.unsigned_abs()).expect("exponent overflow");
+            if digits.len() <= frac {
+                let zeros = frac + 1 - digits.len();
+                digits.insert_str(0, &"0".repeat(zeros));
+            }
+            digits.insert(digits.len() - frac, '.');
+        }
+        f.pad_integral(!self.mantissa.is_negative(), "", &digits)
+    }
+}
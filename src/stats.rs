@@ -0,0 +1,69 @@
+#![cfg(feature = "stats")]
+#![cfg_attr(docsrs, doc(cfg(feature = "stats")))]
+
+//! Global counters of the work done by arithmetic, for tuning and performance debugging.
+//!
+//! Every count is a relaxed atomic increment shared by all threads, so [`stats`] returns a
+//! running total since the start of the program or the last [`reset_stats`]. Comparing the
+//! counts before and after a piece of code shows which algorithms it reaches and how much
+//! work they do, e.g. a `limbs_multiplied` that grows with the square of the input size where
+//! linear growth was expected.
+
+use core::sync::atomic::{AtomicUsize, Ordering::Relaxed};
+
+macro_rules! define_stats {
+    ($($(#[$doc:meta])* $field:ident,)*) => {
+        /// A snapshot of the counters, returned by [`stats`].
+        #[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+        #[non_exhaustive]
+        pub struct Stats {
+            $($(#[$doc])* pub $field: usize,)*
+        }
+
+        pub(crate) struct Counters {
+            $(pub(crate) $field: AtomicUsize,)*
+        }
+
+        pub(crate) static COUNTERS: Counters = Counters {
+            $($field: AtomicUsize::new(0),)*
+        };
+
+        /// Returns the current value of every counter.
+        #[must_use]
+        pub fn stats() -> Stats {
+            Stats {
+                $($field: COUNTERS.$field.load(Relaxed),)*
+            }
+        }
+
+        /// Sets every counter back to zero.
+        pub fn reset_stats() {
+            $(COUNTERS.$field.store(0, Relaxed);)*
+        }
+    };
+}
+
+define_stats! {
+    /// Digit buffers newly allocated for the products, quotients and temporaries of
+    /// multiplication and division. Buffers reused from the `scratch-pool` are not counted.
+    allocations,
+    /// Digit-by-digit products computed by long multiplication, including the small products
+    /// at the leaves of the subquadratic algorithms.
+    limbs_multiplied,
+    /// Multiplications done by long multiplication, for operands of up to 32 digits.
+    long_multiplications,
+    /// Multiplications split by Half-Karatsuba, where one operand is at least twice as long.
+    half_karatsuba_multiplications,
+    /// Multiplications split by Karatsuba.
+    karatsuba_multiplications,
+    /// Multiplications split by Toom-3, for the largest operands.
+    toom3_multiplications,
+    /// Divisions by a divisor of more than one digit using Knuth's long division.
+    long_divisions,
+    /// Divisions by a divisor of more than one digit known to divide exactly.
+    exact_divisions,
+    /// Modular exponentiations using Montgomery multiplication, for an odd modulus.
+    montgomery_modpows,
+    /// Modular exponentiations using plain reduction, for an even modulus.
+    plain_modpows,
+}
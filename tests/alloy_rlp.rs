@@ -0,0 +1,43 @@
+#![cfg(feature = "alloy-rlp")]
+
+use alloy_rlp::{Decodable, Encodable, Error};
+use num_bigint::BigUint;
+use num_traits::Pow;
+
+#[test]
+fn test_alloy_rlp() {
+    let values = [
+        BigUint::from(0u8),
+        BigUint::from(1u8),
+        BigUint::from(0x7fu8),
+        BigUint::from(0x80u8),
+        BigUint::from(u64::MAX),
+        BigUint::from(2u8).pow(439u32),
+        BigUint::from(2u8).pow(440u32),
+        BigUint::from(3u8).pow(5000u32),
+    ];
+    for n in values {
+        let mut out = Vec::new();
+        n.encode(&mut out);
+        assert_eq!(out, n.to_rlp());
+        assert_eq!(n.length(), out.len());
+        // The same bytes as alloy's own unsigned integers, where those can hold the value.
+        if let Ok(x) = u64::try_from(&n) {
+            assert_eq!(alloy_rlp::encode(x), out);
+        }
+
+        let mut buf = &out[..];
+        assert_eq!(BigUint::decode(&mut buf).unwrap(), n);
+        assert!(buf.is_empty());
+    }
+}
+
+#[test]
+fn test_alloy_rlp_invalid() {
+    let decode = |bytes: &[u8]| BigUint::decode(&mut &bytes[..]);
+    assert_eq!(decode(&[0x82, 0x00, 0x01]), Err(Error::LeadingZero));
+    assert_eq!(decode(&[0x00]), Err(Error::LeadingZero));
+    assert_eq!(decode(&[0x81, 0x05]), Err(Error::NonCanonicalSingleByte));
+    assert_eq!(decode(&[0x82, 0x01]), Err(Error::InputTooShort));
+    assert_eq!(decode(&[0xc0]), Err(Error::UnexpectedList));
+}
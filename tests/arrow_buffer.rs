@@ -0,0 +1,36 @@
+#![cfg(feature = "arrow-buffer")]
+
+use arrow_buffer::i256;
+use num_bigint::BigInt;
+use num_traits::One;
+
+#[test]
+fn test_bigint_to_i256() {
+    let values = [
+        i256::ZERO,
+        i256::ONE,
+        i256::MINUS_ONE,
+        i256::from_i128(i128::MIN),
+        i256::from_parts(0x0123_4567_89ab_cdef, -42),
+        i256::MAX,
+        i256::MIN,
+    ];
+    for v in values {
+        let n = BigInt::from(v);
+        assert_eq!(n.to_string(), v.to_string());
+        assert_eq!(i256::try_from(&n).unwrap(), v);
+        assert_eq!(i256::try_from(n).unwrap(), v);
+    }
+}
+
+#[test]
+fn test_bigint_to_i256_overflow() {
+    let max = (BigInt::one() << 255u32) - 1;
+    let min = -(BigInt::one() << 255u32);
+    assert_eq!(i256::try_from(&max).unwrap(), i256::MAX);
+    assert_eq!(i256::try_from(&min).unwrap(), i256::MIN);
+
+    assert!(i256::try_from(&max + 1u8).is_err());
+    let err = i256::try_from(&min - 1u8).unwrap_err();
+    assert_eq!(err.into_original(), &min - 1u8);
+}
@@ -11,7 +11,8 @@ use std::{f32, f64};
 
 use num_integer::Integer;
 use num_traits::{
-    pow, Euclid, FromBytes, FromPrimitive, Num, One, Pow, Signed, ToBytes, ToPrimitive, Zero,
+    pow, CheckedEuclid, Euclid, FromBytes, FromPrimitive, Num, One, Pow, Signed, ToBytes,
+    ToPrimitive, Zero,
 };
 
 mod consts;
@@ -182,6 +183,13 @@ fn test_signed_bytes_le_round_trip() {
     }
 }
 
+#[test]
+fn test_power_of_two() {
+    assert_eq!(BigInt::power_of_two(0), BigInt::one());
+    assert_eq!(BigInt::power_of_two(64), BigInt::from(1i128 << 64));
+    assert_eq!(BigInt::power_of_two(200).sign(), Plus);
+}
+
 #[test]
 fn test_cmp() {
     let vs: [&[u32]; 4] = [&[2_u32], &[1, 1], &[2, 1], &[1, 1, 1]];
@@ -246,6 +254,16 @@ fn test_hash() {
     assert!(hash(&c) != hash(&f));
 }
 
+#[test]
+fn test_hash_matches_i64() {
+    for n in [0, 1, -1, i64::MAX, i64::MIN, i64::from(u32::MAX) + 1] {
+        assert_eq!(hash(&BigInt::from(n)), hash(&n));
+    }
+    let big = BigInt::from(i64::MIN) - 1u8;
+    assert_ne!(hash(&big), hash(&-big.clone()));
+    assert_eq!(hash(&big), hash(&(BigInt::from(i64::MIN) - 1u8)));
+}
+
 #[test]
 fn test_convert_i64() {
     fn check(b1: BigInt, i: i64) {
@@ -308,6 +326,55 @@ fn test_convert_i128() {
     );
 }
 
+#[test]
+fn test_try_from_error_kind() {
+    use num_bigint::TryFromBigIntErrorKind::{Negative, Overflow, Zero};
+    use std::convert::TryFrom;
+    use std::num::{NonZeroI128, NonZeroI8, NonZeroU64, NonZeroUsize};
+
+    assert_eq!(
+        u8::try_from(&BigInt::from(256)).unwrap_err().kind(),
+        Overflow
+    );
+    assert_eq!(
+        u8::try_from(&BigInt::from(-1)).unwrap_err().kind(),
+        Negative
+    );
+    assert_eq!(
+        i8::try_from(&BigInt::from(-129)).unwrap_err().kind(),
+        Overflow
+    );
+    assert_eq!(
+        BigUint::try_from(&BigInt::from(-1)).unwrap_err().kind(),
+        Negative
+    );
+    assert_eq!(
+        BigUint::try_from(BigInt::from(-1)).unwrap_err().kind(),
+        Negative
+    );
+    assert_eq!(
+        BigUint::try_from(-1i32).unwrap_err().to_string(),
+        "cannot convert negative big integer to unsigned integer"
+    );
+
+    let n = BigInt::from(-5);
+    assert_eq!(NonZeroI8::try_from(&n), Ok(NonZeroI8::new(-5).unwrap()));
+    assert_eq!(
+        NonZeroI128::try_from(n.clone() << 100u32),
+        Ok(NonZeroI128::new(-5 << 100).unwrap())
+    );
+    let err = NonZeroU64::try_from(n.clone()).unwrap_err();
+    assert_eq!(err.kind(), Negative);
+    assert_eq!(err.into_original(), n);
+    let err = NonZeroUsize::try_from(BigInt::zero()).unwrap_err();
+    assert_eq!(err.kind(), Zero);
+    assert_eq!(err.into_original(), BigInt::zero());
+    assert_eq!(
+        NonZeroI8::try_from(&BigInt::from(128)).unwrap_err().kind(),
+        Overflow
+    );
+}
+
 #[test]
 fn test_convert_u64() {
     fn check(b1: BigInt, u: u64) {
@@ -563,6 +630,179 @@ fn test_convert_f64() {
     );
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_cmp_f64() {
+    let x = BigInt::from(-3);
+    assert!(x == -3.0);
+    assert!(-3.0 == x);
+    assert!(x != -3.5);
+    assert!(x > -3.5);
+    assert!(x < -2.5);
+    assert!(-3.5 < x);
+    assert!(-2.5 > x);
+    assert!(BigInt::zero() == -0.0);
+    assert!(x > f64::NEG_INFINITY);
+    assert!(x < f64::INFINITY);
+    assert_eq!(x.partial_cmp(&f64::NAN), None);
+    assert_eq!(f64::NAN.partial_cmp(&x), None);
+    assert!(x != f64::NAN);
+
+    // values that round to the same f64 still compare exactly
+    let big = BigInt::from(1i64 << 53);
+    let f = big.to_f64().unwrap();
+    let next = &big + 1u8;
+    assert!(big == f);
+    assert_eq!(next.to_f64(), Some(f));
+    assert!(next > f);
+    assert!(-next < -f);
+
+    let huge = -(BigInt::one() << 1100u32);
+    assert!(huge < f64::MIN);
+    assert!(huge > f64::NEG_INFINITY);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_to_f64_checked() {
+    use num_bigint::Exactness::{Exact, Overflow, Rounded};
+
+    assert_eq!(BigInt::from(-3).to_f64_checked(), (-3.0, Exact));
+    assert_eq!(
+        BigInt::from(-(1i64 << 53) - 1).to_f64_checked(),
+        (-9_007_199_254_740_992.0, Rounded)
+    );
+    assert_eq!(
+        (-(BigInt::one() << 1100u32)).to_f64_checked(),
+        (f64::NEG_INFINITY, Overflow)
+    );
+}
+
+#[test]
+fn test_try_from_f64() {
+    use std::convert::TryFrom;
+
+    assert_eq!(BigInt::try_from(-0.0), Ok(BigInt::zero()));
+    assert_eq!(BigInt::try_from(-42.0), Ok(BigInt::from(-42)));
+    assert_eq!(
+        BigInt::try_from(f64::MIN),
+        Ok(BigInt::from_f64(f64::MIN).unwrap())
+    );
+
+    let err = |x: f64| BigInt::try_from(x).unwrap_err().to_string();
+    assert_eq!(
+        err(-2.5),
+        "cannot convert float with a fractional part to integer"
+    );
+    assert_eq!(
+        err(1e-300),
+        "cannot convert float with a fractional part to integer"
+    );
+    assert_eq!(
+        err(f64::NAN),
+        "cannot convert NaN or infinite float to integer"
+    );
+    assert_eq!(
+        err(f64::NEG_INFINITY),
+        "cannot convert NaN or infinite float to integer"
+    );
+}
+
+#[test]
+fn test_from_f64_round() {
+    use num_bigint::RoundingMode::{Ceil, Floor, HalfEven, Trunc};
+
+    let cases: &[(f64, [i64; 4])] = &[
+        // value, [Floor, Ceil, Trunc, HalfEven]
+        (0.0, [0, 0, 0, 0]),
+        (-0.0, [0, 0, 0, 0]),
+        (0.5, [0, 1, 0, 0]),
+        (1.5, [1, 2, 1, 2]),
+        (2.5, [2, 3, 2, 2]),
+        (2.6, [2, 3, 2, 3]),
+        (-0.5, [-1, 0, 0, 0]),
+        (-1.5, [-2, -1, -1, -2]),
+        (-2.5, [-3, -2, -2, -2]),
+        (-2.4, [-3, -2, -2, -2]),
+        (f64::MIN_POSITIVE, [0, 1, 0, 0]),
+        (
+            4_503_599_627_370_495.5,
+            [
+                4_503_599_627_370_495,
+                4_503_599_627_370_496,
+                4_503_599_627_370_495,
+                4_503_599_627_370_496,
+            ],
+        ),
+        (9_007_199_254_740_992.0, [9_007_199_254_740_992; 4]),
+    ];
+    for &(x, expected) in cases {
+        for (mode, &e) in [Floor, Ceil, Trunc, HalfEven].into_iter().zip(&expected) {
+            assert_eq!(
+                BigInt::from_f64_round(x, mode),
+                Some(BigInt::from(e)),
+                "{x} {mode:?}"
+            );
+        }
+        assert_eq!(BigInt::from_f64_floor(x), Some(BigInt::from(expected[0])));
+        assert_eq!(BigInt::from_f64_ceil(x), Some(BigInt::from(expected[1])));
+        assert_eq!(BigInt::from_f64_trunc(x), Some(BigInt::from(expected[2])));
+    }
+
+    let big = BigInt::from_f64(f64::MAX).unwrap();
+    assert_eq!(BigInt::from_f64_round(f64::MAX, HalfEven), Some(big));
+    for mode in [Floor, Ceil, Trunc, HalfEven] {
+        assert_eq!(BigInt::from_f64_round(f64::NAN, mode), None);
+        assert_eq!(BigInt::from_f64_round(f64::INFINITY, mode), None);
+        assert_eq!(BigInt::from_f64_round(f64::NEG_INFINITY, mode), None);
+    }
+    assert_eq!(BigInt::from_f64_floor(f64::NAN), None);
+    assert_eq!(BigInt::from_f64_ceil(f64::INFINITY), None);
+    assert_eq!(BigInt::from_f64_trunc(f64::NEG_INFINITY), None);
+}
+
+#[test]
+fn test_div_round() {
+    use num_bigint::RoundingMode::{Ceil, Floor, HalfEven, HalfUp, Trunc};
+
+    // Small quotients are exact enough as floats for `from_f64_round` to be the reference.
+    for a in -40i32..=40 {
+        for b in (-8i32..=8).filter(|&b| b != 0) {
+            let x = f64::from(a) / f64::from(b);
+            for mode in [Floor, Ceil, Trunc, HalfUp, HalfEven] {
+                let q = BigInt::from(a).div_round(&BigInt::from(b), mode);
+                assert_eq!(Some(q), BigInt::from_f64_round(x, mode), "{a}/{b} {mode:?}");
+            }
+        }
+    }
+
+    let d = BigInt::from(10).pow(40u32);
+    let q = BigInt::from(3).pow(100u32);
+    let exact: BigInt = &q * &d;
+    let tie: BigInt = &exact + &d / 2;
+    let next: BigInt = &q + 1u8;
+    let one = BigInt::one();
+    for (n, mode, expected) in [
+        (&tie, HalfEven, &next),
+        (&(&tie + &d), HalfEven, &next),
+        (&tie, HalfUp, &next),
+        (&(&tie - 1), HalfUp, &q),
+        (&-&tie, HalfUp, &-&next),
+        (&(-&exact - 1), Floor, &-&next),
+        (&(-&exact - 1), Ceil, &-&q),
+        (&exact, Ceil, &q),
+        (&one, Ceil, &one),
+    ] {
+        assert_eq!(n.div_round(&d, mode), *expected, "{mode:?}");
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_round_zero() {
+    let _ = BigInt::from(1).div_round(&BigInt::from(0), num_bigint::RoundingMode::HalfEven);
+}
+
 #[test]
 fn test_convert_to_biguint() {
     fn check(n: BigInt, ans_1: BigUint) {
@@ -960,6 +1200,26 @@ fn test_div_rem_euclid() {
     }
 }
 
+#[test]
+fn test_checked_euclid() {
+    // Generic code over the num-traits Euclidean division agrees with primitive integers.
+    fn euclid<T: CheckedEuclid + PartialEq>(a: &T, b: &T) -> Option<(T, T)> {
+        let q = a.checked_div_euclid(b)?;
+        let r = a.checked_rem_euclid(b)?;
+        let (q2, r2) = a.checked_div_rem_euclid(b)?;
+        assert!(q == q2 && r == r2);
+        Some((q, r))
+    }
+
+    for a in -20i64..=20 {
+        for b in -7i64..=7 {
+            let big = euclid(&BigInt::from(a), &BigInt::from(b));
+            let prim = euclid(&a, &b).map(|(q, r)| (BigInt::from(q), BigInt::from(r)));
+            assert_eq!(big, prim, "{a} {b}");
+        }
+    }
+}
+
 #[test]
 fn test_checked_add() {
     for elm in SUM_TRIPLES {
@@ -1071,6 +1331,155 @@ fn test_gcd() {
     check(-4, -2, 2);
 }
 
+#[test]
+#[allow(clippy::cast_possible_truncation)]
+fn test_to_wrapping() {
+    let values = [
+        0i128,
+        1,
+        -1,
+        1125,
+        -1125,
+        i128::from(i64::MIN),
+        i128::MAX,
+        i128::MIN,
+    ];
+    for &v in &values {
+        for shift in [0u32, 1, 63, 64, 100] {
+            let x = BigInt::from(v) << shift;
+            let expected = v.wrapping_shl(shift);
+            assert_eq!(x.to_i128_wrapping(), expected, "{x}");
+            assert_eq!(x.to_i64_wrapping(), expected as i64, "{x}");
+        }
+    }
+
+    // Bits beyond 128 are dropped, including the borrow of a negative value.
+    let x = -(BigInt::from(1) << 200u32) - 5i32;
+    assert_eq!(x.to_i128_wrapping(), -5);
+    assert_eq!(x.to_i64_wrapping(), -5);
+    assert_eq!(x.magnitude().to_u64_wrapping(), 5);
+}
+
+#[test]
+fn test_eval_poly() {
+    let xs = [
+        BigInt::zero(),
+        BigInt::one(),
+        -BigInt::one(),
+        BigInt::from(3),
+        -(BigInt::one() << 70u32) - 5i32,
+    ];
+    // Lengths on both sides of the split threshold.
+    for len in [0usize, 1, 2, 63, 64, 65, 200, 300] {
+        let coeffs: Vec<BigInt> = (0..len)
+            .map(|i| (BigInt::from(i * 7919 % 1000) - 500i32) << (i % 3 * 40))
+            .collect();
+        for x in &xs {
+            let mut expected = BigInt::zero();
+            let mut power = BigInt::one();
+            for c in &coeffs {
+                expected += c * &power;
+                power *= x;
+            }
+            assert_eq!(BigInt::eval_poly(&coeffs, x), expected);
+        }
+    }
+}
+
+#[test]
+fn test_addmul_submul_assign() {
+    // Sizes cover long multiplication, half-Karatsuba, Karatsuba and Toom-3.
+    let mut values = vec![BigInt::zero()];
+    for bits in [0u32, 1, 64, 100, 2000, 5000, 20_000] {
+        let x = (BigInt::one() << bits) / 3i32 + 1i32;
+        values.push(-&x);
+        values.push(x);
+    }
+    for acc in &values {
+        for a in &values {
+            for b in &values {
+                let mut sum = acc.clone();
+                sum.addmul_assign(a, b);
+                assert_eq!(sum, acc + a * b);
+
+                let mut diff = acc.clone();
+                diff.submul_assign(a, b);
+                assert_eq!(diff, acc - a * b);
+            }
+        }
+    }
+
+    // Exact cancellation leaves a canonical zero.
+    let mut x = BigInt::from(42);
+    x.submul_assign(&BigInt::from(6), &BigInt::from(7));
+    assert_eq!(x, BigInt::zero());
+    assert_eq!(x.sign(), NoSign);
+}
+
+#[test]
+fn test_checked_pow() {
+    let x = BigInt::from(-3);
+    assert_eq!(x.checked_pow(3, 5), Some(BigInt::from(-27)));
+    assert_eq!(x.checked_pow(4, 7), Some(BigInt::from(81)));
+    assert_eq!(x.checked_pow(4, 6), None);
+    assert_eq!(
+        x.checked_pow_big(&BigUint::from(5u8), 8),
+        Some(BigInt::from(-243))
+    );
+    assert_eq!(x.pow_big(&BigUint::from(5u8)), BigInt::from(-243));
+    assert_eq!(
+        BigInt::from(-1).checked_pow_big(&(BigUint::from(1u8) << 300u32), 1),
+        Some(BigInt::from(1))
+    );
+}
+
+#[test]
+fn test_extended_gcd_large() {
+    // The plain extended Euclidean algorithm, for comparison.
+    fn euclid(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+        let (mut r0, mut r1) = (a.clone(), b.clone());
+        let (mut s0, mut s1) = (BigInt::one(), BigInt::zero());
+        let (mut t0, mut t1) = (BigInt::zero(), BigInt::one());
+        while !r1.is_zero() {
+            let q = &r0 / &r1;
+            let r2 = &r0 - &q * &r1;
+            let s2 = &s0 - &q * &s1;
+            let t2 = &t0 - &q * &t1;
+            (r0, r1, s0, s1, t0, t1) = (r1, r2, s1, s2, t1, t2);
+        }
+        (r0, s0, t0)
+    }
+
+    let fib = |n: usize| {
+        let (mut a, mut b) = (BigInt::zero(), BigInt::one());
+        for _ in 0..n {
+            (a, b) = (b.clone(), a + b);
+        }
+        a
+    };
+    let three = BigInt::from(3).pow(500u32);
+    let seven = BigInt::from(7).pow(300u32);
+    let pairs = [
+        (fib(1000), fib(999)),
+        (three.clone() * 35, seven.clone() * 15),
+        (three.clone() + 1, seven.clone() - 1),
+        (seven, three.clone()),
+        (three.clone() * 12, BigInt::from(18)),
+        (three, BigInt::zero()),
+    ];
+    for (a, b) in &pairs {
+        let (gcd, x, y) = euclid(a, b);
+        let e = a.extended_gcd(b);
+        assert_eq!((&e.gcd, &e.x, &e.y), (&gcd, &x, &y));
+
+        for (a, b) in [(-a, b.clone()), (a.clone(), -b), (-a, -b)] {
+            let e = a.extended_gcd(&b);
+            assert_eq!(e.gcd, gcd);
+            assert_eq!(&e.x * &a + &e.y * &b, gcd);
+        }
+    }
+}
+
 #[test]
 fn test_lcm() {
     fn check(a: isize, b: isize, c: isize) {
@@ -1472,3 +1881,112 @@ fn test_set_bit() {
     x.set_bit(0, false);
     assert_eq!(x, BigInt::from_biguint(Minus, BigUint::one() << 200));
 }
+
+#[test]
+fn test_decimal128() {
+    let decode = |bits: u128| BigInt::from_decimal128(bits.to_le_bytes());
+    let encode = |n: &BigInt| n.try_to_decimal128().map(u128::from_le_bytes);
+
+    // Canonical encodings from the BSON corpus.
+    let max_coefficient = BigInt::from(10).pow(34u32) - 1u8;
+    let cases = [
+        (BigInt::zero(), 0x3040_0000_0000_0000_0000_0000_0000_0000),
+        (BigInt::from(1), 0x3040_0000_0000_0000_0000_0000_0000_0001),
+        (BigInt::from(-1), 0xb040_0000_0000_0000_0000_0000_0000_0001),
+        (
+            max_coefficient.clone(),
+            0x3041_ed09_bead_87c0_378d_8e63_ffff_ffff,
+        ),
+        (
+            BigInt::from(10).pow(34u32),
+            0x3042_314d_c644_8d93_38c1_5b0a_0000_0000,
+        ),
+    ];
+    for (n, bits) in &cases {
+        assert_eq!(encode(n).ok(), Some(*bits), "{n}");
+        assert_eq!(decode(*bits).as_ref(), Some(n), "{n}");
+    }
+
+    // The largest finite value, and one step beyond in either direction.
+    let max = &max_coefficient * BigInt::from(10).pow(6111u32);
+    assert_eq!(decode(encode(&max).unwrap()), Some(max.clone()));
+    assert_eq!(decode(encode(&-&max).unwrap()), Some(-&max));
+    assert!(encode(&(&max * 10u8)).is_err());
+    assert!(encode(&(&max + 1u8)).is_err());
+    assert!(encode(&(BigInt::one() << 30000u32)).is_err());
+
+    // Other encodings of integers.
+    assert_eq!(
+        decode(0x303e_0000_0000_0000_0000_0000_0000_000a),
+        Some(BigInt::from(1))
+    );
+    assert_eq!(
+        decode(0xb040_0000_0000_0000_0000_0000_0000_0000),
+        Some(BigInt::zero())
+    );
+    assert_eq!(
+        decode(0x0000_0000_0000_0000_0000_0000_0000_0000),
+        Some(BigInt::zero())
+    );
+    // Non-canonical coefficients, in either form, are zero.
+    assert_eq!(
+        decode(0x3041_ed09_bead_87c0_378d_8e64_0000_0000),
+        Some(BigInt::zero())
+    );
+    assert_eq!(
+        decode(0x6c10_0000_0000_0000_0000_0000_0000_0001),
+        Some(BigInt::zero())
+    );
+
+    // Fractions, infinities and NaNs.
+    assert_eq!(decode(0x303e_0000_0000_0000_0000_0000_0000_000f), None);
+    assert_eq!(decode(0x0000_0000_0000_0000_0000_0000_0000_0001), None);
+    assert_eq!(decode(0x7800_0000_0000_0000_0000_0000_0000_0000), None);
+    assert_eq!(decode(0xf800_0000_0000_0000_0000_0000_0000_0000), None);
+    assert_eq!(decode(0x7c00_0000_0000_0000_0000_0000_0000_0000), None);
+    assert_eq!(decode(0x7e00_0000_0000_0000_0000_0000_0000_0000), None);
+}
+
+#[test]
+fn test_ssh_mpint() {
+    // The examples of RFC 4251, section 5.
+    let cases: &[(i64, &[u8])] = &[
+        (0, &[0, 0, 0, 0]),
+        (
+            0x09a3_78f9_b2e3_32a7,
+            &[0, 0, 0, 8, 0x09, 0xa3, 0x78, 0xf9, 0xb2, 0xe3, 0x32, 0xa7],
+        ),
+        (0x80, &[0, 0, 0, 2, 0x00, 0x80]),
+        (-0x1234, &[0, 0, 0, 2, 0xed, 0xcc]),
+        (-0xdead_beef, &[0, 0, 0, 5, 0xff, 0x21, 0x52, 0x41, 0x11]),
+        (-1, &[0, 0, 0, 1, 0xff]),
+        (-0x80, &[0, 0, 0, 1, 0x80]),
+        (0x7f, &[0, 0, 0, 1, 0x7f]),
+    ];
+    for &(n, wire) in cases {
+        let n = BigInt::from(n);
+        assert_eq!(n.to_ssh_mpint(), wire, "{n}");
+        assert_eq!(BigInt::from_ssh_mpint(wire), Some((n, &[][..])));
+    }
+
+    let n = -(BigInt::one() << 1000u32) + 12345u32;
+    let mut wire = n.to_ssh_mpint();
+    wire.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(BigInt::from_ssh_mpint(&wire), Some((n, &[1, 2, 3][..])));
+}
+
+#[test]
+fn test_ssh_mpint_invalid() {
+    let invalid: &[&[u8]] = &[
+        &[],
+        &[0, 0, 0],
+        &[0, 0, 0, 2, 0x12],
+        &[0, 0, 0, 1, 0x00],
+        &[0, 0, 0, 2, 0x00, 0x7f],
+        &[0, 0, 0, 2, 0xff, 0x80],
+        &[0xff, 0xff, 0xff, 0xff, 0x01],
+    ];
+    for &wire in invalid {
+        assert_eq!(BigInt::from_ssh_mpint(wire), None, "{wire:?}");
+    }
+}
@@ -1,6 +1,8 @@
 use num_bigint::Sign::Plus;
+use num_bigint::{
+    binary_split, expr, Accumulator, ArcBigUint, BigUint, BigUintSlice, PreparedDivisor, ToBigUint,
+};
 use num_bigint::{BigInt, ToBigInt};
-use num_bigint::{BigUint, ToBigUint};
 use num_integer::Integer;
 
 use std::cmp::Ordering::{Equal, Greater, Less};
@@ -11,8 +13,8 @@ use std::str::FromStr;
 use std::{f32, f64};
 
 use num_traits::{
-    pow, CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Euclid, FromBytes, FromPrimitive, Num,
-    One, Pow, ToBytes, ToPrimitive, Zero,
+    pow, CheckedAdd, CheckedDiv, CheckedEuclid, CheckedMul, CheckedSub, Euclid, FromBytes,
+    FromPrimitive, Num, One, Pow, ToBytes, ToPrimitive, Zero,
 };
 
 mod consts;
@@ -87,6 +89,60 @@ fn test_to_bytes_le() {
     assert_eq!(b.to_bytes_le(), [0, 2, 0, 0, 0, 0, 0, 0, 1]);
 }
 
+#[test]
+fn test_rlp() {
+    // The examples of the Ethereum documentation.
+    let cases: &[(u64, &[u8])] = &[
+        (0, &[0x80]),
+        (0x0f, &[0x0f]),
+        (0x7f, &[0x7f]),
+        (0x80, &[0x81, 0x80]),
+        (1024, &[0x82, 0x04, 0x00]),
+        (
+            u64::MAX,
+            &[0x88, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        ),
+    ];
+    for &(n, wire) in cases {
+        let n = BigUint::from(n);
+        assert_eq!(n.to_rlp(), wire, "{n}");
+        assert_eq!(BigUint::from_rlp(wire), Some((n, &[][..])));
+    }
+
+    // 55 bytes is the last of the short form, and 56 the first of the long form.
+    let n = (BigUint::one() << 440u32) - 1u8;
+    assert_eq!(n.to_rlp()[0], 0xb7);
+    let n = BigUint::one() << 440u32;
+    assert_eq!(n.to_rlp()[..2], [0xb8, 56]);
+    let n = BigUint::one() << 8000u32;
+    let mut wire = n.to_rlp();
+    assert_eq!(wire[..3], [0xb9, 0x03, 0xe9]);
+    wire.extend_from_slice(&[1, 2, 3]);
+    assert_eq!(BigUint::from_rlp(&wire), Some((n, &[1, 2, 3][..])));
+}
+
+#[test]
+fn test_rlp_invalid() {
+    let mut long = vec![0xb8, 55];
+    long.extend_from_slice(&[1; 55]);
+    let invalid: &[&[u8]] = &[
+        &[],
+        &[0x00],
+        &[0x81],
+        &[0x82, 0x01],
+        &[0x81, 0x00],
+        &[0x81, 0x7f],
+        &[0x82, 0x00, 0x01],
+        &long,
+        &[0xb9, 0x00, 0x38],
+        &[0xc0],
+        &[0xc1, 0x01],
+    ];
+    for &wire in invalid {
+        assert_eq!(BigUint::from_rlp(wire), None, "{wire:?}");
+    }
+}
+
 #[test]
 fn test_cmp() {
     let data: [&[_]; 7] = [&[], &[1], &[2], &[!0], &[0, 1], &[2, 1], &[1, 1, 1]];
@@ -145,6 +201,50 @@ fn test_hash() {
     assert!(hash(&d) != hash(&e));
 }
 
+#[test]
+fn test_hash_matches_u64() {
+    for n in [0, 1, u64::from(u32::MAX), u64::from(u32::MAX) + 1, u64::MAX] {
+        assert_eq!(hash(&BigUint::from(n)), hash(&n));
+    }
+    let big = BigUint::from(u64::MAX) + 1u8;
+    assert_ne!(hash(&big), hash(&0u64));
+    assert_eq!(hash(&big), hash(&BigUint::new(vec![0, 0, 1])));
+}
+
+#[test]
+fn test_ops_into() {
+    let values: Vec<BigUint> = [
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(u64::MAX),
+        BigUint::from(u64::MAX) + 1u8,
+        (BigUint::one() << 1000u32) - 1u8,
+        BigUint::from(12345u32).pow(300u32),
+    ]
+    .into();
+
+    let mut out = BigUint::from(u32::MAX).pow(500u32);
+    for a in &values {
+        for b in &values {
+            BigUint::add_into(a, b, &mut out);
+            assert_eq!(out, a + b);
+            BigUint::mul_into(a, b, &mut out);
+            assert_eq!(out, a * b);
+            if a >= b {
+                BigUint::sub_into(a, b, &mut out);
+                assert_eq!(out, a - b);
+            }
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "Cannot subtract b from a because b is larger than a.")]
+fn test_sub_into_underflow() {
+    let mut out = BigUint::zero();
+    BigUint::sub_into(&BigUint::one(), &BigUint::from(2u8), &mut out);
+}
+
 // LEFT, RIGHT, AND, OR, XOR
 #[allow(clippy::type_complexity)]
 const BIT_TESTS: &[(&[u32], &[u32], &[u32], &[u32], &[u32])] = &[
@@ -528,6 +628,34 @@ fn test_convert_i64() {
     assert_eq!(BigUint::new(vec![N1, N1, N1]).to_i64(), None);
 }
 
+#[test]
+fn test_try_from_nonzero() {
+    use num_bigint::TryFromBigIntErrorKind::{Overflow, Zero};
+    use std::convert::TryFrom;
+    use std::num::{NonZeroI16, NonZeroU128, NonZeroU8};
+
+    let n = BigUint::from(200u8);
+    assert_eq!(NonZeroU8::try_from(&n), Ok(NonZeroU8::new(200).unwrap()));
+    assert_eq!(
+        NonZeroI16::try_from(n.clone()),
+        Ok(NonZeroI16::new(200).unwrap())
+    );
+    assert_eq!(
+        NonZeroU128::try_from(BigUint::from(u128::MAX)),
+        Ok(NonZeroU128::new(u128::MAX).unwrap())
+    );
+
+    let err = NonZeroU8::try_from(n << 1u8).unwrap_err();
+    assert_eq!(err.kind(), Overflow);
+    assert_eq!(err.into_original(), BigUint::from(400u16));
+    let err = NonZeroU128::try_from(&BigUint::zero()).unwrap_err();
+    assert_eq!(err.kind(), Zero);
+    assert_eq!(
+        err.to_string(),
+        "cannot convert zero big integer to non-zero integer"
+    );
+}
+
 #[test]
 fn test_convert_i128() {
     fn check(b1: BigUint, i: i128) {
@@ -770,6 +898,136 @@ fn test_convert_f64() {
     assert_eq!((BigUint::one() << 1024u16).to_f64(), Some(f64::INFINITY));
 }
 
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_to_f64_exp() {
+    assert_eq!(BigUint::zero().to_f64_exp(), (0.0, 0));
+    assert_eq!(BigUint::one().to_f64_exp(), (0.5, 1));
+    assert_eq!(BigUint::from(6u8).to_f64_exp(), (0.75, 3));
+    assert_eq!((BigUint::one() << 5000u32).to_f64_exp(), (0.5, 5001));
+
+    // rounds up to the next power of two
+    let x = (BigUint::one() << 3000u32) - 1u8;
+    assert_eq!(x.to_f64_exp(), (0.5, 3001));
+
+    // ties to even, at different offsets to the BigDigits
+    for shift in 0..130u64 {
+        let tie = ((BigUint::one() << 53u32) + 1u8) << shift;
+        assert_eq!(tie.to_f64_exp(), (0.5, 54 + shift));
+        let above = (((BigUint::one() << 53u32) + 1u8) << (shift + 1)) + 1u8;
+        let (m, e) = above.to_f64_exp();
+        assert_eq!(e, 55 + shift);
+        assert_eq!(m, 0.5 + f64::EPSILON / 2.0);
+    }
+
+    // agrees with to_f64 where it is finite
+    let x = BigUint::from(0x1234_5678_9abc_def1u64) << 900u32;
+    let (m, e) = x.to_f64_exp();
+    assert_eq!(
+        m * 2f64.powi(i32::try_from(e).unwrap()),
+        x.to_f64().unwrap()
+    );
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_cmp_f64() {
+    let x = BigUint::from(7u8);
+    assert!(x == 7.0);
+    assert!(7.0 == x);
+    assert!(x > 6.99);
+    assert!(x < 7.01);
+    assert!(6.99 < x);
+    assert!(x > -1.0);
+    assert!(x > f64::NEG_INFINITY);
+    assert!(x < f64::INFINITY);
+    assert!(BigUint::zero() == -0.0);
+    assert!(BigUint::zero() > -f64::MIN_POSITIVE);
+    assert_eq!(x.partial_cmp(&f64::NAN), None);
+
+    let max = BigUint::from_f64(f64::MAX).unwrap();
+    assert!(max == f64::MAX);
+    assert!(&max + 1u8 > f64::MAX);
+    assert!(&max - 1u8 < f64::MAX);
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_to_f64_checked() {
+    use num_bigint::Exactness::{Exact, Overflow, Rounded};
+
+    assert_eq!(BigUint::zero().to_f64_checked(), (0.0, Exact));
+    assert_eq!(
+        BigUint::from(u64::MAX).to_f64_checked(),
+        (2f64.powi(64), Rounded)
+    );
+    assert_eq!(
+        (BigUint::from((1u64 << 53) - 1) << 971u32).to_f64_checked(),
+        (f64::MAX, Exact)
+    );
+    assert_eq!(
+        (BigUint::one() << 1024u32).to_f64_checked(),
+        (f64::INFINITY, Overflow)
+    );
+
+    // ties to even, in both directions
+    let base = BigUint::one() << 53u32;
+    for (offset, expected) in [(0u8, 0u8), (1, 0), (2, 2), (3, 4), (5, 4), (6, 6)] {
+        let (value, exactness) = (&base + offset).to_f64_checked();
+        assert_eq!(value, 2f64.powi(53) + f64::from(expected));
+        assert_eq!(exactness, if offset % 2 == 0 { Exact } else { Rounded });
+    }
+}
+
+#[test]
+#[allow(clippy::float_cmp)]
+fn test_to_f32_ties_to_even() {
+    let base = BigUint::one() << 24u32;
+    for (offset, expected) in [(1u8, 0u8), (3, 4), (5, 4), (7, 8)] {
+        let value = (&base + offset).to_f32().unwrap();
+        assert_eq!(value, 2f32.powi(24) + f32::from(expected));
+    }
+}
+
+#[test]
+fn test_try_from_f64() {
+    use std::convert::TryFrom;
+
+    assert_eq!(BigUint::try_from(0.0), Ok(BigUint::zero()));
+    assert_eq!(BigUint::try_from(-0.0), Ok(BigUint::zero()));
+    assert_eq!(BigUint::try_from(42.0), Ok(BigUint::from(42u8)));
+    assert_eq!(
+        BigUint::try_from(f64::MAX),
+        Ok(BigUint::from_f64(f64::MAX).unwrap())
+    );
+
+    let err = |x: f64| BigUint::try_from(x).unwrap_err().to_string();
+    assert_eq!(
+        err(0.5),
+        "cannot convert float with a fractional part to integer"
+    );
+    assert_eq!(
+        err(-0.5),
+        "cannot convert float with a fractional part to integer"
+    );
+    assert_eq!(
+        err(f64::MIN_POSITIVE),
+        "cannot convert float with a fractional part to integer"
+    );
+    assert_eq!(
+        err(-1.0),
+        "cannot convert negative float to unsigned integer"
+    );
+    assert_eq!(
+        err(f64::NAN),
+        "cannot convert NaN or infinite float to integer"
+    );
+    assert_eq!(
+        err(f64::INFINITY),
+        "cannot convert NaN or infinite float to integer"
+    );
+}
+
 #[test]
 fn test_convert_to_bigint() {
     fn check(n: BigUint, ans: BigInt) {
@@ -994,6 +1252,25 @@ fn test_div_rem_euclid() {
     }
 }
 
+#[test]
+fn test_checked_euclid() {
+    fn euclid<T: CheckedEuclid + PartialEq>(a: &T, b: &T) -> Option<(T, T)> {
+        let q = a.checked_div_euclid(b)?;
+        let r = a.checked_rem_euclid(b)?;
+        let (q2, r2) = a.checked_div_rem_euclid(b)?;
+        assert!(q == q2 && r == r2);
+        Some((q, r))
+    }
+
+    for a in 0u64..=20 {
+        for b in 0u64..=7 {
+            let big = euclid(&BigUint::from(a), &BigUint::from(b));
+            let prim = euclid(&a, &b).map(|(q, r)| (BigUint::from(q), BigUint::from(r)));
+            assert_eq!(big, prim, "{a} {b}");
+        }
+    }
+}
+
 #[test]
 fn test_checked_add() {
     for elm in SUM_TRIPLES {
@@ -1129,6 +1406,649 @@ fn test_lcm() {
     check(8, 9, 72);
     check(11, 5, 55);
     check(99, 17, 1683);
+
+    // Operands of different lengths, in both orders.
+    let g = BigUint::from(3u8).pow(100u32);
+    let a = &g * BigUint::from(2u8).pow(300u32);
+    let b = &g * 1_000_003u32;
+    for (x, y) in [(&a, &b), (&b, &a)] {
+        let (gcd, lcm) = x.gcd_lcm(y);
+        assert_eq!(gcd, g);
+        assert_eq!(lcm, x * y / &g);
+        assert_eq!(lcm, x.lcm(y));
+    }
+}
+
+#[test]
+fn test_gcd_lcm_all() {
+    let big = |values: &[u32]| values.iter().map(|&v| BigUint::from(v)).collect::<Vec<_>>();
+
+    assert_eq!(BigUint::gcd_all(big(&[])), BigUint::zero());
+    assert_eq!(BigUint::gcd_all(big(&[0, 0]).iter()), BigUint::zero());
+    assert_eq!(
+        BigUint::gcd_all(big(&[0, 12, 18, 30]).iter()),
+        BigUint::from(6u8)
+    );
+    assert_eq!(BigUint::gcd_all(big(&[7, 5, 35])), BigUint::one());
+
+    // The early exit leaves the rest of the iterator alone.
+    let mut values = big(&[4, 9, 6, 12]).into_iter();
+    assert_eq!(BigUint::gcd_all(values.by_ref()), BigUint::one());
+    assert_eq!(values.len(), 2);
+
+    assert_eq!(BigUint::lcm_all(big(&[])), BigUint::one());
+    assert_eq!(BigUint::lcm_all(big(&[4, 0, 6]).iter()), BigUint::zero());
+    assert_eq!(
+        BigUint::lcm_all(big(&[4, 6, 10]).iter()),
+        BigUint::from(60u8)
+    );
+    let lcm = BigUint::lcm_all((1..=30u32).map(BigUint::from));
+    assert_eq!(lcm, BigUint::from(2_329_089_562_800u64));
+}
+
+#[test]
+fn test_div_exact() {
+    assert_eq!(
+        BigUint::zero().div_exact(&BigUint::from(7u8)),
+        BigUint::zero()
+    );
+    assert_eq!(
+        BigUint::from(42u8).div_exact(&BigUint::from(42u8)),
+        BigUint::one()
+    );
+
+    // Products of odd, even and single-digit factors across several lengths.
+    let factors: Vec<BigUint> = [1u32, 2, 3, 64, 100, 128, 500]
+        .iter()
+        .map(|&bits| (BigUint::one() << bits) / 7u32 + 3u32)
+        .chain([BigUint::from(6u8), BigUint::one() << 200u32])
+        .collect();
+    for a in &factors {
+        for b in &factors {
+            let product = a * b;
+            assert_eq!(product.div_exact(b), *a, "{a} * {b}");
+            assert_eq!(product.div_exact(a), *b, "{a} * {b}");
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_div_exact_zero() {
+    let _ = BigUint::one().div_exact(&BigUint::zero());
+}
+
+#[test]
+#[cfg(debug_assertions)]
+#[should_panic(expected = "the division was not exact")]
+fn test_div_exact_inexact() {
+    let _ = (BigUint::one() << 100u32).div_exact(&BigUint::from(3u8));
+}
+
+#[test]
+fn test_prepared_divisor() {
+    let values: Vec<BigUint> = [0u32, 1, 31, 32, 63, 64, 65, 100, 128, 300, 1000]
+        .iter()
+        .flat_map(|&bits| {
+            let x = BigUint::one() << bits;
+            [&x - 1u32, &x / 3u32 + 5u32, x]
+        })
+        .filter(|x| !x.is_zero())
+        .collect();
+    for d in &values {
+        let prepared = PreparedDivisor::new(d.clone());
+        assert_eq!(prepared.divisor(), d);
+        for n in values.iter().chain([&BigUint::zero()]) {
+            let expected = n.div_rem(d);
+            assert_eq!(prepared.div_rem(n), expected, "{n} / {d}");
+            assert_eq!(prepared.div(n), expected.0);
+            assert_eq!(prepared.rem(n), expected.1);
+        }
+    }
+}
+
+#[test]
+fn test_rem_no_quotient() {
+    let triples = DIV_REM_QUADRUPLES.iter().map(|&(a, b, c, _)| (a, b, c));
+    for (a_vec, b_vec, c_vec) in MUL_TRIPLES.iter().copied().chain(triples) {
+        let a = BigUint::from_slice(a_vec);
+        let b = BigUint::from_slice(b_vec);
+        let c = BigUint::from_slice(c_vec);
+        for (n, d) in [(&c, &a), (&c, &b), (&a, &b), (&b, &a)] {
+            if !d.is_zero() {
+                let r = n.div_rem(d).1;
+                assert_eq!(n.rem_no_quotient(d), r);
+                assert_eq!(n % d, r);
+                assert_eq!(n.clone() % d.clone(), r);
+            }
+        }
+    }
+
+    // Multi-digit divisors, normalized and not.
+    let n = (BigUint::one() << 500u32) / 7u32;
+    for bits in [64u32, 65, 127, 128, 200, 499, 500, 501] {
+        let d = (BigUint::one() << bits) - 3u32;
+        let r = &n - (&n / &d) * &d;
+        assert_eq!(n.rem_no_quotient(&d), r);
+        assert_eq!(PreparedDivisor::new(d).rem(&n), r);
+    }
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_rem_no_quotient_zero() {
+    let _ = BigUint::one().rem_no_quotient(&BigUint::zero());
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_prepared_divisor_zero() {
+    let _ = PreparedDivisor::new(BigUint::zero());
+}
+
+#[test]
+fn test_mul_pow() {
+    let values = [
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(12_345u32),
+        (BigUint::one() << 300u32) / 7u32,
+    ];
+    for x in &values {
+        for base in [0u32, 1, 2, 3, 10, 16, 40, 1_000_000_007, u32::MAX] {
+            for exp in [0u32, 1, 2, 13, 40, 100, 2000] {
+                let expected = x * BigUint::from(base).pow(exp);
+                assert_eq!(x.mul_pow(base, exp), expected, "{x} * {base}^{exp}");
+            }
+        }
+        for exp in [0u32, 1, 19, 20, 500] {
+            assert_eq!(x.mul_pow10(exp), x * BigUint::from(10u8).pow(exp));
+        }
+    }
+}
+
+#[test]
+#[allow(clippy::cast_possible_truncation)]
+fn test_to_wrapping() {
+    assert_eq!(BigUint::zero().to_u64_wrapping(), 0);
+    assert_eq!(BigUint::zero().to_u128_wrapping(), 0);
+    for &v in &[1u128, 1125, u128::from(u64::MAX), u128::MAX] {
+        for shift in [0u32, 1, 63, 64, 100, 128] {
+            let x = BigUint::from(v) << shift;
+            let expected = v.checked_shl(shift).unwrap_or(0);
+            assert_eq!(x.to_u128_wrapping(), expected, "{x}");
+            assert_eq!(x.to_u64_wrapping(), expected as u64, "{x}");
+        }
+    }
+}
+
+#[test]
+fn test_checked_pow() {
+    let three = BigUint::from(3u8);
+    for exp in 0u32..200 {
+        let power = BigUint::pow(&three, exp);
+        let bits = power.bits();
+        assert_eq!(three.checked_pow(exp, bits), Some(power.clone()));
+        assert_eq!(three.checked_pow(exp, bits - 1), None);
+        assert_eq!(three.pow_big(&BigUint::from(exp)), power);
+    }
+
+    let two = BigUint::from(2u8);
+    assert_eq!(two.checked_pow(64, 65), Some(BigUint::one() << 64u32));
+    assert_eq!(two.checked_pow(64, 64), None);
+
+    let huge = BigUint::one() << 200u32;
+    assert_eq!(two.checked_pow_big(&huge, u64::MAX), None);
+    assert_eq!(
+        BigUint::one().checked_pow_big(&huge, 1),
+        Some(BigUint::one())
+    );
+    assert_eq!(
+        BigUint::zero().checked_pow_big(&huge, 0),
+        Some(BigUint::zero())
+    );
+    assert_eq!(BigUint::zero().checked_pow(0, 0), None);
+    assert_eq!(BigUint::zero().pow_big(&huge), BigUint::zero());
+}
+
+#[test]
+fn test_addmul_assign() {
+    // Sizes cover long multiplication, half-Karatsuba, Karatsuba and Toom-3.
+    let values: Vec<BigUint> = [0u32, 1, 64, 100, 2000, 5000, 20_000]
+        .iter()
+        .map(|&bits| (BigUint::one() << bits) / 3u32 + 1u32)
+        .collect();
+    for acc in &values {
+        for a in &values {
+            for b in &values {
+                let mut sum = acc.clone();
+                sum.addmul_assign(a, b);
+                assert_eq!(sum, acc + a * b);
+            }
+        }
+    }
+
+    // Carries out of the top of a full accumulator.
+    let mut sum = (BigUint::one() << 256u32) - 1u32;
+    let max = (BigUint::one() << 128u32) - 1u32;
+    sum.addmul_assign(&max, &max);
+    assert_eq!(sum, (BigUint::one() << 256u32) - 1u32 + &max * &max);
+}
+
+#[test]
+fn test_split_at_bit() {
+    let values: Vec<BigUint> = [0u32, 1, 63, 64, 65, 200]
+        .iter()
+        .flat_map(|&bits| {
+            let ones = (BigUint::one() << bits) - 1u32;
+            let third = (BigUint::one() << bits) / 3u32 + 1u32;
+            vec![ones, third]
+        })
+        .collect();
+    for n in &values {
+        for k in [0u64, 1, 31, 32, 63, 64, 65, 128, 199, 200, 201, 1000] {
+            let (low, high) = n.split_at_bit(k);
+            assert_eq!(low, n % (BigUint::one() << k));
+            assert_eq!(high, n >> k);
+            assert_eq!(BigUint::from_parts(&low, &high, k), *n);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "the low part does not fit in 3 bits")]
+fn test_from_parts_overlap() {
+    let _ = BigUint::from_parts(&BigUint::from(8u8), &BigUint::one(), 3);
+}
+
+#[test]
+fn test_limb_window() {
+    let limbs = [3u64, 0, 0, u64::MAX, 5, 0, 9];
+    let n = limbs
+        .iter()
+        .rev()
+        .fold(BigUint::zero(), |acc, &d| (acc << 64u32) + d);
+    for start in 0..limbs.len() + 2 {
+        for end in start..limbs.len() + 2 {
+            let window = n.limb_window(start..end);
+            let expected = (&n >> (64 * start)) % (BigUint::one() << (64 * (end - start)));
+            assert_eq!(window, expected);
+            assert_eq!(window.to_biguint(), expected);
+            assert_eq!(window.bits(), expected.bits());
+            assert_eq!(window.is_zero(), expected.is_zero());
+            assert_eq!(
+                window.iter_u64_digits().collect::<Vec<_>>(),
+                expected.to_u64_digits()
+            );
+            assert_eq!(window.partial_cmp(&n), expected.partial_cmp(&n));
+        }
+    }
+    assert_eq!(n.limb_window(..), n);
+    assert_eq!(n.limb_window(1..=2), BigUintSlice::from(&BigUint::zero()));
+    assert!(n.limb_window(4..6) < n.limb_window(3..5));
+    assert_eq!(n.limb_window(6..).to_string(), "9");
+}
+
+#[test]
+#[should_panic(expected = "limb window starts at 3 but ends at 2")]
+fn test_limb_window_reversed() {
+    #[allow(clippy::reversed_empty_ranges)]
+    let _ = BigUint::one().limb_window(3..2);
+}
+
+#[test]
+fn test_to_str_radix_large() {
+    // Large enough to be split for parallel conversion with the `rayon` feature.
+    let n = BigUint::from(3u32).pow(300_000u32) - 1u32;
+    for radix in [3u32, 7, 10, 36] {
+        let s = n.to_str_radix(radix);
+        assert_eq!(BigUint::from_str_radix(&s, radix).unwrap(), n);
+    }
+    // Zeros at the split points must be kept.
+    let n = BigUint::from(10u32).pow(200_000u32) + 1u32;
+    let s = n.to_string();
+    assert_eq!(s.len(), 200_001);
+    assert!(s.starts_with("10000") && s.ends_with("00001"));
+}
+
+#[test]
+fn test_binary_split() {
+    assert_eq!(binary_split(7..8, |i| i * 10, |a, b| a + b), 70);
+
+    // Concatenation is not commutative, so this checks the leaves stay in order.
+    let digits = binary_split(
+        3..40,
+        |i| vec![i],
+        |mut a, b| {
+            a.extend(b);
+            a
+        },
+    );
+    assert_eq!(digits, (3..40).collect::<Vec<_>>());
+
+    let product = binary_split(1..101, BigUint::from, |a, b| a * b);
+    assert_eq!(product, (1..101u32).map(BigUint::from).product::<BigUint>());
+}
+
+#[test]
+#[should_panic(expected = "binary split of an empty range")]
+fn test_binary_split_empty() {
+    let _ = binary_split(3..3, |i| i, |a, b| a + b);
+}
+
+#[test]
+fn test_dot() {
+    assert_eq!(BigUint::dot(&[], &[]), BigUint::zero());
+
+    // Mixed sizes, with all-ones values to carry through the spare digits.
+    let xs: Vec<BigUint> = [0u32, 1, 64, 100, 2000, 5000, 64, 64]
+        .iter()
+        .map(|&bits| (BigUint::one() << bits) - 1u32)
+        .collect();
+    let ys: Vec<BigUint> = xs.iter().rev().cloned().collect();
+    let expected = xs
+        .iter()
+        .zip(&ys)
+        .fold(BigUint::zero(), |acc, (x, y)| acc + x * y);
+    assert_eq!(BigUint::dot(&xs, &ys), expected);
+
+    let ones = vec![(BigUint::one() << 128u32) - 1u32; 50];
+    assert_eq!(BigUint::dot(&ones, &ones), &ones[0] * &ones[0] * 50u32);
+}
+
+#[test]
+fn test_nonzero_biguint() {
+    use num_bigint::NonZeroBigUint;
+    use std::convert::TryFrom;
+    use std::num::NonZeroU64;
+
+    assert_eq!(NonZeroBigUint::new(BigUint::zero()), None);
+    let err = NonZeroBigUint::try_from(BigUint::zero()).unwrap_err();
+    assert_eq!(err.kind(), num_bigint::TryFromBigIntErrorKind::Zero);
+
+    let a = NonZeroBigUint::from(NonZeroU64::new(u64::MAX).unwrap());
+    let b = NonZeroBigUint::try_from(BigUint::from(6u8)).unwrap();
+    assert_eq!(*(&a + &b), BigUint::from(u64::MAX) + 6u8);
+    assert_eq!(*(a.clone() * b.clone()), BigUint::from(u64::MAX) * 6u8);
+    assert_eq!(*(&b + &BigUint::zero()), BigUint::from(6u8));
+    assert_eq!(*b.pow(3), BigUint::from(216u8));
+    assert_eq!(
+        *b.lcm(&NonZeroBigUint::from(NonZeroU64::new(4).unwrap())),
+        BigUint::from(12u8)
+    );
+    assert_eq!(*a.gcd(&b), BigUint::from(3u8));
+    assert_eq!(*NonZeroBigUint::one(), BigUint::one());
+
+    let x = BigUint::from(1000u16);
+    assert_eq!(&x / &b, BigUint::from(166u8));
+    assert_eq!(x % &b, BigUint::from(4u8));
+    assert_eq!(b.to_string(), "6");
+    assert_eq!(BigUint::from(b), BigUint::from(6u8));
+}
+
+#[test]
+fn test_to_string_approx() {
+    let ten = |exp: u32| BigUint::from(10u8).pow(exp);
+    let values = [
+        BigUint::zero(),
+        BigUint::from(7u8),
+        BigUint::from(123_456_789u32),
+        BigUint::from(u64::MAX),
+        BigUint::from(2u8).pow(10_000u32),
+        BigUint::from(3u8).pow(5_000u32),
+        ten(500),
+        ten(500) - 1u8,
+        ten(500) + 1u8,
+        ten(64) * 12_345u32,
+        BigUint::from(7u8).pow(3_000u32) * ten(40),
+    ];
+    for x in &values {
+        let s = x.to_string();
+        for k in [1, 2, 5, 19, 20, 21, 40, 100] {
+            let (digits, exp) = x.to_string_approx(k);
+            let len = Ord::min(k, s.len());
+            assert_eq!(digits, s[..len], "{x} to {k} digits");
+            assert_eq!(exp, (s.len() - len) as u64, "{x} to {k} digits");
+        }
+    }
+}
+
+#[test]
+fn test_odd_modulus() {
+    use num_bigint::OddModulus;
+
+    assert_eq!(OddModulus::new(BigUint::zero()), None);
+    assert_eq!(OddModulus::new(BigUint::from(10u8)), None);
+
+    for m in [
+        BigUint::one(),
+        BigUint::from(383u32),
+        (BigUint::one() << 127u32) - 1u8,
+        (BigUint::one() << 300u32) + 1u8,
+    ] {
+        let odd = OddModulus::new(m.clone()).unwrap();
+        assert_eq!(*odd, m);
+        for x in [
+            BigUint::zero(),
+            BigUint::from(271u32),
+            BigUint::from(7u8).pow(200u32),
+        ] {
+            let e = BigUint::from(65537u32);
+            assert_eq!(x.modpow_odd(&e, &odd), x.modpow(&e, &m));
+            assert_eq!(x.modinv_ct_odd(&odd), x.modinv_ct(&m));
+        }
+    }
+    let odd = OddModulus::new(BigUint::from(9u8)).unwrap();
+    assert_eq!(odd.to_string(), "9");
+    assert_eq!(BigUint::from(odd), BigUint::from(9u8));
+}
+
+#[test]
+fn test_cancellable() {
+    use num_bigint::Cancelled;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::Arc;
+    use std::thread;
+    use std::time::Duration;
+
+    let x = BigUint::from(7u8).pow(20_000u32);
+    let y = BigUint::from(5u8).pow(9_000u32);
+    let m = BigUint::from(3u8).pow(300u32) + 1u8;
+
+    let go = AtomicBool::new(false);
+    assert_eq!(x.try_mul_cancellable(&y, &go), Ok(&x * &y));
+    assert_eq!(x.try_div_rem_cancellable(&y, &go), Ok(x.div_rem(&y)));
+    assert_eq!(
+        x.try_to_str_radix_cancellable(10, &go),
+        Ok(x.to_str_radix(10))
+    );
+    assert_eq!(y.try_modpow_cancellable(&x, &m, &go), Ok(y.modpow(&x, &m)));
+    let even = &m + 1u8;
+    assert_eq!(
+        y.try_modpow_cancellable(&x, &even, &go),
+        Ok(y.modpow(&x, &even))
+    );
+
+    let stop = AtomicBool::new(true);
+    assert_eq!(x.try_mul_cancellable(&y, &stop), Err(Cancelled));
+    assert_eq!(x.try_div_rem_cancellable(&y, &stop), Err(Cancelled));
+    assert_eq!(x.try_to_str_radix_cancellable(10, &stop), Err(Cancelled));
+    assert_eq!(y.try_modpow_cancellable(&x, &m, &stop), Err(Cancelled));
+    assert_eq!(y.try_modpow_cancellable(&x, &even, &stop), Err(Cancelled));
+
+    // Values too small to reach a check finish regardless.
+    let small = BigUint::from(12345u32);
+    assert_eq!(
+        small.try_mul_cancellable(&small, &stop),
+        Ok(&small * &small)
+    );
+
+    // Work that would take far too long is stopped from another thread.
+    let huge = (BigUint::one() << 20_000_000u32) - 1u8;
+    let stop_later = || {
+        let flag = Arc::new(AtomicBool::new(false));
+        let setter = {
+            let flag = Arc::clone(&flag);
+            thread::spawn(move || {
+                thread::sleep(Duration::from_millis(20));
+                flag.store(true, Ordering::Relaxed);
+            })
+        };
+        (flag, setter)
+    };
+    let (flag, setter) = stop_later();
+    assert_eq!(huge.try_to_str_radix_cancellable(10, &flag), Err(Cancelled));
+    setter.join().unwrap();
+    let (flag, setter) = stop_later();
+    assert_eq!(huge.try_mul_cancellable(&huge, &flag), Err(Cancelled));
+    setter.join().unwrap();
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_cancellable_div_by_zero() {
+    let go = std::sync::atomic::AtomicBool::new(false);
+    let _ = BigUint::one().try_div_rem_cancellable(&BigUint::zero(), &go);
+}
+
+#[test]
+fn test_accumulator() {
+    assert_eq!(Accumulator::new().sum(), BigUint::zero());
+
+    let values: Vec<BigUint> = [0u32, 1, 63, 64, 65, 1000, 64, 0]
+        .iter()
+        .map(|&bits| (BigUint::one() << bits) - 1u32)
+        .collect();
+    let mut acc = Accumulator::new();
+    let mut expected = BigUint::zero();
+    for x in &values {
+        acc += x;
+        expected += x;
+        assert_eq!(acc.sum(), expected);
+    }
+
+    let mut acc: Accumulator = values.iter().collect();
+    for x in &values {
+        acc.push_u64_digits(&x.to_u64_digits());
+    }
+    acc.push_u64_digits(&[0, 0, 0]);
+    assert_eq!(acc.sum(), expected * 2u32);
+
+    // Enough all-ones digits to fill the columns many times over.
+    let max = BigUint::from(u64::MAX);
+    let acc: Accumulator = repeat(max.clone()).take(100_000).collect();
+    assert_eq!(acc.sum(), max * 100_000u32);
+}
+
+#[test]
+fn test_arc_biguint() {
+    let n = (BigUint::one() << 100_000u32) - 1u32;
+    let shared = ArcBigUint::from(n.clone());
+    let handles: Vec<_> = (0..4u32)
+        .map(|i| {
+            let m = shared.clone();
+            std::thread::spawn(move || &*m % (i + 2))
+        })
+        .collect();
+    for (i, h) in (0..4u32).zip(handles) {
+        assert_eq!(h.join().unwrap(), &n % (i + 2));
+    }
+
+    // The only handle left is mutated and unwrapped in place.
+    let mut m = shared.clone();
+    drop(shared);
+    let before = std::ptr::addr_of!(*m);
+    *m.make_mut() += 1u32;
+    assert_eq!(before, std::ptr::addr_of!(*m));
+    assert_eq!(m.into_inner(), BigUint::one() << 100_000u32);
+
+    let a = ArcBigUint::new(n.clone());
+    let mut b = a.clone();
+    b.make_mut().set_bit(0, false);
+    assert_eq!(*a, n);
+    assert_eq!(BigUint::from(b), n - 1u32);
+}
+
+#[test]
+fn test_expr() {
+    let values: Vec<BigUint> = [0u32, 1, 64, 100, 2000, 5000]
+        .iter()
+        .map(|&bits| (BigUint::one() << bits) - 1u32)
+        .collect();
+    let mut out = BigUint::from(12345u32);
+    for a in &values {
+        assert_eq!(expr(a).eval(), *a);
+        for b in &values {
+            (expr(a) + b).eval_into(&mut out);
+            assert_eq!(out, a + b);
+            for c in &values {
+                (expr(a) * b * c).eval_into(&mut out);
+                assert_eq!(out, a * b * c);
+                (expr(a) * b + c).eval_into(&mut out);
+                assert_eq!(out, a * b + c);
+                ((expr(a) + b) * (expr(c) + a)).eval_into(&mut out);
+                assert_eq!(out, (a + b) * (c + a));
+            }
+        }
+    }
+
+    // Many full products carrying through the spare digits.
+    let max = (BigUint::one() << 128u32) - 1u32;
+    let sum = (0..50).fold(expr(&max) * &max, |e, _| e + expr(&max) * &max);
+    assert_eq!(sum.eval(), &max * &max * 51u32);
+}
+
+#[test]
+#[should_panic(expected = "dot product of slices with different lengths")]
+fn test_dot_mismatched() {
+    let _ = BigUint::dot(&[BigUint::one()], &[]);
+}
+
+#[test]
+fn test_add_shifted() {
+    let values: Vec<BigUint> = [0u32, 1, 63, 64, 65, 200]
+        .iter()
+        .flat_map(|&bits| {
+            let ones = (BigUint::one() << bits) - 1u32;
+            let third = (BigUint::one() << bits) / 3u32 + 1u32;
+            vec![ones, third]
+        })
+        .collect();
+    for a in &values {
+        for b in &values {
+            for shift in [0u64, 1, 31, 32, 63, 64, 65, 150, 300] {
+                let mut sum = a.clone();
+                sum.add_shifted(b, shift);
+                assert_eq!(sum, a + (b << shift));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_mul_low_high_bits() {
+    // All-ones values maximize the carries across the cut.
+    let values: Vec<BigUint> = [0u32, 1, 63, 64, 65, 200, 1000, 2100, 5000]
+        .iter()
+        .flat_map(|&bits| {
+            let ones = (BigUint::one() << bits) - 1u32;
+            let third = (BigUint::one() << bits) / 3u32 + 1u32;
+            vec![ones, third]
+        })
+        .collect();
+    let cuts = [
+        0u64, 1, 31, 32, 64, 100, 128, 250, 640, 1000, 2000, 4000, 7000, 20_000,
+    ];
+    for a in &values {
+        for b in &values {
+            let prod = a * b;
+            for &k in &cuts {
+                let mask = (BigUint::one() << k) - 1u32;
+                assert_eq!(a.mul_low_bits(b, k), &prod & &mask);
+                assert_eq!(a.mul_high_bits(b, k), &prod >> k);
+            }
+        }
+    }
 }
 
 #[test]
@@ -1614,6 +2534,130 @@ fn test_from_and_to_radix() {
     assert_eq!(BigUint::from_radix_be(&[], 2), Some(BigUint::zero()));
 }
 
+#[test]
+fn test_from_radix_digits_iter() {
+    use num_bigint::DigitOrder::{LeastSignificantFirst, MostSignificantFirst};
+
+    let x = BigUint::from(7u8).pow(20_000u32) - 1u8;
+    for radix in [2, 3, 10, 16, 36, 100, 255, 256] {
+        let be = x.to_radix_be(radix);
+        let le = x.to_radix_le(radix);
+        for n in [0, 1, 5, 19, 20, 21, 200, be.len()] {
+            let value = BigUint::from_radix_be(&be[..n], radix);
+            let digits = be[..n].iter().copied();
+            assert_eq!(
+                BigUint::from_radix_digits_iter(digits, radix, MostSignificantFirst),
+                value
+            );
+            let value = BigUint::from_radix_le(&le[..n], radix);
+            let digits = le[..n].iter().copied();
+            assert_eq!(
+                BigUint::from_radix_digits_iter(digits, radix, LeastSignificantFirst),
+                value
+            );
+        }
+    }
+
+    let digits = [1, 0, 50, 3];
+    assert!(BigUint::from_radix_digits_iter(digits, 50, MostSignificantFirst).is_none());
+    assert!(BigUint::from_radix_digits_iter(digits, 50, LeastSignificantFirst).is_none());
+    // The digits after an invalid one are not consumed.
+    let mut iter = digits.into_iter();
+    assert!(BigUint::from_radix_digits_iter(&mut iter, 10, MostSignificantFirst).is_none());
+    assert_eq!(iter.next(), Some(3));
+}
+
+#[test]
+fn test_radix_custom() {
+    const ZBASE32: &[u8] = b"ybndrfg8ejkmcpqxot1uwisza345h769";
+    const STANDARD: &[u8] = b"0123456789abcdefghijklmnopqrstuvwxyz";
+
+    let x = BigUint::from(7u8).pow(3000u32) - 1u8;
+    for radix in [2, 3, 10, 16, 36] {
+        let alphabet = &STANDARD[..radix];
+        let s = x.to_radix_custom(alphabet);
+        assert_eq!(
+            s,
+            x.to_str_radix(u32::try_from(radix).unwrap()).into_bytes()
+        );
+        assert_eq!(BigUint::from_radix_custom(&s, alphabet), Some(x.clone()));
+    }
+
+    let s = x.to_radix_custom(ZBASE32);
+    assert!(s.iter().all(|c| ZBASE32.contains(c)));
+    assert_eq!(BigUint::from_radix_custom(&s, ZBASE32), Some(x.clone()));
+    assert_eq!(BigUint::zero().to_radix_custom(ZBASE32), b"y");
+    assert_eq!(BigUint::from(33u8).to_radix_custom(ZBASE32), b"bb");
+
+    // All 256 byte values, in reverse.
+    let reversed: Vec<u8> = (0..=255).rev().collect();
+    let s = x.to_radix_custom(&reversed);
+    assert_eq!(
+        s,
+        x.to_bytes_be().iter().map(|b| 255 - b).collect::<Vec<_>>()
+    );
+    assert_eq!(BigUint::from_radix_custom(&s, &reversed), Some(x));
+
+    assert_eq!(BigUint::from_radix_custom(b"", ZBASE32), None);
+    assert_eq!(BigUint::from_radix_custom(b"ybl", ZBASE32), None);
+    assert_eq!(
+        BigUint::from_radix_custom(b"yyyb", ZBASE32),
+        Some(BigUint::one())
+    );
+}
+
+#[test]
+#[should_panic(expected = "more than once")]
+fn test_radix_custom_duplicate() {
+    let _ = BigUint::one().to_radix_custom(b"0120");
+}
+
+#[test]
+#[should_panic(expected = "The alphabet must have 2...256 symbols")]
+fn test_radix_custom_short() {
+    let _ = BigUint::from_radix_custom(b"0", b"0");
+}
+
+#[test]
+fn test_mixed_radix() {
+    // Lehmer code of the last permutation of 20 elements in the factorial number system.
+    let radices: Vec<u64> = (1..=20).collect();
+    let last = (1..=20u32).map(BigUint::from).product::<BigUint>() - 1u8;
+    let (digits, high) = last.to_mixed_radix(&radices);
+    assert_eq!(digits, (0..20).collect::<Vec<u64>>());
+    assert!(high.is_zero());
+    assert_eq!(
+        BigUint::from_mixed_radix(&digits, &radices, high),
+        Some(last)
+    );
+
+    let radices = [u64::MAX, 3, u64::MAX, 1];
+    let n = BigUint::from(7u8).pow(100u32);
+    let (digits, high) = n.to_mixed_radix(&radices);
+    assert!(digits.iter().zip(&radices).all(|(d, r)| d < r));
+    assert_eq!(digits[3], 0);
+    assert_eq!(
+        BigUint::from_mixed_radix(&digits, &radices, high),
+        Some(n.clone())
+    );
+
+    let (digits, high) = n.to_mixed_radix(&[]);
+    assert!(digits.is_empty());
+    assert_eq!(high, n);
+    assert_eq!(BigUint::from_mixed_radix(&[], &[], n.clone()), Some(n));
+
+    assert_eq!(
+        BigUint::from_mixed_radix(&[1, 3], &[2, 3], BigUint::zero()),
+        None
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_mixed_radix_zero() {
+    let _ = BigUint::from(5u8).to_mixed_radix(&[2, 0]);
+}
+
 #[test]
 fn test_from_str_radix() {
     let r = to_str_pairs();
@@ -1860,6 +2904,38 @@ fn test_trailing_zeros() {
     assert_eq!(x.trailing_zeros().unwrap(), 128);
 }
 
+#[test]
+fn test_remove_twos() {
+    assert_eq!(BigUint::zero().remove_twos(), (BigUint::zero(), 0));
+    assert_eq!(BigUint::one().remove_twos(), (BigUint::one(), 0));
+    assert_eq!(BigUint::from(96u8).remove_twos(), (BigUint::from(3u8), 5));
+    let odd = BigUint::from(3u8).pow(100u32);
+    for &k in &[0u64, 1, 63, 64, 65, 1000] {
+        assert_eq!((&odd << k).remove_twos(), (odd.clone(), k));
+    }
+    assert_eq!(
+        (BigUint::one() << 200u32).remove_twos(),
+        (BigUint::one(), 200)
+    );
+}
+
+#[test]
+fn test_mask_pow2() {
+    for &bits in &[0u64, 1, 31, 32, 33, 63, 64, 65, 127, 128, 129, 1000] {
+        let one = BigUint::one();
+        assert_eq!(BigUint::pow2(bits), &one << bits);
+        assert_eq!(BigUint::mask(bits), (&one << bits) - 1u8);
+        assert_eq!(BigUint::mask(bits).bits(), bits);
+        assert_eq!(BigUint::pow2(bits).trailing_zeros(), Some(bits));
+    }
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_pow2_max() {
+    let _ = BigUint::pow2(u64::MAX);
+}
+
 #[test]
 fn test_trailing_ones() {
     assert_eq!(BigUint::from(0u8).trailing_ones(), 0);
@@ -1907,3 +2983,35 @@ fn test_set_bit() {
     x.set_bit(1, false);
     assert_eq!(x, BigUint::zero());
 }
+
+#[test]
+fn test_shr_round() {
+    use num_bigint::RoundingMode::{Ceil, Floor, HalfEven, HalfUp, Trunc};
+
+    // A shift is a division by a power of two, rounded the same way.
+    let values = [
+        BigInt::from(0),
+        BigInt::from(0b1011_1000),
+        BigInt::from(0b1011_0111),
+        BigInt::from(3).pow(90u32),
+        (BigInt::from(5) << 200) + (BigInt::from(1) << 99),
+        (BigInt::from(5) << 200) + (BigInt::from(3) << 99),
+    ];
+    for n in &values {
+        for shift in [0, 1, 3, 4, 5, 64, 100, 101, 300] {
+            let d = BigInt::from(1) << shift;
+            for mode in [Floor, Ceil, Trunc, HalfUp, HalfEven] {
+                let expected = n.div_round(&d, mode);
+                assert_eq!(n.shr_round(shift, mode), expected, "{n} {shift} {mode:?}");
+                assert_eq!(
+                    (-n).shr_round(shift, mode),
+                    (-n).div_round(&d, mode),
+                    "-{n} {shift} {mode:?}"
+                );
+                let u = n.to_biguint().unwrap();
+                assert_eq!(Some(u.shr_round(shift, mode)), expected.to_biguint());
+            }
+        }
+        assert_eq!((-n).shr_round(7, Floor), -n >> 7);
+    }
+}
@@ -1,4 +1,5 @@
 use num_bigint::BigUint;
+use num_integer::Integer;
 use num_traits::{One, ToPrimitive, Zero};
 
 use std::panic::catch_unwind;
@@ -114,6 +115,34 @@ fn test_scalar_div_rem() {
     }
 }
 
+#[test]
+fn test_div_rem_u64() {
+    let n = (BigUint::one() << 300u32) / 7u32 + 12_345u32;
+    for d in [
+        1u64,
+        3,
+        10,
+        u64::from(u32::MAX),
+        1 << 32,
+        u64::MAX / 3,
+        u64::MAX,
+    ] {
+        let (q, r) = n.div_rem_u64(d);
+        assert_eq!((q.clone(), BigUint::from(r)), n.div_rem(&BigUint::from(d)));
+
+        let mut m = n.clone();
+        assert_eq!(m.div_assign_u64(d), r);
+        assert_eq!(m, q);
+    }
+    assert_eq!(BigUint::zero().div_rem_u64(5), (BigUint::zero(), 0));
+    assert_eq!(
+        BigUint::from(4u8).div_rem_u64(u64::MAX),
+        (BigUint::zero(), 4)
+    );
+
+    catch_unwind(|| BigUint::one().div_rem_u64(0)).unwrap_err();
+}
+
 #[test]
 fn test_scalar_div_rem_zero() {
     catch_unwind(|| BigUint::zero() / 0u32).unwrap_err();
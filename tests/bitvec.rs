@@ -0,0 +1,51 @@
+#![cfg(feature = "bitvec")]
+
+use bitvec::prelude::*;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+#[test]
+fn test_bitvec() {
+    let bits = bitvec![u8, Lsb0; 1, 0, 1, 1];
+    assert_eq!(BigUint::from(&bits), BigUint::from(13u8));
+    let bits = bitvec![u16, Msb0; 1, 0, 1, 1, 0, 0];
+    assert_eq!(BigUint::from(bits), BigUint::from(13u8));
+    assert_eq!(BigUint::from(BitVec::<u64, Lsb0>::new()), BigUint::zero());
+
+    let x = BigUint::from(13u8);
+    assert_eq!(BitVec::<u8, Lsb0>::from(&x), bitvec![u8, Lsb0; 1, 0, 1, 1]);
+    assert_eq!(
+        BitVec::<u32, Msb0>::from(&x),
+        bitvec![u32, Msb0; 1, 0, 1, 1]
+    );
+    assert!(BitVec::<usize, Lsb0>::from(BigUint::zero()).is_empty());
+
+    // Slices start anywhere within an element.
+    let raw = [0b1011_0100u8, 0xff];
+    let slice = &raw.view_bits::<Lsb0>()[2..12];
+    assert_eq!(BigUint::from(slice), BigUint::from(0b11_1110_1101u16));
+    let slice = &raw.view_bits::<Msb0>()[2..12];
+    assert_eq!(BigUint::from(slice), BigUint::from(0b11_1100_1011u16));
+}
+
+/// Checks the conversion of `x` to and from a `BitVec<T, O>`.
+fn check<T: BitStore, O: BitOrder>(x: &BigUint) {
+    let bits = BitVec::<T, O>::from(x);
+    assert_eq!(bits.len() as u64, x.bits());
+    assert_eq!(bits.count_ones() as u64, x.count_ones());
+    for (i, bit) in bits.iter().by_vals().enumerate() {
+        assert_eq!(bit, x.bit(i as u64));
+    }
+    assert_eq!(&BigUint::from(bits), x);
+}
+
+#[test]
+fn test_bitvec_roundtrip() {
+    let x = (BigUint::one() << 1000u32) - BigUint::from(3u8).pow(300u32);
+    check::<u8, Lsb0>(&x);
+    check::<u8, Msb0>(&x);
+    check::<u16, Msb0>(&x);
+    check::<u32, Lsb0>(&x);
+    check::<u64, Msb0>(&x);
+    check::<usize, Lsb0>(&x);
+}
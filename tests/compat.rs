@@ -0,0 +1,136 @@
+use num_bigint::compat::python::ByteOrder::{Big, Little};
+use num_bigint::compat::{dotnet, go, java, python};
+use num_bigint::BigInt;
+use num_traits::{One, Signed};
+
+fn samples() -> Vec<BigInt> {
+    let mut values: Vec<BigInt> = [
+        0, 1, -1, 127, 128, -128, -129, 255, 256, -256, 32767, -32769,
+    ]
+    .iter()
+    .map(|&n| BigInt::from(n))
+    .collect();
+    let big = BigInt::one() << 100u32;
+    values.extend([big.clone(), -&big, &big - 1u8, -&big + 1u8]);
+    values
+}
+
+#[test]
+fn test_java() {
+    // `BigInteger.valueOf(n).toByteArray()`
+    let cases: &[(i64, &[u8])] = &[
+        (0, &[0x00]),
+        (1, &[0x01]),
+        (-1, &[0xff]),
+        (127, &[0x7f]),
+        (128, &[0x00, 0x80]),
+        (-128, &[0x80]),
+        (-129, &[0xff, 0x7f]),
+        (65535, &[0x00, 0xff, 0xff]),
+    ];
+    for &(n, bytes) in cases {
+        let n = BigInt::from(n);
+        assert_eq!(java::to_byte_array(&n), bytes, "{n}");
+        assert_eq!(java::from_byte_array(bytes), Some(n));
+    }
+    assert_eq!(java::from_byte_array(&[]), None);
+    // Redundant sign bytes are accepted on input.
+    assert_eq!(
+        java::from_byte_array(&[0xff, 0xff, 0x80]),
+        Some(BigInt::from(-128))
+    );
+
+    for n in samples() {
+        assert_eq!(java::from_byte_array(&java::to_byte_array(&n)), Some(n));
+    }
+}
+
+#[test]
+fn test_dotnet() {
+    // `new BigInteger(n).ToByteArray()`
+    let cases: &[(i64, &[u8])] = &[
+        (0, &[0x00]),
+        (-1, &[0xff]),
+        (128, &[0x80, 0x00]),
+        (-129, &[0x7f, 0xff]),
+        (256, &[0x00, 0x01]),
+    ];
+    for &(n, bytes) in cases {
+        let n = BigInt::from(n);
+        assert_eq!(dotnet::to_byte_array(&n), bytes, "{n}");
+        assert_eq!(dotnet::from_byte_array(bytes), n);
+    }
+    assert_eq!(dotnet::from_byte_array(&[]), BigInt::from(0));
+
+    for n in samples() {
+        assert_eq!(dotnet::from_byte_array(&dotnet::to_byte_array(&n)), n);
+    }
+}
+
+#[test]
+fn test_go() {
+    // `big.NewInt(n).Bytes()`
+    let cases: &[(i64, &[u8])] = &[
+        (0, &[]),
+        (1, &[0x01]),
+        (-1, &[0x01]),
+        (128, &[0x80]),
+        (-256, &[0x01, 0x00]),
+    ];
+    for &(n, bytes) in cases {
+        assert_eq!(go::bytes(&BigInt::from(n)), bytes, "{n}");
+        assert_eq!(go::set_bytes(bytes), BigInt::from(n.abs()));
+    }
+    assert_eq!(go::set_bytes(&[0x00, 0x00, 0x80]), BigInt::from(128));
+
+    for n in samples() {
+        assert_eq!(go::set_bytes(&go::bytes(&n)), n.abs());
+    }
+}
+
+#[test]
+fn test_python() {
+    // `n.to_bytes(length, byteorder, signed=signed)`
+    let cases: &[(i64, usize, bool, Option<&[u8]>)] = &[
+        (0, 0, false, Some(&[])),
+        (0, 0, true, Some(&[])),
+        (0, 2, true, Some(&[0x00, 0x00])),
+        (255, 2, false, Some(&[0x00, 0xff])),
+        (255, 1, false, Some(&[0xff])),
+        (255, 1, true, None),
+        (256, 1, false, None),
+        (1, 0, false, None),
+        (-1, 1, false, None),
+        (-1, 2, true, Some(&[0xff, 0xff])),
+        (-128, 1, true, Some(&[0x80])),
+        (-129, 1, true, None),
+        (-129, 3, true, Some(&[0xff, 0xff, 0x7f])),
+    ];
+    for &(n, length, signed, bytes) in cases {
+        let n = BigInt::from(n);
+        let big = python::to_bytes(&n, length, Big, signed);
+        assert_eq!(big.as_deref(), bytes, "{n} {length} {signed}");
+        let little = python::to_bytes(&n, length, Little, signed);
+        let reversed = bytes.map(|b| b.iter().rev().copied().collect::<Vec<u8>>());
+        assert_eq!(little, reversed, "{n} {length} {signed}");
+        if let Some(bytes) = bytes {
+            assert_eq!(python::from_bytes(bytes, Big, signed), n);
+        }
+    }
+    assert_eq!(python::from_bytes(&[], Little, true), BigInt::from(0));
+    assert_eq!(
+        python::from_bytes(&[0xff], Little, false),
+        BigInt::from(255)
+    );
+    assert_eq!(python::from_bytes(&[0xff], Little, true), BigInt::from(-1));
+
+    for n in samples() {
+        for order in [Big, Little] {
+            let bytes = python::to_bytes(&n, 16, order, true).unwrap();
+            assert_eq!(python::from_bytes(&bytes, order, true), n);
+            if let Some(bytes) = python::to_bytes(&n, 16, order, false) {
+                assert_eq!(python::from_bytes(&bytes, order, false), n);
+            }
+        }
+    }
+}
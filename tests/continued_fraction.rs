@@ -0,0 +1,114 @@
+use num_bigint::{BigInt, Convergents};
+use num_integer::Integer;
+use num_traits::{One, Signed};
+
+fn quotients(n: i64, d: i64) -> Vec<BigInt> {
+    BigInt::continued_fraction(&BigInt::from(n), &BigInt::from(d)).collect()
+}
+
+#[test]
+fn test_continued_fraction() {
+    assert_eq!(quotients(415, 93), [4, 2, 6, 7].map(BigInt::from));
+    assert_eq!(quotients(-415, 93), [-5, 1, 1, 6, 7].map(BigInt::from));
+    assert_eq!(quotients(415, -93), quotients(-415, 93));
+    assert_eq!(quotients(6, 3), [BigInt::from(2)]);
+    assert_eq!(quotients(0, 7), [BigInt::from(0)]);
+    assert_eq!(quotients(3, 7), [0, 2, 3].map(BigInt::from));
+}
+
+#[test]
+fn test_convergents_roundtrip() {
+    let big = BigInt::from(3).pow(200u32) + 17i32;
+    let cases = [
+        (BigInt::from(415), BigInt::from(93)),
+        (BigInt::from(-415), BigInt::from(93)),
+        (big.clone(), BigInt::from(7).pow(90u32)),
+        (BigInt::from(1) << 300, big),
+    ];
+    for (n, d) in &cases {
+        let convergents: Vec<(BigInt, BigInt)> =
+            BigInt::continued_fraction(n, d).convergents().collect();
+        let g = n.gcd(d);
+        assert_eq!(convergents.last().unwrap(), &(n / &g, d / &g));
+        for window in convergents.windows(2) {
+            let ((p0, q0), (p1, q1)) = (&window[0], &window[1]);
+            // Consecutive convergents differ by exactly 1 / (q0 * q1).
+            assert!((p1 * q0 - p0 * q1).abs().is_one());
+            assert!(q0 <= q1);
+        }
+    }
+}
+
+#[test]
+fn test_convergents_fibonacci() {
+    // The golden ratio's convergents are ratios of consecutive Fibonacci numbers.
+    let mut convergents = Convergents::new(std::iter::repeat(BigInt::one()));
+    let (p, q) = convergents.nth(99).unwrap();
+    let fib = |n: usize| {
+        let (mut a, mut b) = (BigInt::from(0), BigInt::from(1));
+        for _ in 0..n {
+            (a, b) = (b.clone(), a + b);
+        }
+        a
+    };
+    assert_eq!((p, q), (fib(101), fib(100)));
+}
+
+#[test]
+#[should_panic]
+fn test_continued_fraction_zero_denominator() {
+    let _ = BigInt::continued_fraction(&BigInt::from(1), &BigInt::from(0));
+}
+
+#[test]
+fn test_best_rational_approx() {
+    // Compare against a search over every denominator.
+    let brute = |n: i64, d: i64, max: i64| {
+        let (mut best_p, mut best_q) = (0i64, 0i64);
+        for q in 1..=max {
+            let p = (2 * n * q + d).div_euclid(2 * d);
+            let better =
+                best_q == 0 || (n * q - p * d).abs() * best_q < (n * best_q - best_p * d).abs() * q;
+            if better {
+                (best_p, best_q) = (p, q);
+            }
+        }
+        (best_p, best_q)
+    };
+    for &(n, d) in &[
+        (1, 2),
+        (3, 7),
+        (-22, 7),
+        (1000, 3),
+        (314_159, 100_000),
+        (-5, 11),
+    ] {
+        for max in 1..40 {
+            let (p, q) = BigInt::best_rational_approx(
+                &BigInt::from(n),
+                &BigInt::from(d),
+                &BigInt::from(max),
+            );
+            let (bp, bq) = brute(n, d, max);
+            // Ties may pick either side, so compare the errors rather than the fractions.
+            let err = |p: &BigInt, q: &BigInt| (BigInt::from(n) * q - p * d).abs() * bq;
+            assert_eq!(
+                err(&p, &q),
+                (BigInt::from(n) * bq - BigInt::from(bp) * d).abs() * &q,
+                "{n}/{d} within {max}"
+            );
+            assert!(q >= BigInt::one() && q <= BigInt::from(max));
+            assert!(p.gcd(&q).is_one());
+        }
+    }
+}
+
+#[test]
+fn test_best_rational_approx_exact() {
+    let (n, d) = (BigInt::from(-6), BigInt::from(-4));
+    let big = BigInt::from(1) << 100u32;
+    assert_eq!(
+        BigInt::best_rational_approx(&n, &d, &big),
+        (BigInt::from(3), BigInt::from(2))
+    );
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "crypto-bigint")]
+
+use crypto_bigint::{U128, U256, U64};
+use num_bigint::{BigInt, BigUint};
+use num_traits::{One, Zero};
+
+#[test]
+fn test_biguint_to_uint() {
+    let x = BigUint::parse_bytes(b"0123456789abcdef0123456789abcdef", 16).unwrap();
+    let u = U256::try_from(&x).unwrap();
+    assert_eq!(
+        u,
+        U256::from_be_hex("000000000000000000000000000000000123456789abcdef0123456789abcdef")
+    );
+    assert_eq!(BigUint::from(u), x);
+    assert_eq!(
+        U128::try_from(x.clone()).unwrap(),
+        U128::from_u128(x.to_string().parse().unwrap())
+    );
+
+    assert_eq!(U64::try_from(&BigUint::zero()).unwrap(), U64::ZERO);
+    assert_eq!(BigUint::from(U64::ZERO), BigUint::zero());
+}
+
+#[test]
+fn test_biguint_to_uint_overflow() {
+    let max = (BigUint::one() << 128u32) - 1u32;
+    assert_eq!(U128::try_from(&max).unwrap(), U128::MAX);
+    assert_eq!(BigUint::from(U128::MAX), max);
+
+    let over = &max + 1u32;
+    assert!(U128::try_from(&over).is_err());
+    let err = U128::try_from(over.clone()).unwrap_err();
+    assert_eq!(err.into_original(), over);
+}
+
+#[test]
+fn test_bigint_to_uint() {
+    let x = BigInt::from(u128::MAX);
+    assert_eq!(U128::try_from(&x).unwrap(), U128::MAX);
+    assert_eq!(BigInt::from(U128::MAX), x);
+    assert!(U128::try_from(&(x + 1)).is_err());
+
+    let neg = BigInt::from(-1);
+    assert!(U256::try_from(&neg).is_err());
+    assert_eq!(
+        U256::try_from(neg.clone()).unwrap_err().into_original(),
+        neg
+    );
+}
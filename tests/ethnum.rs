@@ -0,0 +1,25 @@
+#![cfg(feature = "ethnum")]
+
+use ethnum::U256;
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+#[test]
+fn test_ethnum_u256() {
+    let max = (BigUint::one() << 256u32) - 1u32;
+    assert_eq!(U256::try_from(&max).unwrap(), U256::MAX);
+    assert_eq!(BigUint::from(U256::MAX), max);
+    assert!(U256::try_from(&max + 1u32).is_err());
+
+    let u = U256::from_words(
+        0x0123_4567_89ab_cdef,
+        0xfedc_ba98_7654_3210_0000_0000_0000_0001,
+    );
+    let x = (BigUint::from(0x0123_4567_89ab_cdefu64) << 128u32)
+        + BigUint::from(0xfedc_ba98_7654_3210_0000_0000_0000_0001u128);
+    assert_eq!(BigUint::from(&u), x);
+    assert_eq!(U256::try_from(x).unwrap(), u);
+
+    assert_eq!(U256::try_from(BigUint::zero()).unwrap(), U256::ZERO);
+    assert_eq!(BigUint::from(U256::ZERO), BigUint::zero());
+}
@@ -0,0 +1,110 @@
+use num_bigint::{BigUint, Factorization};
+use num_integer::Integer;
+use num_traits::One;
+
+fn naive_factors(mut n: u32) -> Vec<(BigUint, u32)> {
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while n > 1 {
+        let mut e = 0;
+        while n % p == 0 {
+            n /= p;
+            e += 1;
+        }
+        if e > 0 {
+            factors.push((BigUint::from(p), e));
+        }
+        p += 1;
+    }
+    factors
+}
+
+#[test]
+fn test_factorize_small() {
+    for n in 1..3000u32 {
+        let f = BigUint::from(n).factorize();
+        assert_eq!(f.factors(), &naive_factors(n)[..], "{n}");
+        assert_eq!(f.value(), BigUint::from(n));
+
+        let divisors: Vec<u32> = (1..=n).filter(|d| n % d == 0).collect();
+        assert_eq!(f.divisor_count(), BigUint::from(divisors.len()));
+        assert_eq!(
+            f.divisor_sum(),
+            divisors.iter().map(|&d| BigUint::from(d)).sum::<BigUint>()
+        );
+        let totient = (1..=n).filter(|k| k.gcd(&n) == 1).count();
+        assert_eq!(f.totient(), BigUint::from(totient));
+    }
+    assert!(BigUint::one().factorize().factors().is_empty());
+}
+
+#[test]
+fn test_factorize_large() {
+    let p = BigUint::from(1_000_000_007u32);
+    let q = BigUint::from(998_244_353u32);
+    let m61 = (BigUint::from(1u8) << 61u32) - 1u8;
+    let n = &p * &p * &q * &m61 * 12u8;
+    let f = n.factorize();
+    assert_eq!(
+        f.factors(),
+        [
+            (BigUint::from(2u8), 2),
+            (BigUint::from(3u8), 1),
+            (q.clone(), 1),
+            (p.clone(), 2),
+            (m61.clone(), 1),
+        ]
+    );
+    assert_eq!(f.value(), n);
+    assert_eq!(f.radical(), &p * &q * &m61 * 6u8);
+}
+
+#[test]
+fn test_factorization_ops() {
+    let a = BigUint::from(360u16).factorize();
+    let b = BigUint::from(1050u16).factorize();
+    assert_eq!((&a * &b).value(), BigUint::from(360u32 * 1050));
+    assert_eq!(
+        (a.clone() * b.clone()).value(),
+        BigUint::from(360u32 * 1050)
+    );
+    assert_eq!(a.gcd(&b).value(), BigUint::from(30u8));
+    assert_eq!(a.gcd(&Factorization::default()).value(), BigUint::one());
+
+    let f = Factorization::from_prime_powers([
+        (BigUint::from(5u8), 1),
+        (BigUint::from(2u8), 2),
+        (BigUint::from(5u8), 2),
+        (BigUint::from(3u8), 0),
+    ]);
+    assert_eq!(
+        f.factors(),
+        [(BigUint::from(2u8), 2), (BigUint::from(5u8), 3)]
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_factorize_zero() {
+    let _ = BigUint::from(0u8).factorize();
+}
+
+#[test]
+fn test_divisors() {
+    for n in 1..500u32 {
+        let f = BigUint::from(n).factorize();
+        let expected: Vec<BigUint> = (1..=n).filter(|d| n % d == 0).map(BigUint::from).collect();
+        assert_eq!(f.divisors_sorted().collect::<Vec<_>>(), expected, "{n}");
+        let mut unsorted: Vec<BigUint> = f.divisors().collect();
+        unsorted.sort();
+        assert_eq!(unsorted, expected, "{n}");
+    }
+
+    let m61 = (BigUint::from(1u8) << 61u32) - 1u8;
+    let p = BigUint::from(1_000_000_007u32);
+    let f = (&m61 * &p * &p * 720u16).factorize();
+    assert_eq!(BigUint::from(f.divisors().count()), f.divisor_count());
+    let first: Vec<BigUint> = f.divisors_sorted().take(4).collect();
+    assert_eq!(first, [1u8, 2, 3, 4].map(BigUint::from));
+    assert_eq!(f.divisors_sorted().last(), Some(f.value()));
+}
@@ -0,0 +1,113 @@
+#![cfg(feature = "ffi")]
+
+use num_bigint::ffi::*;
+use num_bigint::BigInt;
+
+use std::ffi::{CStr, CString};
+use std::ptr;
+
+fn parse(s: &str) -> *mut BigInt {
+    let s = CString::new(s).unwrap();
+    let x = unsafe { num_bigint_from_str(s.as_ptr(), 10) };
+    assert!(!x.is_null());
+    x
+}
+
+fn format_and_free(x: *mut BigInt) -> String {
+    assert!(!x.is_null());
+    unsafe {
+        let s = num_bigint_to_str(x, 10);
+        let result = CStr::from_ptr(s).to_str().unwrap().to_owned();
+        num_bigint_str_free(s);
+        num_bigint_free(x);
+        result
+    }
+}
+
+#[test]
+fn test_str_roundtrip() {
+    for s in [
+        "0",
+        "-1",
+        "18446744073709551616",
+        "-123456789012345678901234567890",
+    ] {
+        assert_eq!(format_and_free(parse(s)), s);
+    }
+
+    let bad = CString::new("12x").unwrap();
+    unsafe {
+        assert!(num_bigint_from_str(bad.as_ptr(), 10).is_null());
+        assert!(num_bigint_from_str(ptr::null(), 10).is_null());
+        assert!(num_bigint_from_str(bad.as_ptr(), 37).is_null());
+    }
+}
+
+#[test]
+fn test_bytes_roundtrip() {
+    let x = parse("-340282366920938463463374607431768211457");
+    unsafe {
+        let mut len = 0;
+        let bytes = num_bigint_to_signed_bytes_be(x, &mut len);
+        let y = num_bigint_from_signed_bytes_be(bytes, len);
+        num_bigint_bytes_free(bytes, len);
+        assert_eq!(num_bigint_cmp(x, y), 0);
+        num_bigint_free(x);
+        assert_eq!(
+            format_and_free(y),
+            "-340282366920938463463374607431768211457"
+        );
+        assert_eq!(
+            format_and_free(num_bigint_from_signed_bytes_be(ptr::null(), 0)),
+            "0"
+        );
+    }
+}
+
+#[test]
+fn test_arithmetic() {
+    let a = parse("100000000000000000000");
+    let b = num_bigint_from_i64(-7);
+    let zero = num_bigint_from_u64(0);
+    unsafe {
+        assert_eq!(num_bigint_sign(a), 1);
+        assert_eq!(num_bigint_sign(b), -1);
+        assert_eq!(num_bigint_sign(zero), 0);
+        assert_eq!(num_bigint_cmp(a, b), 1);
+        assert_eq!(num_bigint_cmp(b, a), -1);
+
+        assert_eq!(
+            format_and_free(num_bigint_add(a, b)),
+            "99999999999999999993"
+        );
+        assert_eq!(
+            format_and_free(num_bigint_sub(a, b)),
+            "100000000000000000007"
+        );
+        assert_eq!(
+            format_and_free(num_bigint_mul(a, b)),
+            "-700000000000000000000"
+        );
+        assert_eq!(
+            format_and_free(num_bigint_div(a, b)),
+            "-14285714285714285714"
+        );
+        assert_eq!(format_and_free(num_bigint_rem(a, b)), "2");
+        assert_eq!(format_and_free(num_bigint_neg(b)), "7");
+        assert_eq!(format_and_free(num_bigint_clone(b)), "-7");
+        assert_eq!(format_and_free(num_bigint_pow(b, 3)), "-343");
+        assert!(num_bigint_div(a, zero).is_null());
+        assert!(num_bigint_rem(a, zero).is_null());
+
+        let m = num_bigint_from_u64(1000);
+        let e = num_bigint_from_u64(3);
+        assert_eq!(format_and_free(num_bigint_modpow(b, e, m)), "657");
+        assert!(num_bigint_modpow(a, b, m).is_null());
+        assert!(num_bigint_modpow(a, e, zero).is_null());
+
+        for x in [a, b, zero, m, e] {
+            num_bigint_free(x);
+        }
+        num_bigint_free(ptr::null_mut());
+    }
+}
@@ -0,0 +1,44 @@
+#![cfg(feature = "getrandom")]
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+
+#[test]
+fn test_random_secure() {
+    assert!(BigUint::random_secure(0).unwrap().is_zero());
+    for bits in [1, 7, 8, 9, 63, 64, 65, 1000] {
+        let n = BigUint::random_secure(bits).unwrap();
+        assert!(n.bits() <= bits);
+    }
+    // Two 256-bit draws colliding would mean the source is broken.
+    assert_ne!(
+        BigUint::random_secure(256).unwrap(),
+        BigUint::random_secure(256).unwrap()
+    );
+}
+
+#[test]
+fn test_random_secure_below() {
+    assert!(BigUint::random_secure_below(&BigUint::one())
+        .unwrap()
+        .is_zero());
+
+    let bound = BigUint::from(3u8);
+    let mut seen = [false; 3];
+    for _ in 0..200 {
+        let n = BigUint::random_secure_below(&bound).unwrap();
+        seen[usize::from(u8::try_from(&n).unwrap())] = true;
+    }
+    assert!(seen.iter().all(|&s| s));
+
+    let bound = (BigUint::one() << 500u32) + 1u8;
+    for _ in 0..20 {
+        assert!(BigUint::random_secure_below(&bound).unwrap() < bound);
+    }
+}
+
+#[test]
+#[should_panic(expected = "bound must be non-zero")]
+fn test_random_secure_below_zero() {
+    let _ = BigUint::random_secure_below(&BigUint::zero());
+}
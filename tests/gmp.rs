@@ -0,0 +1,93 @@
+#![cfg(feature = "gmp")]
+
+use gmp_mpfr_sys::gmp;
+use num_bigint::{BigInt, BigUint};
+
+use std::ffi::CString;
+use std::mem::MaybeUninit;
+
+/// An owned, initialized `mpz_t` for testing.
+struct Mpz(gmp::mpz_t);
+
+impl Mpz {
+    fn new() -> Self {
+        unsafe {
+            let mut z = MaybeUninit::uninit();
+            gmp::mpz_init(z.as_mut_ptr());
+            Mpz(z.assume_init())
+        }
+    }
+
+    fn from_str(s: &str) -> Self {
+        let mut z = Self::new();
+        let s = CString::new(s).unwrap();
+        assert_eq!(unsafe { gmp::mpz_set_str(&mut z.0, s.as_ptr(), 10) }, 0);
+        z
+    }
+
+    fn to_string(&self) -> String {
+        unsafe {
+            let len = gmp::mpz_sizeinbase(&self.0, 10) + 2;
+            let mut buf = vec![0u8; len];
+            gmp::mpz_get_str(buf.as_mut_ptr().cast(), 10, &self.0);
+            let end = buf.iter().position(|&b| b == 0).unwrap();
+            String::from_utf8(buf[..end].to_vec()).unwrap()
+        }
+    }
+}
+
+impl Drop for Mpz {
+    fn drop(&mut self) {
+        unsafe { gmp::mpz_clear(&mut self.0) }
+    }
+}
+
+const VALUES: &[&str] = &[
+    "0",
+    "1",
+    "4294967295",
+    "4294967296",
+    "18446744073709551615",
+    "18446744073709551616",
+    "340282366920938463463374607431768211457",
+    "123456789012345678901234567890123456789012345678901234567890",
+];
+
+#[test]
+fn test_biguint_mpz_roundtrip() {
+    for s in VALUES {
+        let x: BigUint = s.parse().unwrap();
+        let z = Mpz::from_str(s);
+        assert_eq!(unsafe { BigUint::from_mpz(&z.0) }, Some(x.clone()));
+
+        let mut out = Mpz::from_str("-999999999999999999999999999999");
+        unsafe { x.assign_to_mpz(&mut out.0) };
+        assert_eq!(out.to_string(), *s);
+    }
+}
+
+#[test]
+fn test_biguint_from_negative_mpz() {
+    let z = Mpz::from_str("-5");
+    assert_eq!(unsafe { BigUint::from_mpz(&z.0) }, None);
+}
+
+#[test]
+fn test_bigint_mpz_roundtrip() {
+    for s in VALUES {
+        for neg in [false, true] {
+            let s = if neg && *s != "0" {
+                format!("-{s}")
+            } else {
+                s.to_string()
+            };
+            let x: BigInt = s.parse().unwrap();
+            let z = Mpz::from_str(&s);
+            assert_eq!(unsafe { BigInt::from_mpz(&z.0) }, x);
+
+            let mut out = Mpz::new();
+            unsafe { x.assign_to_mpz(&mut out.0) };
+            assert_eq!(out.to_string(), s);
+        }
+    }
+}
@@ -0,0 +1,120 @@
+#![cfg(feature = "std")]
+
+use num_bigint::{limits, BigInt, BigUint, CapacityError, CapacityErrorKind};
+use num_integer::Integer;
+use num_traits::{FromPrimitive, Num, One, Pow, Zero};
+use std::panic::catch_unwind;
+
+fn error_kind<T>(result: Result<T, CapacityError>) -> Option<CapacityErrorKind> {
+    result.err().map(|e| e.kind())
+}
+
+#[test]
+fn test_limits() {
+    assert_eq!(limits::max_bits(), u64::MAX);
+    let one = BigUint::one();
+
+    {
+        let _guard = limits::scoped_max_bits(100);
+        assert_eq!(limits::max_bits(), 100);
+        assert_eq!((&one << 99u32).bits(), 100);
+        assert!(catch_unwind(|| BigUint::one() << 100u32).is_err());
+        assert!(catch_unwind(|| BigInt::one() << 10_000_000_000u64).is_err());
+        assert!(catch_unwind(|| BigUint::from(3u8).pow(1u32 << 31)).is_err());
+        assert!(catch_unwind(|| BigUint::from(3u8).pow(64u32)).is_err());
+        assert_eq!(BigUint::from(3u8).pow(63u32).bits(), 100);
+        assert_eq!(BigUint::from(3u8).checked_pow(64, 1000), None);
+        assert!(BigUint::from(3u8).checked_pow(63, 1000).is_some());
+
+        let digits = "9".repeat(30);
+        assert!(BigUint::from_str_radix(&digits, 10).is_ok());
+        assert!(format!("{digits}9").parse::<BigUint>().is_err());
+        assert!(format!("-{digits}9").parse::<BigInt>().is_err());
+        assert!(BigUint::from_str_radix(&"f".repeat(26), 16).is_err());
+        // Leading zeros don't count.
+        assert!(format!("{}1", "0".repeat(1000)).parse::<BigUint>().is_ok());
+
+        // The `try_` methods return errors instead.
+        let limit = Some(CapacityErrorKind::LimitExceeded);
+        let x = BigUint::from(3u8).pow(30u32);
+        assert_eq!(one.try_shl(99), Ok(&one << 99u32));
+        assert_eq!(error_kind(one.try_shl(100)), limit);
+        assert_eq!(error_kind(one.try_shl(1 << 40)), limit);
+        assert_eq!(x.try_mul(&x), Ok(&x * &x));
+        assert_eq!(error_kind(x.try_mul(&(&x * &x))), limit);
+        assert_eq!(x.try_mul(&BigUint::zero()), Ok(BigUint::zero()));
+        assert_eq!(
+            BigUint::from(3u8).try_pow(63),
+            Ok(BigUint::from(3u8).pow(63u32))
+        );
+        assert_eq!(error_kind(BigUint::from(3u8).try_pow(64)), limit);
+        assert_eq!(BigUint::zero().try_pow(u32::MAX), Ok(BigUint::zero()));
+        assert_eq!(one.try_pow(u32::MAX), Ok(one.clone()));
+        let max = &(&one << 99u32) + (&one << 98u32);
+        assert_eq!(max.try_add(&BigUint::one()), Ok(&max + 1u8));
+        assert_eq!(error_kind(max.try_add(&max)), limit);
+
+        let y = BigInt::from(-3);
+        assert_eq!(y.try_pow(63), Ok(Pow::pow(&y, 63u32)));
+        assert_eq!(error_kind(y.try_pow(64)), limit);
+        assert_eq!(y.try_shl(98), Ok(&y << 98u32));
+        assert_eq!(
+            y.try_mul(&BigInt::from(x.clone())),
+            Ok(&y * &BigInt::from(x))
+        );
+        assert_eq!(
+            y.try_add(&BigInt::from(max.clone())),
+            Ok(&y + BigInt::from(max))
+        );
+        assert!(y.try_shl(99).is_err());
+        let err: CapacityError = y.try_shl(99).unwrap_err();
+        assert!(err.to_string().contains("limit"));
+
+        // An inner guard can only lower the limit.
+        {
+            let _inner = limits::scoped_max_bits(1000);
+            assert_eq!(limits::max_bits(), 100);
+            let _inner = limits::scoped_max_bits(10);
+            assert_eq!(limits::max_bits(), 10);
+        }
+        assert_eq!(limits::max_bits(), 100);
+
+        // Other threads are unaffected.
+        let other = std::thread::spawn(limits::max_bits).join().unwrap();
+        assert_eq!(other, u64::MAX);
+    }
+    assert_eq!(limits::max_bits(), u64::MAX);
+    assert_eq!((&one << 1000u32).bits(), 1001);
+    let overflow = Some(CapacityErrorKind::Overflow);
+    assert_eq!(error_kind(one.try_shl(u64::MAX)), overflow);
+
+    limits::set_max_bits(64);
+    let other = std::thread::spawn(limits::max_bits).join().unwrap();
+    assert_eq!(other, 64);
+    assert!(catch_unwind(|| BigUint::one() << 64u32).is_err());
+    limits::set_max_bits(u64::MAX);
+    assert_eq!(limits::max_bits(), u64::MAX);
+}
+
+#[test]
+fn test_limits_internal_shifts() {
+    // Operations whose inputs and results fit under the limit may shift past it internally.
+    let one = BigUint::one();
+    let a = (&one << 998u32) + 12345u32;
+    let d = (&one << 100u32) + 3u8;
+    let m = (&one << 600u32) + 1u8;
+    let (q, r) = (&a / &d, &a % &d);
+    let power = BigUint::from(7u8).modpow(&a, &m);
+    let (root, cube_root) = (a.sqrt(), a.cbrt());
+    let float = BigUint::from(1u8 << 7) << 990u32;
+
+    let _guard = limits::scoped_max_bits(1000);
+    assert_eq!(a.bits(), 999);
+    assert_eq!(&a / &d, q);
+    assert_eq!(&a % &d, r);
+    assert_eq!(a.div_rem(&d), (q, r));
+    assert_eq!(BigUint::from(7u8).modpow(&a, &m), power);
+    assert_eq!(a.sqrt(), root);
+    assert_eq!(a.cbrt(), cube_root);
+    assert_eq!(BigUint::from_f64(2f64.powi(997)), Some(float));
+}
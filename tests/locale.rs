@@ -0,0 +1,88 @@
+#![cfg(feature = "locale")]
+
+use num_bigint::locale::NumberFormat;
+use num_bigint::{BigInt, BigUint, Scaled};
+use num_traits::{Num, Pow};
+
+#[test]
+fn test_locale_format() {
+    let en = NumberFormat::new().with_group_separator(',');
+    for (s, grouped) in [
+        ("0", "0"),
+        ("999", "999"),
+        ("1000", "1,000"),
+        ("123456", "123,456"),
+        ("1234567", "1,234,567"),
+    ] {
+        let n = BigUint::from_str_radix(s, 10).unwrap();
+        assert_eq!(en.format_uint(&n), grouped);
+        assert_eq!(NumberFormat::new().format_uint(&n), s);
+        assert_eq!(en.parse_uint(grouped), Ok(n.clone()));
+        assert_eq!(en.parse_uint(s), Ok(n));
+    }
+
+    let n = 1 - BigInt::from(10).pow(30u32);
+    let plain = NumberFormat::new();
+    assert_eq!(plain.format_int(&n), n.to_string());
+    assert_eq!(plain.parse_int(&n.to_string()), Ok(n.clone()));
+
+    let ch = NumberFormat::new()
+        .with_group_separator('\'')
+        .with_minus_sign('\u{2212}');
+    let s = "\u{2212}999'999'999'999'999'999'999'999'999'999";
+    assert_eq!(ch.format_int(&n), s);
+    assert_eq!(ch.parse_int(s), Ok(n));
+
+    let in_ = NumberFormat::new()
+        .with_group_separator(',')
+        .with_grouping(3, 2);
+    let n = BigUint::from(10u8).pow(10u32);
+    assert_eq!(in_.format_uint(&n), "10,00,00,00,000");
+    assert_eq!(in_.parse_uint("10,00,00,00,000"), Ok(n));
+}
+
+#[test]
+fn test_locale_scaled() {
+    let fr = NumberFormat::new()
+        .with_group_separator('\u{202f}')
+        .with_decimal_separator(',');
+    for (mantissa, exponent, s) in [
+        (0, 0, "0"),
+        (0, -2, "0,00"),
+        (5, -3, "0,005"),
+        (-5, -3, "-0,005"),
+        (1_234_567, -1, "123\u{202f}456,7"),
+        (-12, 4, "-120\u{202f}000"),
+    ] {
+        let n = Scaled::new(BigInt::from(mantissa), exponent);
+        assert_eq!(fr.format_scaled(&n), s);
+        let parsed = fr.parse_scaled(s).unwrap();
+        assert_eq!(parsed, n);
+        if exponent <= 0 {
+            assert_eq!(parsed.exponent(), exponent);
+        }
+    }
+    assert_eq!(
+        fr.parse_scaled("1234,50").unwrap().into_parts(),
+        (BigInt::from(123_450), -2)
+    );
+}
+
+#[test]
+fn test_locale_parse_invalid() {
+    let de = NumberFormat::new()
+        .with_group_separator('.')
+        .with_decimal_separator(',');
+    for s in [
+        "1.23", "1.2345", "1234.567", ".123", "123.", "1..234", "1.234.56", "1,2.3", "1,", ",5",
+        "1,5,0", "+1", "1 234", "1_000", "--1",
+    ] {
+        assert!(de.parse_scaled(s).is_err(), "{s:?}");
+        assert!(de.parse_int(s).is_err(), "{s:?}");
+    }
+    assert!(de.parse_int("").is_err());
+    assert!(de.parse_int("-").is_err());
+    assert!(de.parse_uint("-1").is_err());
+    assert!(de.parse_int("1,5").is_err());
+    assert!(NumberFormat::new().parse_uint("1,000").is_err());
+}
@@ -0,0 +1,84 @@
+use num_bigint::lowlevel::{self, Limb, LIMB_BITS};
+use num_bigint::BigUint;
+use num_traits::One;
+use std::cmp::Ordering;
+
+fn value(limbs: &[Limb]) -> BigUint {
+    let bytes: Vec<u8> = limbs.iter().flat_map(|l| l.to_le_bytes()).collect();
+    BigUint::from_bytes_le(&bytes)
+}
+
+fn samples() -> Vec<Vec<Limb>> {
+    vec![
+        vec![],
+        vec![0],
+        vec![Limb::MAX],
+        vec![1, 0, 0],
+        vec![Limb::MAX; 3],
+        vec![0x0123_4567, Limb::MAX / 3, 7],
+        vec![Limb::MAX, 0, Limb::MAX, 1],
+        (1..=9).map(|i| Limb::MAX / i).collect(),
+    ]
+}
+
+#[test]
+fn test_add_sub_n() {
+    for a in samples() {
+        for b in samples().into_iter().filter(|b| b.len() == a.len()) {
+            let base = BigUint::one() << (u32::try_from(a.len()).unwrap() * LIMB_BITS);
+            let mut r = vec![0; a.len()];
+
+            let carry = lowlevel::add_n(&mut r, &a, &b);
+            assert_eq!(value(&r) + &base * carry, value(&a) + value(&b));
+
+            let borrow = lowlevel::sub_n(&mut r, &a, &b);
+            assert_eq!(value(&r) + value(&b), value(&a) + &base * borrow);
+
+            let expected = value(&a).cmp(&value(&b));
+            assert_eq!(lowlevel::cmp(&a, &b), expected);
+        }
+    }
+    assert_eq!(lowlevel::cmp(&[], &[]), Ordering::Equal);
+}
+
+#[test]
+fn test_mul_basecase() {
+    for a in samples() {
+        for b in samples() {
+            let mut r = vec![Limb::MAX; a.len() + b.len()];
+            lowlevel::mul_basecase(&mut r, &a, &b);
+            assert_eq!(value(&r), value(&a) * value(&b));
+        }
+    }
+}
+
+#[test]
+fn test_shl_shr() {
+    for a in samples() {
+        for shift in [0, 1, 7, LIMB_BITS / 2, LIMB_BITS - 1] {
+            let bits = u32::try_from(a.len()).unwrap() * LIMB_BITS;
+            let mut r = vec![0; a.len()];
+
+            let out = lowlevel::shl(&mut r, &a, shift);
+            let shifted = value(&a) << shift;
+            assert_eq!(value(&r) + (BigUint::from(out) << bits), shifted);
+
+            let out = lowlevel::shr(&mut r, &a, shift);
+            let low = value(&a) - ((value(&a) >> shift) << shift);
+            assert_eq!(value(&r), value(&a) >> shift);
+            assert_eq!(BigUint::from(out) >> ((LIMB_BITS - shift) % LIMB_BITS), low);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "add_n operands must have the same length")]
+fn test_add_n_mismatched() {
+    lowlevel::add_n(&mut [0; 2], &[1, 2], &[3]);
+}
+
+#[test]
+#[should_panic(expected = "shl shift must be less than LIMB_BITS")]
+fn test_shl_too_far() {
+    lowlevel::shl(&mut [0], &[1], LIMB_BITS);
+}
@@ -106,6 +106,73 @@ mod biguint {
         assert!(even_modpow < even_m);
         assert_eq!(even_modpow % m, r);
     }
+
+    #[test]
+    fn test_modpow_crt() {
+        // Two Mersenne primes, standing in for an RSA key.
+        let p = (BigUint::from(1u8) << 127u32) - 1u8;
+        let q = (BigUint::from(1u8) << 89u32) - 1u8;
+        let n = &p * &q;
+        let e = BigUint::from(65537u32);
+        let phi = (&p - 1u8) * (&q - 1u8);
+        let d = e.modinv(&phi).unwrap();
+        let dp = &d % (&p - 1u8);
+        let dq = &d % (&q - 1u8);
+        let qinv = q.modinv(&p).unwrap();
+
+        let b = BigUint::from_str_radix(super::BIG_B, 16).unwrap();
+        for c in [
+            BigUint::from(0u8),
+            BigUint::from(2u8),
+            &b % &n,
+            &n - 1u8,
+            &p * 3u8,
+        ] {
+            let m = c.modpow(&d, &n);
+            assert_eq!(c.modpow_crt(&dp, &dq, &p, &q, &qinv), m);
+            assert_eq!(c.modpow_crt_checked(&dp, &dq, &p, &q, &qinv, &e), Some(m));
+        }
+
+        // A fault in one half is caught by the check.
+        let c = BigUint::from(2u8);
+        let bad_dp = &dp + 1u8;
+        assert_eq!(c.modpow_crt_checked(&bad_dp, &dq, &p, &q, &qinv, &e), None);
+    }
+
+    #[test]
+    fn test_modinv_ct() {
+        let b = BigUint::from_str_radix(super::BIG_B, 16).unwrap();
+        let modp = BigUint::from_str_radix(super::BIG_M, 16).unwrap();
+        let moduli = [
+            BigUint::from(1u8),
+            BigUint::from(3u8),
+            BigUint::from(45u8),
+            BigUint::from(u64::MAX),
+            (BigUint::from(1u8) << 127u32) - 1u8,
+            BigUint::from(3u8).pow(100u32),
+            modp,
+        ];
+        for m in &moduli {
+            for a in [
+                BigUint::from(0u8),
+                BigUint::from(1u8),
+                BigUint::from(2u8),
+                BigUint::from(3u8),
+                m - 1u8,
+                m + 2u8,
+                &b % m,
+                b.clone(),
+            ] {
+                assert_eq!(a.modinv_ct(m), a.modinv(m), "{a}^-1 (mod {m})");
+            }
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "modulus must be odd")]
+    fn test_modinv_ct_even() {
+        let _ = BigUint::from(3u8).modinv_ct(&BigUint::from(10u8));
+    }
 }
 
 mod bigint {
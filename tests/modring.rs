@@ -0,0 +1,135 @@
+use num_bigint::{BigUint, FixedBasePow, ModRing, OddModulus};
+use num_traits::{One, Zero};
+
+fn moduli() -> Vec<BigUint> {
+    vec![
+        BigUint::one(),
+        BigUint::from(2u8),
+        BigUint::from(101u8),
+        BigUint::from(1u8) << 64u32,
+        (BigUint::one() << 127u32) - 1u8,
+        (BigUint::one() << 300u32) + 1u8,
+        (BigUint::one() << 300u32) - 2u8,
+        BigUint::from(3u8).pow(200),
+    ]
+}
+
+fn values(modulus: &BigUint) -> Vec<BigUint> {
+    vec![
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(12345u32),
+        modulus - 1u8,
+        modulus / 3u8,
+        modulus * 5u8 + 7u8,
+        BigUint::from(7u8).pow(150),
+    ]
+}
+
+#[test]
+fn test_ring_ops() {
+    for m in moduli() {
+        let ring = ModRing::new(m.clone());
+        assert_eq!(ring.modulus(), &m);
+        for a in values(&m) {
+            let x = ring.element(&a);
+            assert_eq!(x.to_biguint(), &a % &m);
+            assert_eq!((-&x).to_biguint(), (&m - &a % &m) % &m);
+            for b in values(&m) {
+                let y = ring.element(&b);
+                assert_eq!((&x + &y).to_biguint(), (&a + &b) % &m);
+                assert_eq!((&x - &y).to_biguint(), (&a + &m * &b - &b) % &m);
+                assert_eq!((&x * &y).to_biguint(), (&a * &b) % &m);
+                assert_eq!(BigUint::from(x.clone() * y), (&a * &b) % &m);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ring_pow_inv() {
+    for m in moduli() {
+        let ring = ModRing::new(m.clone());
+        for a in values(&m) {
+            let x = ring.element(&a);
+            for e in [0u32, 1, 2, 65537] {
+                let e = BigUint::from(e);
+                assert_eq!(x.pow(&e).to_biguint(), a.modpow(&e, &m));
+            }
+            match a.modinv(&m) {
+                Some(inv) => {
+                    let y = x.inv().unwrap();
+                    assert_eq!(y.to_biguint(), inv);
+                    assert_eq!(&x * &y, ring.one());
+                }
+                None => assert!(x.inv().is_none()),
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ring_identities() {
+    let ring = ModRing::new(BigUint::from(10u8));
+    assert!(ring.zero().is_zero());
+    assert_eq!(ring.one().to_biguint(), BigUint::one());
+    assert_eq!(ring.element(&BigUint::from(13u8)).to_string(), "3");
+    assert!(ModRing::new(BigUint::one()).one().is_zero());
+}
+
+#[test]
+#[should_panic]
+fn test_ring_mismatch() {
+    let a = ModRing::new(BigUint::from(7u8));
+    let b = ModRing::new(BigUint::from(11u8));
+    let _ = a.one() + b.one();
+}
+
+#[test]
+#[should_panic]
+fn test_ring_zero_modulus() {
+    let _ = ModRing::new(BigUint::zero());
+}
+
+#[test]
+fn test_fixed_base_pow() {
+    let exponents = [
+        BigUint::zero(),
+        BigUint::one(),
+        BigUint::from(2u8),
+        BigUint::from(255u8),
+        BigUint::from(u64::MAX),
+        (BigUint::one() << 299u32) + 12345u32,
+        (BigUint::one() << 300u32) - 1u8,
+    ];
+    for m in moduli() {
+        for base in values(&m) {
+            for max_bits in [1, 64, 300] {
+                let table = FixedBasePow::new(&base, m.clone(), max_bits);
+                assert_eq!(table.modulus(), &m);
+                for x in &exponents {
+                    // Exponents wider than the table fall back to square-and-multiply.
+                    assert_eq!(table.pow(x), base.modpow(x, &m), "{base}^{x} mod {m}");
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_ring_from_odd() {
+    for m in moduli().into_iter().filter(|m| m.bit(0)) {
+        let ring = ModRing::new(m.clone());
+        let odd = ModRing::from_odd(OddModulus::new(m.clone()).unwrap());
+        assert_eq!(odd.modulus(), &m);
+        for x in values(&m) {
+            let a = ring.element(&x);
+            let b = odd.element(&x);
+            assert_eq!((&b * &b).to_biguint(), (&a * &a).to_biguint());
+            assert_eq!(
+                b.inv().map(|i| i.to_biguint()),
+                a.inv().map(|i| i.to_biguint())
+            );
+        }
+    }
+}
@@ -0,0 +1,40 @@
+#![cfg(feature = "openssl")]
+
+use num_bigint::{BigInt, BigUint};
+use openssl::bn::BigNum;
+
+const VALUES: &[&str] = &[
+    "0",
+    "1",
+    "255",
+    "256",
+    "18446744073709551615",
+    "18446744073709551616",
+    "123456789012345678901234567890123456789012345678901234567890",
+];
+
+#[test]
+fn test_biguint_bn_roundtrip() {
+    for s in VALUES {
+        let x: BigUint = s.parse().unwrap();
+        let bn = x.to_bn().unwrap();
+        assert_eq!(bn.to_dec_str().unwrap().to_string(), *s);
+        assert_eq!(BigUint::from_bn(&bn), Some(x));
+    }
+
+    let neg = BigNum::from_dec_str("-1").unwrap();
+    assert_eq!(BigUint::from_bn(&neg), None);
+}
+
+#[test]
+fn test_bigint_bn_roundtrip() {
+    for s in VALUES {
+        for s in [s.to_string(), format!("-{s}")] {
+            let x: BigInt = s.parse().unwrap();
+            let bn = x.to_bn().unwrap();
+            let expected = if s == "-0" { "0" } else { &s };
+            assert_eq!(bn.to_dec_str().unwrap().to_string(), expected);
+            assert_eq!(BigInt::from_bn(&bn), x);
+        }
+    }
+}
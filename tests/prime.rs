@@ -0,0 +1,207 @@
+use num_bigint::{BigUint, PrattCertificate};
+
+fn is_prime_naive(n: u32) -> bool {
+    n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0)
+}
+
+#[test]
+fn test_strong_lucas_small() {
+    // All strong Lucas pseudoprimes with Selfridge parameters below the limit, OEIS A217255.
+    let pseudoprimes = [5459, 5777, 10877, 16109, 18971];
+    for n in 0..20_000u32 {
+        let expected = is_prime_naive(n) || pseudoprimes.contains(&n);
+        assert_eq!(BigUint::from(n).is_strong_lucas_prp(), expected, "{n}");
+    }
+}
+
+#[test]
+fn test_strong_lucas_large() {
+    let m127 = (BigUint::from(1u8) << 127u32) - 1u8;
+    let m89 = (BigUint::from(1u8) << 89u32) - 1u8;
+    assert!(m127.is_strong_lucas_prp());
+    assert!(m89.is_strong_lucas_prp());
+    assert!(!(&m127 * &m89).is_strong_lucas_prp());
+    assert!(!(&m127 * &m127).is_strong_lucas_prp());
+    assert!(!((BigUint::from(1u8) << 128u32) + 1u8).is_strong_lucas_prp());
+}
+
+#[test]
+fn test_prime_deterministic_small() {
+    for n in 0..20_000u32 {
+        assert_eq!(
+            BigUint::from(n).is_prime_deterministic(),
+            is_prime_naive(n),
+            "{n}"
+        );
+    }
+}
+
+#[test]
+fn test_prime_deterministic_pseudoprimes() {
+    // The smallest strong pseudoprimes to each prefix of the prime bases.
+    let pseudoprimes: [u128; 9] = [
+        2_047,
+        1_373_653,
+        25_326_001,
+        3_215_031_751,
+        2_152_302_898_747,
+        3_474_749_660_383,
+        341_550_071_728_321,
+        3_825_123_056_546_413_051,
+        318_665_857_834_031_151_167_461,
+    ];
+    for n in pseudoprimes {
+        assert!(!BigUint::from(n).is_prime_deterministic(), "{n}");
+    }
+    assert!(!BigUint::from(3_317_044_064_679_887_385_961_981u128).is_prime_deterministic());
+
+    // Primes on either side of the deterministic bound.
+    assert!(BigUint::from(1_000_000_007u32).is_prime_deterministic());
+    assert!(BigUint::from(18_446_744_073_709_551_557u64).is_prime_deterministic());
+    let m127 = (BigUint::from(1u8) << 127u32) - 1u8;
+    let m89 = (BigUint::from(1u8) << 89u32) - 1u8;
+    assert!(m127.is_prime_deterministic());
+    assert!(m89.is_prime_deterministic());
+    assert!(!(&m127 * &m89).is_prime_deterministic());
+}
+
+#[test]
+fn test_primes_in_range() {
+    let range = |a: u32, b: u32| BigUint::primes_in_range(BigUint::from(a)..BigUint::from(b));
+
+    let expected: Vec<BigUint> = (0..20_000u32)
+        .filter(|&n| is_prime_naive(n))
+        .map(BigUint::from)
+        .collect();
+    assert_eq!(range(0, 20_000).collect::<Vec<_>>(), expected);
+    assert_eq!(range(2, 3).collect::<Vec<_>>(), [BigUint::from(2u8)]);
+    assert_eq!(range(24, 29).count(), 0);
+    assert_eq!(range(10, 5).count(), 0);
+
+    // Around 2^32, where survivors of the sieve need confirming.
+    let start = (1u64 << 32) - 100;
+    let primes: Vec<BigUint> =
+        BigUint::primes_in_range(BigUint::from(start)..BigUint::from(start + 200)).collect();
+    let expected: Vec<BigUint> = (start..start + 200)
+        .filter(|&n| {
+            (2..=65_536u64)
+                .take_while(|d| d * d <= n)
+                .all(|d| n % d != 0)
+        })
+        .map(BigUint::from)
+        .collect();
+    assert_eq!(primes, expected);
+
+    let start = BigUint::from(1u8) << 200u32;
+    let primes: Vec<BigUint> = BigUint::primes_in_range(start.clone()..&start + 1000u32).collect();
+    assert_eq!(primes[..2], [&start + 235u32, &start + 697u32]);
+    assert!(primes.iter().all(BigUint::is_prime_deterministic));
+}
+
+#[test]
+fn test_quadratic_residue() {
+    for m in 1..200u32 {
+        let squares: Vec<u32> = (0..m).map(|x| x * x % m).collect();
+        let modulus = BigUint::from(m);
+        let factored = modulus.factorize();
+        for a in 0..2 * m {
+            let expected = squares.contains(&(a % m));
+            let a = BigUint::from(a);
+            assert_eq!(
+                a.is_quadratic_residue_mod(&factored),
+                expected,
+                "{a} mod {m}"
+            );
+            if is_prime_naive(m) {
+                assert_eq!(a.is_quadratic_residue(&modulus), expected, "{a} mod {m}");
+            }
+        }
+    }
+}
+
+#[test]
+fn test_hensel_lift() {
+    use num_bigint::BigInt;
+
+    let poly = |coeffs: &[i32]| coeffs.iter().map(|&c| BigInt::from(c)).collect::<Vec<_>>();
+    let eval = |f: &[BigInt], x: &BigUint, m: &BigUint| {
+        let x = BigInt::from(x.clone());
+        let value = f.iter().rev().fold(BigInt::from(0), |acc, c| acc * &x + c);
+        num_integer::Integer::mod_floor(&value, &BigInt::from(m.clone()))
+    };
+
+    // Square roots of 2 modulo powers of 7, a cube root of 2 modulo powers of 5, and a linear
+    // polynomial modulo powers of a large prime.
+    let cases: [(&[i32], u32, u32); 4] = [
+        (&[-2, 0, 1], 7, 3),
+        (&[-2, 0, 1], 7, 4),
+        (&[-2, 0, 0, 1], 5, 3),
+        (&[-1, 3], 1_000_000_007, 333_333_336),
+    ];
+    for &(coeffs, p, r) in &cases {
+        let f = poly(coeffs);
+        let p = BigUint::from(p);
+        for k in [0, 1, 2, 5, 16, 33] {
+            let m = p.pow(k);
+            let root = BigUint::hensel_lift(&BigUint::from(r), &p, k, &f).unwrap();
+            assert!(root < m);
+            assert_eq!(
+                eval(&f, &root, &m),
+                BigInt::from(0),
+                "{coeffs:?} mod {p}^{k}"
+            );
+            if k > 0 {
+                assert_eq!(&root % &p, BigUint::from(r) % &p);
+            }
+        }
+    }
+
+    // Not a root, and a repeated root of x² modulo 3.
+    let p = BigUint::from(3u8);
+    assert!(BigUint::hensel_lift(&BigUint::from(1u8), &p, 4, &poly(&[-2, 0, 1])).is_none());
+    assert!(BigUint::hensel_lift(&BigUint::from(0u8), &p, 4, &poly(&[0, 0, 1])).is_none());
+}
+
+#[test]
+fn test_pratt_certificate() {
+    for n in 0..2_000u32 {
+        let n = BigUint::from(n);
+        match n.certify_prime() {
+            Some(cert) => {
+                assert!(n.is_prime_deterministic(), "{n}");
+                assert_eq!(cert.prime(), &n);
+                assert!(n.verify_certificate(&cert), "{n}");
+            }
+            None => assert!(!n.is_prime_deterministic(), "{n}"),
+        }
+    }
+
+    let m127 = (BigUint::from(1u8) << 127u32) - 1u8;
+    let cert = m127.certify_prime().unwrap();
+    assert!(m127.verify_certificate(&cert));
+    assert!(!(&m127 + 2u8).verify_certificate(&cert));
+    assert!((BigUint::from(1u8) << 127u32).certify_prime().is_none());
+}
+
+#[test]
+fn test_pratt_certificate_forged() {
+    let p = BigUint::from(1_000_003u32);
+    let cert = p.certify_prime().unwrap();
+
+    // 1 has order 1, not `p - 1`.
+    let forged = PrattCertificate::new(p.clone(), BigUint::from(1u8), cert.factors().to_vec());
+    assert!(!p.verify_certificate(&forged));
+
+    // Dropping a factor breaks the factorization of `p - 1`.
+    let mut factors = cert.factors().to_vec();
+    factors.pop();
+    let forged = PrattCertificate::new(p.clone(), cert.witness().clone(), factors);
+    assert!(!p.verify_certificate(&forged));
+
+    // A composite cannot be passed off as a factor, with 9 standing in for 3 * 3.
+    let n = BigUint::from(19u8);
+    let nine = PrattCertificate::new(BigUint::from(9u8), BigUint::from(2u8), Vec::new());
+    let two = BigUint::from(2u8).certify_prime().unwrap();
+    let forged = PrattCertificate::new(n.clone(), BigUint::from(2u8), vec![(two, 1), (nine, 1)]);
+    assert!(!n.verify_certificate(&forged));
+}
@@ -0,0 +1,45 @@
+#![cfg(feature = "primitive-types")]
+
+use num_bigint::BigUint;
+use num_traits::{One, Zero};
+use primitive_types::{H256, U256, U512};
+
+#[test]
+fn test_u256() {
+    let max = (BigUint::one() << 256u32) - 1u32;
+    assert_eq!(U256::try_from(&max).unwrap(), U256::MAX);
+    assert_eq!(BigUint::from(U256::MAX), max);
+    assert!(U256::try_from(&max + 1u32).is_err());
+
+    let x = BigUint::parse_bytes(b"1234567890abcdef1234567890abcdef1234567890", 16).unwrap();
+    let u = U256::from_str_radix("1234567890abcdef1234567890abcdef1234567890", 16).unwrap();
+    assert_eq!(U256::try_from(x.clone()).unwrap(), u);
+    assert_eq!(BigUint::from(&u), x);
+
+    assert_eq!(U256::try_from(BigUint::zero()).unwrap(), U256::zero());
+    assert_eq!(BigUint::from(U256::zero()), BigUint::zero());
+}
+
+#[test]
+fn test_u512() {
+    let max = (BigUint::one() << 512u32) - 1u32;
+    assert_eq!(U512::try_from(&max).unwrap(), U512::MAX);
+    assert_eq!(BigUint::from(U512::MAX), max);
+    let err = U512::try_from(&max + 1u32).unwrap_err();
+    assert_eq!(err.into_original(), max + 1u32);
+}
+
+#[test]
+fn test_h256() {
+    let mut bytes = [0u8; 32];
+    bytes[30] = 0x12;
+    bytes[31] = 0x34;
+    let h = H256(bytes);
+    assert_eq!(BigUint::from(h), BigUint::from(0x1234u32));
+    assert_eq!(H256::try_from(BigUint::from(0x1234u32)).unwrap(), h);
+
+    let max = (BigUint::one() << 256u32) - 1u32;
+    assert_eq!(H256::try_from(&max).unwrap(), H256::repeat_byte(0xff));
+    assert!(H256::try_from(&max + 1u32).is_err());
+    assert_eq!(H256::try_from(BigUint::zero()).unwrap(), H256::zero());
+}
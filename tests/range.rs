@@ -0,0 +1,74 @@
+use num_bigint::{BigInt, BigUint};
+
+fn biguints(values: &[u64]) -> Vec<BigUint> {
+    values.iter().map(|&v| BigUint::from(v)).collect()
+}
+
+fn bigints(values: &[i64]) -> Vec<BigInt> {
+    values.iter().map(|&v| BigInt::from(v)).collect()
+}
+
+#[test]
+fn test_biguint_range() {
+    let r = BigUint::range(BigUint::from(2u8)..BigUint::from(5u8));
+    assert_eq!(r.size_hint(), (3, Some(3)));
+    assert_eq!(r.collect::<Vec<_>>(), biguints(&[2, 3, 4]));
+
+    let r = BigUint::range(BigUint::from(2u8)..BigUint::from(5u8));
+    assert_eq!(r.rev().collect::<Vec<_>>(), biguints(&[4, 3, 2]));
+
+    let mut r = BigUint::range(BigUint::from(5u8)..BigUint::from(2u8));
+    assert_eq!(r.size_hint(), (0, Some(0)));
+    assert_eq!(r.next(), None);
+    assert_eq!(r.next_back(), None);
+
+    // crossing a digit boundary
+    let start = BigUint::from(u64::MAX) - 1u8;
+    let r = BigUint::range(start.clone()..&start + 4u8);
+    let expected: Vec<_> = (0..4u8).map(|i| &start + i).collect();
+    assert_eq!(r.collect::<Vec<_>>(), expected);
+
+    let huge = BigUint::from(1u8) << 200u32;
+    assert_eq!(
+        BigUint::range(BigUint::from(0u8)..huge).size_hint(),
+        (usize::MAX, None)
+    );
+}
+
+#[test]
+fn test_biguint_range_inclusive() {
+    let r = BigUint::range_inclusive(BigUint::from(0u8)..=BigUint::from(2u8));
+    assert_eq!(r.size_hint(), (3, Some(3)));
+    assert_eq!(r.collect::<Vec<_>>(), biguints(&[0, 1, 2]));
+
+    let r = BigUint::range_inclusive(BigUint::from(0u8)..=BigUint::from(2u8));
+    assert_eq!(r.rev().collect::<Vec<_>>(), biguints(&[2, 1, 0]));
+
+    let r = BigUint::range_inclusive(BigUint::from(0u8)..=BigUint::from(0u8));
+    assert_eq!(r.collect::<Vec<_>>(), biguints(&[0]));
+
+    let mut r = BigUint::range_inclusive(BigUint::from(3u8)..=BigUint::from(2u8));
+    assert_eq!(r.size_hint(), (0, Some(0)));
+    assert_eq!(r.next(), None);
+
+    // meeting in the middle from both ends
+    let mut r = BigUint::range_inclusive(BigUint::from(1u8)..=BigUint::from(3u8));
+    assert_eq!(r.next(), Some(BigUint::from(1u8)));
+    assert_eq!(r.next_back(), Some(BigUint::from(3u8)));
+    assert_eq!(r.next_back(), Some(BigUint::from(2u8)));
+    assert_eq!(r.next(), None);
+    assert_eq!(r.next_back(), None);
+}
+
+#[test]
+fn test_bigint_range() {
+    let r = BigInt::range(BigInt::from(-2)..BigInt::from(2));
+    assert_eq!(r.collect::<Vec<_>>(), bigints(&[-2, -1, 0, 1]));
+
+    let r = BigInt::range_inclusive(BigInt::from(-2)..=BigInt::from(1));
+    assert_eq!(r.rev().collect::<Vec<_>>(), bigints(&[1, 0, -1, -2]));
+
+    let start = BigInt::from(i64::MIN);
+    let sum: BigInt = BigInt::range(&start - 2..start.clone()).sum();
+    assert_eq!(sum, start * 2 - 3);
+}
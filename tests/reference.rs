@@ -0,0 +1,62 @@
+#![cfg(feature = "reference")]
+
+use num_bigint::{reference, BigUint};
+use num_traits::Zero;
+use std::convert::TryFrom;
+
+/// Returns a deterministic pseudo-random value of `len` 32-bit digits.
+fn value(len: usize, seed: &mut u64) -> BigUint {
+    let digits = (0..len)
+        .map(|_| {
+            *seed ^= *seed << 13;
+            *seed ^= *seed >> 7;
+            *seed ^= *seed << 17;
+            u32::try_from(*seed >> 32).unwrap()
+        })
+        .collect();
+    BigUint::new(digits)
+}
+
+#[test]
+fn test_reference_mul_div() {
+    let mut seed = 0x2545_f491_4f6c_dd1d;
+    let lens = [0, 1, 2, 5, 33, 70, 150, 600];
+    for &m in &lens {
+        for &n in &lens {
+            let x = value(m, &mut seed);
+            let y = value(n, &mut seed);
+            assert_eq!(reference::mul(&x, &y), &x * &y);
+            if !y.is_zero() {
+                let big = &x * &y + value(n / 2, &mut seed);
+                assert_eq!(reference::div_rem(&big, &y), (&big / &y, &big % &y));
+            }
+        }
+    }
+
+    let all_ones = (BigUint::from(1u8) << 4000u32) - 1u8;
+    assert_eq!(reference::mul(&all_ones, &all_ones), &all_ones * &all_ones);
+}
+
+#[test]
+#[should_panic(expected = "attempt to divide by zero")]
+fn test_reference_div_zero() {
+    let _ = reference::div_rem(&BigUint::from(1u8), &BigUint::zero());
+}
+
+#[test]
+fn test_reference_radix() {
+    let mut seed = 0x9e37_79b9_7f4a_7c15;
+    for &len in &[0, 1, 3, 40, 300] {
+        let x = value(len, &mut seed);
+        for radix in [2, 3, 7, 10, 16, 36, 100, 255, 256] {
+            let digits = x.to_radix_be(radix);
+            assert_eq!(reference::to_radix_be(&x, radix), digits);
+            assert_eq!(reference::from_radix_be(&digits, radix), Some(x.clone()));
+            if radix <= 36 {
+                assert_eq!(reference::to_str_radix(&x, radix), x.to_str_radix(radix));
+            }
+        }
+    }
+    assert_eq!(reference::from_radix_be(&[1, 10], 10), None);
+    assert_eq!(reference::from_radix_be(&[], 10), Some(BigUint::zero()));
+}
@@ -33,6 +33,25 @@ mod biguint {
         check(120u32, 2);
     }
 
+    #[test]
+    fn test_sqrt_rem_large() {
+        // Sizes around the recursive algorithm's cutoff and several levels above it.
+        for bits in [4000u32, 4096, 4097, 4100, 8191, 20_000, 65_537] {
+            let x = (BigUint::one() << bits) / 3u32 + 12_345u32;
+            let (s, r) = x.sqrt_rem();
+            assert_eq!(&s * &s + &r, x);
+            assert!(r <= &s << 1u32);
+            assert_eq!(s, x.sqrt());
+
+            // Perfect squares and their neighbours.
+            let sq = &s * &s;
+            assert_eq!(sq.sqrt_rem(), (s.clone(), BigUint::zero()));
+            assert_eq!((&sq - 1u32).sqrt(), &s - 1u32);
+            let next = (&s + 1u32).pow(2u32);
+            assert_eq!((&next - 1u32).sqrt_rem(), (s.clone(), &s << 1u32));
+        }
+    }
+
     #[test]
     fn test_cbrt() {
         check(8u32, 3);
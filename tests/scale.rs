@@ -0,0 +1,69 @@
+#![cfg(feature = "scale")]
+
+use num_bigint::{BigUint, StackBigUint};
+use num_traits::{Num, Pow};
+use parity_scale_codec::{Compact, Decode, Encode, MaxEncodedLen};
+
+#[test]
+fn test_scale_biguint() {
+    let cases = [
+        ("0", &[0x00][..]),
+        ("1", &[0x04, 0x01]),
+        ("100", &[0x08, 0x00, 0x01]),
+        (
+            "ffffffffffffffffff",
+            &[0x24, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff],
+        ),
+    ];
+    for (hex, bytes) in cases {
+        let n = BigUint::from_str_radix(hex, 16).unwrap();
+        assert_eq!(n.encode(), bytes, "{hex}");
+        assert_eq!(n.size_hint(), bytes.len());
+        assert_eq!(BigUint::decode(&mut &bytes[..]).unwrap(), n);
+        // The same bytes as the `Vec<u8>` of the little-endian value.
+        assert_eq!(
+            Vec::<u8>::decode(&mut &bytes[..]).unwrap().len(),
+            bytes.len() - 1
+        );
+    }
+
+    let big = BigUint::from(3u8).pow(1000u32);
+    assert_eq!(BigUint::decode(&mut &big.encode()[..]).unwrap(), big);
+    assert_eq!(big.encode().len(), big.size_hint());
+}
+
+#[test]
+fn test_scale_invalid() {
+    // Trailing zero byte.
+    assert!(BigUint::decode(&mut &[0x08, 0x01, 0x00][..]).is_err());
+    assert!(StackBigUint::<1>::decode(&mut &[0x08, 0x01, 0x00][..]).is_err());
+    // Truncated.
+    assert!(BigUint::decode(&mut &[0x08, 0x01][..]).is_err());
+    assert!(StackBigUint::<1>::decode(&mut &[0x08, 0x01][..]).is_err());
+    // Larger than the capacity.
+    let too_big = BigUint::from(1u8) << 64u32;
+    assert!(StackBigUint::<1>::decode(&mut &too_big.encode()[..]).is_err());
+    // A huge length prefix fails without allocating.
+    let prefix = Compact(u32::MAX).encode();
+    assert!(BigUint::decode(&mut &prefix[..]).is_err());
+}
+
+#[test]
+fn test_scale_stack() {
+    for n in [
+        BigUint::from(0u8),
+        BigUint::from(0xabu8),
+        BigUint::from(u64::MAX),
+        BigUint::from(u64::MAX) << 7u32,
+        (BigUint::from(1u8) << 256u32) - 1u8,
+    ] {
+        let s = StackBigUint::<4>::try_from(&n).unwrap();
+        let bytes = s.encode();
+        assert_eq!(bytes, n.encode());
+        assert_eq!(s.size_hint(), bytes.len());
+        assert_eq!(StackBigUint::<4>::decode(&mut &bytes[..]).unwrap(), s);
+        assert!(bytes.len() <= StackBigUint::<4>::max_encoded_len());
+    }
+    assert_eq!(StackBigUint::<4>::max_encoded_len(), 33);
+    assert_eq!(StackBigUint::<8>::max_encoded_len(), 66);
+}
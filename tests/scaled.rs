@@ -0,0 +1,108 @@
+use num_bigint::{BigInt, Scaled};
+use num_traits::Zero;
+
+fn scaled(mantissa: i64, exponent: i64) -> Scaled {
+    Scaled::new(BigInt::from(mantissa), exponent)
+}
+
+#[test]
+fn test_scaled_arithmetic() {
+    let a = scaled(125, -2); // 1.25
+    let b = scaled(3, 1); // 30
+    assert_eq!((&a + &b).into_parts(), (BigInt::from(3125), -2));
+    assert_eq!((&a - &b).into_parts(), (BigInt::from(-2875), -2));
+    assert_eq!((&a * &b).into_parts(), (BigInt::from(375), -1));
+    assert_eq!((-&a).into_parts(), (BigInt::from(-125), -2));
+    assert!((&a - &a).is_zero());
+
+    let big = Scaled::new(BigInt::from(7).pow(80u32), -60);
+    assert_eq!(&(&big + &a) - &a, big);
+    assert_eq!(&big * &Scaled::from(BigInt::from(1)), big);
+}
+
+#[test]
+fn test_scaled_cmp() {
+    assert_eq!(scaled(10, -1), scaled(1, 0));
+    assert_eq!(scaled(0, 5), scaled(0, -5));
+    assert!(scaled(-1, 3) < scaled(0, 0));
+    assert!(scaled(-1, 3) < scaled(-999, 0));
+    assert!(scaled(1, 3) > scaled(999, 0));
+    assert!(scaled(1001, -3) > scaled(1, 0));
+    assert!(scaled(-1001, -3) < scaled(-1, 0));
+
+    let mut values = vec![scaled(5, 0), scaled(-3, -1), scaled(49, -1), scaled(0, 2)];
+    values.sort();
+    assert_eq!(
+        values,
+        [scaled(-3, -1), scaled(0, 0), scaled(49, -1), scaled(5, 0)]
+    );
+}
+
+#[test]
+fn test_scaled_round_to_scale() {
+    let cases = [
+        (25, -1, 0, 2),
+        (35, -1, 0, 4),
+        (-25, -1, 0, -2),
+        (-35, -1, 0, -4),
+        (26, -1, 0, 3),
+        (-24, -1, 0, -2),
+        (12345, -3, 1, 123),
+        (12355, -3, 1, 124),
+        (149, 0, -2, 1),
+        (150, 0, -2, 2),
+        (250, 0, -2, 2),
+        (4, -3, 1, 0),
+    ];
+    for (m, e, scale, expected) in cases {
+        let rounded = scaled(m, e).round_to_scale(scale);
+        assert_eq!(
+            rounded.into_parts(),
+            (BigInt::from(expected), -scale),
+            "{m}e{e}"
+        );
+    }
+
+    let x = scaled(-7, 2);
+    assert_eq!(x.round_to_scale(2).into_parts(), (BigInt::from(-70000), -2));
+}
+
+#[test]
+fn test_scaled_display() {
+    assert_eq!(scaled(12345, -2).to_string(), "123.45");
+    assert_eq!(scaled(-12345, -7).to_string(), "-0.0012345");
+    assert_eq!(scaled(12, -2).to_string(), "0.12");
+    assert_eq!(scaled(-12, 3).to_string(), "-12000");
+    assert_eq!(scaled(0, 3).to_string(), "0");
+    assert_eq!(scaled(0, -3).to_string(), "0.000");
+    assert_eq!(format!("{:>8}", scaled(15, -1)), "     1.5");
+    assert_eq!(format!("{:+}", scaled(15, -1)), "+1.5");
+}
+
+#[test]
+fn test_scaled_cmp_far_exponents() {
+    // Exponents far apart compare without building 10^(10^12).
+    let huge = scaled(1, 1_000_000_000_000);
+    let tiny = scaled(1, -1_000_000_000_000);
+    let one = scaled(1, 0);
+    assert!(huge > one);
+    assert!(tiny < one);
+    assert!(-&huge < -&one);
+    assert!(-&tiny > -&one);
+    assert!(scaled(i64::MAX, i64::MIN) < scaled(1, i64::MAX));
+    assert!(scaled(-1, i64::MAX) < scaled(i64::MIN, i64::MIN));
+    assert_ne!(huge, one);
+    assert_eq!(Ord::max(huge.clone(), one.clone()), huge);
+
+    let mut values = vec![huge.clone(), one.clone(), tiny.clone(), -&huge];
+    values.sort();
+    assert_eq!(values, [-&huge, tiny, one, huge]);
+
+    // Close magnitudes are still compared exactly.
+    let big = BigInt::from(10).pow(500u32);
+    assert_eq!(Scaled::new(big.clone(), -500), scaled(1, 0));
+    assert!(Scaled::new(big.clone() + 1, -500) > scaled(1, 0));
+    assert!(Scaled::new(big - 1, -500) < scaled(1, 0));
+    assert!(scaled(999, 0) < scaled(1, 3));
+    assert!(scaled(1000, 0) == scaled(1, 3));
+}
@@ -0,0 +1,42 @@
+#![cfg(feature = "scratch-pool")]
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+use std::thread;
+
+/// Values large enough to use Karatsuba multiplication, with some unbalanced sizes.
+fn values() -> Vec<BigUint> {
+    [40u32, 100, 150, 300, 1000]
+        .iter()
+        .map(|&digits| (BigUint::one() << (digits * 32)) / 7u8 + 12345u32)
+        .collect()
+}
+
+fn check_products() {
+    let values = values();
+    for _ in 0..3 {
+        for a in &values {
+            for b in &values {
+                let product = a * b;
+                assert_eq!(&product / a, *b);
+                assert_eq!(&product % a, BigUint::default());
+                assert_eq!(product, b * a);
+                assert_eq!((&product + 1u8) % b, BigUint::one() % b);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_repeated_products() {
+    check_products();
+}
+
+#[test]
+fn test_products_across_threads() {
+    let handles: Vec<_> = (0..4).map(|_| thread::spawn(check_products)).collect();
+    for handle in handles {
+        handle.join().unwrap();
+    }
+}
@@ -0,0 +1,34 @@
+#![cfg(feature = "serde")]
+
+use num_bigint::{serde_max_bits, set_serde_max_bits, BigUint, DEFAULT_SERDE_MAX_BITS};
+use serde::de::value::{Error, SeqDeserializer};
+use serde::Deserialize;
+
+/// Returns a deserializer for a sequence of 32-bit digits.
+fn digits(n: usize) -> SeqDeserializer<std::vec::IntoIter<u32>, Error> {
+    SeqDeserializer::new(vec![u32::MAX; n].into_iter())
+}
+
+#[test]
+fn test_serde_max_bits() {
+    let max = (BigUint::from(1u8) << 320u32) - 1u8;
+    assert_eq!(
+        BigUint::deserialize_with_max_bits(digits(10), 320),
+        Ok(max.clone())
+    );
+    assert_eq!(BigUint::deserialize_with_max_bits(digits(10), 300), Ok(max));
+    assert!(BigUint::deserialize_with_max_bits(digits(11), 320).is_err());
+    assert!(BigUint::deserialize_with_max_bits(digits(1), 0).is_err());
+    assert_eq!(
+        BigUint::deserialize_with_max_bits(digits(0), 0),
+        Ok(BigUint::from(0u8))
+    );
+
+    assert_eq!(serde_max_bits(), DEFAULT_SERDE_MAX_BITS);
+    assert!(BigUint::deserialize(digits(1000)).is_ok());
+    set_serde_max_bits(100);
+    assert_eq!(serde_max_bits(), 128);
+    assert!(BigUint::deserialize(digits(4)).is_ok());
+    assert!(BigUint::deserialize(digits(5)).is_err());
+    set_serde_max_bits(DEFAULT_SERDE_MAX_BITS);
+}
@@ -0,0 +1,122 @@
+use num_bigint::{BigUint, StackBigUint};
+use num_traits::{One, Zero};
+
+use std::collections::hash_map::DefaultHasher;
+use std::convert::TryFrom;
+use std::hash::{Hash, Hasher};
+
+type U256 = StackBigUint<4>;
+
+fn values() -> Vec<BigUint> {
+    let mut values: Vec<BigUint> = [0u64, 1, 2, 7, 10, u64::from(u32::MAX), u64::MAX]
+        .iter()
+        .map(|&v| BigUint::from(v))
+        .collect();
+    values.push(BigUint::from(u64::MAX) + 1u8);
+    values.push("123456789012345678901234567890123456789".parse().unwrap());
+    values.push((BigUint::one() << 200u32) - 1u8);
+    values.push((BigUint::one() << 255u32) + 12345u32);
+    values.push((BigUint::one() << 256u32) - 1u8);
+    values
+}
+
+fn stack(x: &BigUint) -> U256 {
+    U256::try_from(x).unwrap()
+}
+
+/// Converts a wide result back to the stack type, or `None` if it doesn't fit.
+fn fits(x: BigUint) -> Option<U256> {
+    U256::try_from(x).ok()
+}
+
+#[test]
+fn test_conversions() {
+    for x in values() {
+        let s = stack(&x);
+        assert_eq!(BigUint::from(s), x);
+        assert_eq!(s.to_string(), x.to_string());
+        assert_eq!(format!("{s:?}"), x.to_string());
+        assert_eq!(s.bits(), x.bits());
+        assert_eq!(s.to_u64_digits(), &x.to_u64_digits()[..]);
+        assert_eq!(U256::from_u64_digits(&x.to_u64_digits()), Some(s));
+    }
+    assert!(U256::try_from(BigUint::one() << 256u32).is_err());
+    assert_eq!(
+        U256::from_u64_digits(&[1, 2, 3, 4, 0, 0]),
+        U256::from_u64_digits(&[1, 2, 3, 4])
+    );
+    assert_eq!(U256::from_u64_digits(&[1, 2, 3, 4, 5]), None);
+    assert_eq!(U256::ZERO, U256::zero());
+    assert_eq!(U256::ONE, U256::one());
+    assert_eq!(U256::BITS, 256);
+    assert_eq!(StackBigUint::<0>::ONE, StackBigUint::<0>::ZERO);
+}
+
+#[test]
+fn test_arithmetic() {
+    let values = values();
+    for a in &values {
+        for b in &values {
+            let (x, y) = (stack(a), stack(b));
+            assert_eq!(x.checked_add(&y), fits(a + b));
+            assert_eq!(x.checked_mul(&y), fits(a * b));
+            assert_eq!(x.cmp(&y), a.cmp(b));
+            if a >= b {
+                assert_eq!(x.checked_sub(&y), fits(a - b));
+            } else {
+                assert_eq!(x.checked_sub(&y), None);
+            }
+            if b.is_zero() {
+                assert_eq!(x.checked_div_rem(&y), None);
+            } else {
+                let (q, r) = x.checked_div_rem(&y).unwrap();
+                assert_eq!(BigUint::from(q), a / b);
+                assert_eq!(BigUint::from(r), a % b);
+            }
+        }
+    }
+}
+
+fn hash<T: Hash>(x: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    x.hash(&mut hasher);
+    hasher.finish()
+}
+
+#[test]
+fn test_hash() {
+    for a in &values() {
+        assert_eq!(hash(&stack(a)), hash(a));
+        assert_eq!(hash(&StackBigUint::<9>::try_from(a).unwrap()), hash(a));
+    }
+}
+
+#[test]
+fn test_ops() {
+    let mut x = U256::from(10);
+    x += U256::from(5);
+    x *= &x.clone();
+    x -= U256::ONE;
+    x /= U256::from(8);
+    x %= U256::from(20);
+    assert_eq!(x, U256::from(8));
+
+    assert_eq!(
+        U256::from(3).checked_pow(161),
+        fits(BigUint::from(3u8).pow(161u32))
+    );
+    assert_eq!(U256::from(3).checked_pow(162), None);
+    assert_eq!(U256::from(3).checked_pow(0), Some(U256::ONE));
+}
+
+#[test]
+#[should_panic(expected = "capacity overflow")]
+fn test_add_overflow() {
+    let _ = StackBigUint::<1>::from(u64::MAX) + StackBigUint::ONE;
+}
+
+#[test]
+#[should_panic(expected = "attempt to subtract with overflow")]
+fn test_sub_overflow() {
+    let _ = U256::ZERO - U256::ONE;
+}
@@ -0,0 +1,56 @@
+#![cfg(feature = "stats")]
+
+use num_bigint::{reset_stats, stats, BigUint, Stats};
+use num_traits::{One, Pow};
+
+/// Returns the number of digits in a value of `bits` bits, which follow the pointer width.
+fn digits(bits: usize) -> usize {
+    bits / usize::BITS as usize
+}
+
+#[test]
+fn test_stats() {
+    reset_stats();
+    assert_eq!(stats(), Stats::default());
+
+    let small = (BigUint::one() << 640u32) - 1u8;
+    let _ = &small * &small;
+    let s = stats();
+    assert_eq!(s.long_multiplications, 1);
+    assert_eq!(s.limbs_multiplied, digits(640) * digits(640));
+    assert_eq!(s.karatsuba_multiplications, 0);
+    assert!(s.allocations >= 1);
+
+    let big = BigUint::from(3u8).pow(2580u32);
+    assert_eq!(big.bits(), 4090);
+    reset_stats();
+    let _ = &big * &big;
+    let s = stats();
+    assert_eq!(s.karatsuba_multiplications, 1);
+    assert_eq!(s.long_multiplications, 3);
+    // Karatsuba needs at most three quarters of the digit products of long multiplication.
+    let half = digits(4096) / 2;
+    assert!(s.limbs_multiplied <= 3 * half * half);
+
+    let huge = (BigUint::one() << 40_000u32) - 1u8;
+    reset_stats();
+    let _ = &huge * &big;
+    let _ = &huge * &huge;
+    let s = stats();
+    assert!(s.half_karatsuba_multiplications > 0);
+    assert!(s.toom3_multiplications > 0);
+
+    let product = &huge * &big;
+    reset_stats();
+    let _ = &huge / &big;
+    let _ = product.div_exact(&big);
+    let s = stats();
+    assert_eq!(s.long_divisions, 1);
+    assert_eq!(s.exact_divisions, 1);
+
+    reset_stats();
+    let _ = small.modpow(&small, &big);
+    assert_eq!(stats().montgomery_modpows, 1);
+    let _ = small.modpow(&small, &(&big + 1u8));
+    assert_eq!(stats().plain_modpows, 1);
+}
@@ -0,0 +1,66 @@
+#![cfg(feature = "tracing")]
+
+use num_bigint::BigUint;
+use num_traits::One;
+
+use std::cell::RefCell;
+use tracing::span::{Attributes, Id, Record};
+use tracing::{Event, Metadata, Subscriber};
+
+thread_local! {
+    /// The names of all spans created so far on this thread.
+    static SPANS: RefCell<Vec<&'static str>> = const { RefCell::new(Vec::new()) };
+}
+
+/// Records the name of every span that is created in [`SPANS`].
+struct Spans;
+
+impl Subscriber for Spans {
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        SPANS.with(|names| {
+            let mut names = names.borrow_mut();
+            names.push(span.metadata().name());
+            Id::from_u64(names.len() as u64)
+        })
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, _: &Event<'_>) {}
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+fn take_spans() -> Vec<&'static str> {
+    SPANS.with(RefCell::take)
+}
+
+#[test]
+fn test_tracing_spans() {
+    tracing::subscriber::set_global_default(Spans).unwrap();
+
+    let small = BigUint::from(12345u32);
+    let _ = &small * &small / 7u8;
+    let _ = small.to_string();
+    assert!(take_spans().is_empty());
+
+    let big = (BigUint::one() << 10_000u32) - 1u8;
+    let _ = &big * &big;
+    assert!(take_spans().contains(&"mul"));
+    let _ = (&big * &big) / (&big >> 4_000u32);
+    assert!(take_spans().contains(&"div"));
+    let _ = small.modpow(&small, &big);
+    assert!(take_spans().contains(&"modpow"));
+    let s = big.to_string();
+    assert!(take_spans().contains(&"to_radix"));
+    let _: BigUint = s.parse().unwrap();
+    assert!(take_spans().contains(&"from_radix"));
+}
@@ -0,0 +1,23 @@
+#![cfg(feature = "try-reserve")]
+
+use num_bigint::{BigInt, BigUint, CapacityErrorKind};
+use num_traits::{One, Pow};
+
+#[test]
+fn test_try_reserve() {
+    let x = BigUint::from(3u8).pow(5000u32);
+    assert_eq!(x.try_add(&x), Ok(&x + &x));
+    assert_eq!(x.try_mul(&x), Ok(&x * &x));
+    assert_eq!(x.try_shl(1000), Ok(&x << 1000u32));
+    assert_eq!(x.try_pow(7), Ok(Pow::pow(&x, 7u32)));
+    assert_eq!(BigInt::from(-3).try_pow(5), Ok(BigInt::from(-243)));
+
+    // Far more memory than any machine has, which would otherwise abort.
+    #[cfg(target_pointer_width = "64")]
+    {
+        let err = BigUint::one().try_shl(1 << 62).unwrap_err();
+        assert_eq!(err.kind(), CapacityErrorKind::AllocFailed);
+        let err = BigInt::from(-1).try_shl(1 << 62).unwrap_err();
+        assert_eq!(err.kind(), CapacityErrorKind::AllocFailed);
+    }
+}
@@ -0,0 +1,58 @@
+#![cfg(feature = "ufmt")]
+
+use num_bigint::{BigInt, BigUint};
+use num_traits::Num;
+use std::convert::Infallible;
+use ufmt::{uWrite, uwrite};
+
+struct Buffer(String);
+
+impl uWrite for Buffer {
+    type Error = Infallible;
+
+    fn write_str(&mut self, s: &str) -> Result<(), Infallible> {
+        self.0.push_str(s);
+        Ok(())
+    }
+}
+
+fn display<T: ufmt::uDisplay + ufmt::uDebug>(value: &T) -> String {
+    let mut buf = Buffer(String::new());
+    uwrite!(buf, "{}", value).unwrap();
+    let mut debug = Buffer(String::new());
+    uwrite!(debug, "{:?}", value).unwrap();
+    assert_eq!(buf.0, debug.0);
+    buf.0
+}
+
+#[test]
+fn test_ufmt_biguint() {
+    let cases = [
+        "0",
+        "7",
+        "9999999999999999999",
+        "10000000000000000000",
+        "18446744073709551616",
+        "100000000000000000000000000000000000001",
+        "340282366920938463463374607431768211455",
+    ];
+    for s in cases {
+        let n = BigUint::from_str_radix(s, 10).unwrap();
+        assert_eq!(display(&n), s);
+    }
+    let n = BigUint::from(3u8).pow(1000);
+    assert_eq!(display(&n), n.to_string());
+}
+
+#[test]
+fn test_ufmt_bigint() {
+    for s in [
+        "0",
+        "-1",
+        "-10000000000000000000",
+        "12345678901234567890123",
+    ] {
+        let n = BigInt::from_str_radix(s, 10).unwrap();
+        assert_eq!(display(&n), s);
+    }
+}